@@ -20,6 +20,10 @@ Example:
 
     # Sync usage
     dbs = client.databases_sync()
+
+    # Context manager usage closes the client on exit
+    with EnterpriseClient.from_env() as client:
+        info = client.cluster_info_sync()
 """
 
 from .redis_enterprise import EnterpriseClient, RedisEnterpriseError, __version__
@@ -1,17 +1,29 @@
 //! Python bindings for Redis Enterprise API client
 
-use crate::error::IntoPyResult;
+use crate::error::{IntoPyResult, RedisEnterpriseError};
 use crate::runtime::{block_on, future_into_py};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
-use redis_enterprise::EnterpriseClient;
+use redis_enterprise::{CreateDatabaseRequest, EnterpriseClient};
 use std::sync::Arc;
 use std::time::Duration;
 
 /// Redis Enterprise API client
+///
+/// Can be used as a context manager (`with` or `async with`); leaving the
+/// block closes the client and releases its connection pool.
 #[pyclass(name = "EnterpriseClient")]
 pub struct PyEnterpriseClient {
-    client: Arc<EnterpriseClient>,
+    client: Option<Arc<EnterpriseClient>>,
+}
+
+impl PyEnterpriseClient {
+    /// The underlying client, or an error once the client has been closed
+    fn inner(&self) -> PyResult<Arc<EnterpriseClient>> {
+        self.client
+            .clone()
+            .ok_or_else(|| RedisEnterpriseError::new_err("Client is closed"))
+    }
 }
 
 #[pymethods]
@@ -38,7 +50,7 @@ impl PyEnterpriseClient {
 
         let client = builder.build().into_py_result()?;
         Ok(Self {
-            client: Arc::new(client),
+            client: Some(Arc::new(client)),
         })
     }
 
@@ -47,7 +59,59 @@ impl PyEnterpriseClient {
     fn from_env() -> PyResult<Self> {
         let client = EnterpriseClient::from_env().into_py_result()?;
         Ok(Self {
-            client: Arc::new(client),
+            client: Some(Arc::new(client)),
+        })
+    }
+
+    // Lifecycle
+
+    /// Close the client, releasing its connection pool
+    ///
+    /// In-flight requests complete normally; any later call raises
+    /// `RedisEnterpriseError`. Closing twice is a no-op.
+    fn close(&mut self) {
+        self.client = None;
+    }
+
+    /// Whether the client has been closed
+    #[getter]
+    fn closed(&self) -> bool {
+        self.client.is_none()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.inner()?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> bool {
+        self.close();
+        false
+    }
+
+    fn __aenter__<'py>(slf: Bound<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        slf.borrow().inner()?;
+        let client = slf.into_any().unbind();
+        future_into_py(py, async move { Ok(client) })
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __aexit__<'py>(
+        &mut self,
+        py: Python<'py>,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.close();
+        future_into_py(py, async move {
+            Python::with_gil(|py| Ok(false.into_pyobject(py)?.to_owned().into_any().unbind()))
         })
     }
 
@@ -55,7 +119,7 @@ impl PyEnterpriseClient {
 
     /// Get cluster information (async)
     fn cluster_info<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let info = client.cluster().info().await.into_py_result()?;
             let json = serde_json::to_value(&info)
@@ -66,7 +130,7 @@ impl PyEnterpriseClient {
 
     /// Get cluster information (sync)
     fn cluster_info_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(
             py,
             async move { client.cluster().info().await.into_py_result() },
@@ -78,7 +142,7 @@ impl PyEnterpriseClient {
 
     /// Get cluster statistics (async)
     fn cluster_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let stats = client.cluster().stats().await.into_py_result()?;
             Python::with_gil(|py| Ok(json_to_py(py, stats)))
@@ -87,7 +151,7 @@ impl PyEnterpriseClient {
 
     /// Get cluster statistics (sync)
     fn cluster_stats_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.cluster().stats().await.into_py_result()
         })?;
@@ -96,7 +160,7 @@ impl PyEnterpriseClient {
 
     /// Get license information (async)
     fn license<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let license = client.cluster().license().await.into_py_result()?;
             let json = serde_json::to_value(&license)
@@ -107,7 +171,7 @@ impl PyEnterpriseClient {
 
     /// Get license information (sync)
     fn license_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.cluster().license().await.into_py_result()
         })?;
@@ -120,7 +184,7 @@ impl PyEnterpriseClient {
 
     /// List all databases (async)
     fn databases<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let dbs = client.databases().list().await.into_py_result()?;
             let json = serde_json::to_value(&dbs)
@@ -131,7 +195,7 @@ impl PyEnterpriseClient {
 
     /// List all databases (sync)
     fn databases_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.databases().list().await.into_py_result()
         })?;
@@ -142,7 +206,7 @@ impl PyEnterpriseClient {
 
     /// Get a specific database by ID (async)
     fn database<'py>(&self, py: Python<'py>, uid: u32) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let db = client.databases().get(uid).await.into_py_result()?;
             let json = serde_json::to_value(&db)
@@ -153,7 +217,7 @@ impl PyEnterpriseClient {
 
     /// Get a specific database by ID (sync)
     fn database_sync(&self, py: Python<'_>, uid: u32) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.databases().get(uid).await.into_py_result()
         })?;
@@ -162,11 +226,56 @@ impl PyEnterpriseClient {
         Ok(json_to_py(py, json))
     }
 
+    /// Create a database from a request dict (async)
+    fn database_create<'py>(
+        &self,
+        py: Python<'py>,
+        request: Py<PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let request = create_database_request(py, request)?;
+        let client = self.inner()?;
+        future_into_py(py, async move {
+            let db = client.databases().create(request).await.into_py_result()?;
+            let json = serde_json::to_value(&db)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+            Python::with_gil(|py| Ok(json_to_py(py, json)))
+        })
+    }
+
+    /// Create a database from a request dict (sync)
+    fn database_create_sync(&self, py: Python<'_>, request: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let request = create_database_request(py, request)?;
+        let client = self.inner()?;
+        let result = block_on(py, async move {
+            client.databases().create(request).await.into_py_result()
+        })?;
+        let json = serde_json::to_value(&result)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        Ok(json_to_py(py, json))
+    }
+
+    /// Delete a database by ID (async)
+    fn database_delete<'py>(&self, py: Python<'py>, uid: u32) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.inner()?;
+        future_into_py(py, async move {
+            client.databases().delete(uid).await.into_py_result()?;
+            Python::with_gil(|py| Ok(py.None()))
+        })
+    }
+
+    /// Delete a database by ID (sync)
+    fn database_delete_sync(&self, py: Python<'_>, uid: u32) -> PyResult<()> {
+        let client = self.inner()?;
+        block_on(py, async move {
+            client.databases().delete(uid).await.into_py_result()
+        })
+    }
+
     // Nodes API
 
     /// List all nodes (async)
     fn nodes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let nodes = client.nodes().list().await.into_py_result()?;
             let json = serde_json::to_value(&nodes)
@@ -177,7 +286,7 @@ impl PyEnterpriseClient {
 
     /// List all nodes (sync)
     fn nodes_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(
             py,
             async move { client.nodes().list().await.into_py_result() },
@@ -189,7 +298,7 @@ impl PyEnterpriseClient {
 
     /// Get a specific node by ID (async)
     fn node<'py>(&self, py: Python<'py>, uid: u32) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let node = client.nodes().get(uid).await.into_py_result()?;
             let json = serde_json::to_value(&node)
@@ -200,7 +309,7 @@ impl PyEnterpriseClient {
 
     /// Get a specific node by ID (sync)
     fn node_sync(&self, py: Python<'_>, uid: u32) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(
             py,
             async move { client.nodes().get(uid).await.into_py_result() },
@@ -214,7 +323,7 @@ impl PyEnterpriseClient {
 
     /// List all users (async)
     fn users<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let users = client.users().list().await.into_py_result()?;
             let json = serde_json::to_value(&users)
@@ -225,7 +334,7 @@ impl PyEnterpriseClient {
 
     /// List all users (sync)
     fn users_sync(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(
             py,
             async move { client.users().list().await.into_py_result() },
@@ -239,7 +348,7 @@ impl PyEnterpriseClient {
 
     /// Execute a raw GET request (async)
     fn get<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let result = client.get_raw(&path).await.into_py_result()?;
             Python::with_gil(|py| Ok(json_to_py(py, result)))
@@ -248,7 +357,7 @@ impl PyEnterpriseClient {
 
     /// Execute a raw GET request (sync)
     fn get_sync(&self, py: Python<'_>, path: String) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(
             py,
             async move { client.get_raw(&path).await.into_py_result() },
@@ -264,7 +373,7 @@ impl PyEnterpriseClient {
         body: Py<PyAny>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let body_json = py_to_json(py, body)?;
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let result = client.post_raw(&path, body_json).await.into_py_result()?;
             Python::with_gil(|py| Ok(json_to_py(py, result)))
@@ -274,7 +383,7 @@ impl PyEnterpriseClient {
     /// Execute a raw POST request (sync)
     fn post_sync(&self, py: Python<'_>, path: String, body: Py<PyAny>) -> PyResult<Py<PyAny>> {
         let body_json = py_to_json(py, body)?;
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.post_raw(&path, body_json).await.into_py_result()
         })?;
@@ -283,7 +392,7 @@ impl PyEnterpriseClient {
 
     /// Execute a raw DELETE request (async)
     fn delete<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         future_into_py(py, async move {
             let result = client.delete_raw(&path).await.into_py_result()?;
             Python::with_gil(|py| Ok(json_to_py(py, result)))
@@ -292,7 +401,7 @@ impl PyEnterpriseClient {
 
     /// Execute a raw DELETE request (sync)
     fn delete_sync(&self, py: Python<'_>, path: String) -> PyResult<Py<PyAny>> {
-        let client = self.client.clone();
+        let client = self.inner()?;
         let result = block_on(py, async move {
             client.delete_raw(&path).await.into_py_result()
         })?;
@@ -300,6 +409,13 @@ impl PyEnterpriseClient {
     }
 }
 
+/// Convert a Python dict into a typed database creation request
+fn create_database_request(py: Python<'_>, request: Py<PyAny>) -> PyResult<CreateDatabaseRequest> {
+    let json = py_to_json(py, request)?;
+    serde_json::from_value(json)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid request: {}", e)))
+}
+
 /// Convert serde_json::Value to Python object
 pub fn json_to_py(py: Python<'_>, value: serde_json::Value) -> Py<PyAny> {
     match value {
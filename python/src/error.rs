@@ -18,10 +18,11 @@ pub fn enterprise_error_to_py(err: redis_enterprise::RestError) -> PyErr {
         redis_enterprise::RestError::AuthenticationFailed => {
             RedisEnterpriseError::new_err("Authentication failed")
         }
-        redis_enterprise::RestError::Unauthorized => {
+        redis_enterprise::RestError::Unauthorized { .. } => {
             RedisEnterpriseError::new_err("Unauthorized access")
         }
-        redis_enterprise::RestError::NotFound => PyValueError::new_err("Resource not found"),
+        redis_enterprise::RestError::Forbidden { .. } => RedisEnterpriseError::new_err(err.to_string()),
+        redis_enterprise::RestError::NotFound { .. } => PyValueError::new_err("Resource not found"),
         redis_enterprise::RestError::ValidationError(_) => PyValueError::new_err(err.to_string()),
         _ => PyRuntimeError::new_err(err.to_string()),
     }
@@ -6,8 +6,12 @@
 //! - Cancel or wait for actions
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::sleep;
 
 /// Action information
 /// Represents an action (operation) in the cluster
@@ -37,6 +41,21 @@ pub struct Action {
     pub node_uid: Option<u32>,
 }
 
+impl Action {
+    /// Whether the action has finished: `completed`, `failed` or `cancelled`
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status.to_ascii_lowercase().as_str(),
+            "completed" | "failed" | "cancelled" | "canceled"
+        )
+    }
+
+    /// Whether the action is still in flight, including `queued` and `cancelling`
+    pub fn is_running(&self) -> bool {
+        !self.is_terminal()
+    }
+}
+
 /// Action handler for tracking async operations
 /// Handler for action-related operations
 pub struct ActionHandler {
@@ -67,6 +86,49 @@ impl ActionHandler {
             .await
     }
 
+    /// Poll an action until it finishes and return its final state
+    ///
+    /// Failed and cancelled actions are returned as-is; check
+    /// [`Action::status`] and [`Action::error`] for the outcome. Use with the
+    /// UID from [`EnterpriseClient::post_action_tracked`](crate::EnterpriseClient::post_action_tracked).
+    pub async fn wait_for(
+        &self,
+        action_uid: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Action> {
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let action = self.get(action_uid).await?;
+            if action.is_terminal() {
+                return Ok(action);
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
+    /// List actions that have not finished yet
+    pub async fn list_running(&self) -> Result<Vec<Action>> {
+        let mut actions = self.list().await?;
+        actions.retain(Action::is_running);
+        Ok(actions)
+    }
+
+    /// Cancel every action that has not finished yet
+    ///
+    /// Returns each running action with the outcome of its cancel request, in
+    /// list order. Not every action type can be cancelled; the cluster rejects
+    /// those, and the rejection is reported for that action only.
+    pub async fn cancel_all_running(&self) -> Result<Vec<(Action, Result<()>)>> {
+        let running = self.list_running().await?;
+        let outcomes = join_all(running.iter().map(|a| self.cancel(&a.action_uid))).await;
+        Ok(running.into_iter().zip(outcomes).collect())
+    }
+
     /// List actions via v2 API - GET /v2/actions
     pub async fn list_v2(&self) -> Result<Vec<Action>> {
         self.client.get("/v2/actions").await
@@ -6,9 +6,16 @@
 //! - Manage alert thresholds
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use futures::future::join_all;
+use futures::stream::{FuturesUnordered, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::pin::Pin;
+
+/// Stream of alerts from every alert source, yielded by
+/// [`AlertHandler::all_sources_stream`]
+pub type AlertStream<'a> = Pin<Box<dyn Stream<Item = Result<Alert>> + Send + 'a>>;
 
 /// Alert information
 /// Represents an alert state for a cluster object (database, node, or cluster)
@@ -46,6 +53,132 @@ pub struct Alert {
     /// Error code associated with the alert
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_code: Option<String>,
+    /// Endpoint the alert was read from, set by [`AlertHandler::all_sources_stream`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<AlertSource>,
+}
+
+/// Alert endpoint an [`Alert`] was collected from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSource {
+    /// `/v1/cluster/alerts`
+    Cluster,
+    /// `/v1/nodes/alerts`
+    Node,
+    /// `/v1/bdbs/alerts`
+    Database,
+}
+
+impl AlertSource {
+    fn path(self) -> &'static str {
+        match self {
+            AlertSource::Cluster => "/v1/cluster/alerts",
+            AlertSource::Node => "/v1/nodes/alerts",
+            AlertSource::Database => "/v1/bdbs/alerts",
+        }
+    }
+
+    fn entity_type(self) -> &'static str {
+        match self {
+            AlertSource::Cluster => "cluster",
+            AlertSource::Node => "node",
+            AlertSource::Database => "bdb",
+        }
+    }
+}
+
+impl Alert {
+    /// Whether the alert is currently triggered
+    pub fn is_active(&self) -> bool {
+        ["active", "on", "true"]
+            .iter()
+            .any(|state| self.state.eq_ignore_ascii_case(state))
+    }
+
+    /// Whether the alert has CRITICAL severity
+    pub fn is_critical(&self) -> bool {
+        self.severity.eq_ignore_ascii_case("critical")
+    }
+}
+
+/// Normalize an alerts response into [`Alert`]s tagged with `source`
+///
+/// Accepts either a list of alerts, or the object form keyed by alert name
+/// (cluster) or by entity UID and then alert name (nodes and databases).
+fn normalize_alerts(value: Value, source: AlertSource) -> Result<Vec<Alert>> {
+    let tag = |mut alert: Alert, entity_uid: Option<&str>| {
+        alert.source = Some(source);
+        alert
+            .entity_type
+            .get_or_insert_with(|| source.entity_type().to_string());
+        if alert.entity_uid.is_none() {
+            alert.entity_uid = entity_uid.map(str::to_string);
+        }
+        alert
+    };
+
+    match value {
+        Value::Array(_) => Ok(serde_json::from_value::<Vec<Alert>>(value)?
+            .into_iter()
+            .map(|alert| tag(alert, None))
+            .collect()),
+        Value::Object(map) if source == AlertSource::Cluster => map
+            .into_iter()
+            .map(|(name, state)| Ok(tag(alert_from_state(&name, None, state)?, None)))
+            .collect(),
+        Value::Object(map) => {
+            let mut alerts = Vec::new();
+            for (entity_uid, states) in map {
+                let Value::Object(states) = states else {
+                    return Err(RestError::ParseError(format!(
+                        "Expected alerts object for {} {}",
+                        source.entity_type(),
+                        entity_uid
+                    )));
+                };
+                for (name, state) in states {
+                    let alert = alert_from_state(&name, Some(&entity_uid), state)?;
+                    alerts.push(tag(alert, Some(&entity_uid)));
+                }
+            }
+            Ok(alerts)
+        }
+        other => Err(RestError::ParseError(format!(
+            "Unexpected alerts response from {}: {}",
+            source.path(),
+            other
+        ))),
+    }
+}
+
+/// Build an [`Alert`] from an alert state object keyed by alert `name`
+fn alert_from_state(name: &str, entity_uid: Option<&str>, state: Value) -> Result<Alert> {
+    let Value::Object(mut fields) = state else {
+        return Err(RestError::ParseError(format!(
+            "Expected alert state object for '{}'",
+            name
+        )));
+    };
+    let uid = match entity_uid {
+        Some(entity_uid) => format!("{}:{}", entity_uid, name),
+        None => name.to_string(),
+    };
+    fields.entry("uid").or_insert_with(|| Value::from(uid));
+    fields
+        .entry("name")
+        .or_insert_with(|| Value::from(name.to_string()));
+    fields
+        .entry("severity")
+        .or_insert_with(|| Value::from("INFO"));
+    // Alert state objects report `state` as a boolean
+    let state = match fields.remove("state") {
+        Some(Value::Bool(on)) => Value::from(on.to_string()),
+        Some(state) => state,
+        None => Value::from("false"),
+    };
+    fields.insert("state".to_string(), state);
+    Ok(serde_json::from_value(Value::Object(fields))?)
 }
 
 /// Generic alert settings (legacy - kept for compatibility)
@@ -55,7 +188,7 @@ pub struct AlertSettings {
     pub enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Alert threshold value when applicable
-    pub threshold: Option<Value>,
+    pub threshold: Option<AlertThreshold>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// List of email addresses to notify when alert triggers
     pub email_recipients: Option<Vec<String>>,
@@ -64,6 +197,96 @@ pub struct AlertSettings {
     pub webhook_url: Option<String>,
 }
 
+impl AlertSettings {
+    /// Numeric threshold, for plain and percentage thresholds
+    ///
+    /// Also reads thresholds the API returns as strings, such as `"80"` or
+    /// `"80%"`, which are kept as [`AlertThreshold::Raw`].
+    pub fn threshold_value(&self) -> Option<f64> {
+        match self.threshold.as_ref()? {
+            AlertThreshold::Value(value) | AlertThreshold::Percentage(value) => Some(*value),
+            AlertThreshold::Raw(Value::String(s)) => {
+                let s = s.trim();
+                s.strip_suffix('%').unwrap_or(s).trim().parse().ok()
+            }
+            AlertThreshold::Enabled(_) | AlertThreshold::Raw(_) => None,
+        }
+    }
+}
+
+/// Threshold of an alert setting
+///
+/// Covers the common shapes the API returns. Anything else, including string
+/// thresholds such as `"80"`, is kept verbatim in [`AlertThreshold::Raw`], so
+/// settings that are read and written back round-trip unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertThreshold {
+    /// Plain numeric threshold, e.g. `80`
+    Value(f64),
+    /// Percentage threshold, e.g. `{"value": 80, "unit": "percent"}`
+    Percentage(f64),
+    /// On/off threshold
+    Enabled(bool),
+    /// Any other shape, as returned by the API
+    Raw(Value),
+}
+
+impl AlertThreshold {
+    fn from_value(value: Value) -> Self {
+        let parsed = match &value {
+            Value::Number(n) => n.as_f64().map(AlertThreshold::Value),
+            Value::Bool(b) => Some(AlertThreshold::Enabled(*b)),
+            Value::Object(map) if map.len() == 2 && map.get("unit") == Some(&"percent".into()) => {
+                map.get("value")
+                    .and_then(Value::as_f64)
+                    .map(AlertThreshold::Percentage)
+            }
+            _ => None,
+        };
+        // Only keep a typed threshold if it serializes back to the same value
+        match parsed {
+            Some(threshold) if threshold.to_value() == value => threshold,
+            _ => AlertThreshold::Raw(value),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        // Whole numbers are sent as integers, matching what the API returns
+        fn number(n: f64) -> Value {
+            if n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+                Value::from(n as i64)
+            } else {
+                Value::from(n)
+            }
+        }
+        match self {
+            AlertThreshold::Value(n) => number(*n),
+            AlertThreshold::Percentage(n) => {
+                serde_json::json!({"value": number(*n), "unit": "percent"})
+            }
+            AlertThreshold::Enabled(b) => Value::Bool(*b),
+            AlertThreshold::Raw(value) => value.clone(),
+        }
+    }
+}
+
+impl Serialize for AlertThreshold {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AlertThreshold {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(AlertThreshold::from_value)
+    }
+}
+
 /// Database alert settings with threshold
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BdbAlertSettingsWithThreshold {
@@ -208,6 +431,9 @@ pub struct ClusterAlertsSettings {
     pub node_persistent_storage: Option<ClusterAlertSettingsWithThreshold>,
 }
 
+/// Identifier of an alert, as found in [`Alert::uid`]
+pub type AlertId = String;
+
 /// Alert handler for managing alerts
 pub struct AlertHandler {
     client: RestClient,
@@ -250,6 +476,35 @@ impl AlertHandler {
         self.client.get("/v1/cluster/alerts").await
     }
 
+    /// Stream alerts from the cluster, node and database alert endpoints
+    ///
+    /// The three endpoints are queried concurrently and alerts are yielded as
+    /// each response arrives, with [`Alert::source`] set to its origin. A failing
+    /// endpoint yields one error and does not stop the others.
+    pub fn all_sources_stream(&self) -> AlertStream<'_> {
+        Box::pin(async_stream::stream! {
+            let mut pending: FuturesUnordered<_> = [
+                AlertSource::Cluster,
+                AlertSource::Node,
+                AlertSource::Database,
+            ]
+            .into_iter()
+            .map(|source| async move { (source, self.client.get::<Value>(source.path()).await) })
+            .collect();
+
+            while let Some((source, response)) = pending.next().await {
+                match response.and_then(|value| normalize_alerts(value, source)) {
+                    Ok(alerts) => {
+                        for alert in alerts {
+                            yield Ok(alert);
+                        }
+                    }
+                    Err(e) => yield Err(e),
+                }
+            }
+        })
+    }
+
     /// Get alert settings for a specific alert type
     pub async fn get_settings(&self, alert_name: &str) -> Result<AlertSettings> {
         self.client
@@ -313,4 +568,31 @@ impl AlertHandler {
     pub async fn clear_all(&self) -> Result<()> {
         self.client.delete("/v1/alerts").await
     }
+
+    /// Clear/acknowledge several alerts concurrently
+    ///
+    /// Every alert is attempted even if some fail; the result for each id is
+    /// returned in the same order as `uids`.
+    pub async fn acknowledge_many(&self, uids: &[AlertId]) -> Vec<(AlertId, Result<()>)> {
+        let results = join_all(uids.iter().map(|uid| self.clear(uid))).await;
+        uids.iter().cloned().zip(results).collect()
+    }
+
+    /// Clear/acknowledge every alert currently raised on a database
+    ///
+    /// Lists the database's alerts and acknowledges them with
+    /// [`acknowledge_many`](Self::acknowledge_many). Fails only if the alerts
+    /// cannot be listed.
+    pub async fn acknowledge_all_for_database(
+        &self,
+        bdb_uid: u32,
+    ) -> Result<Vec<(AlertId, Result<()>)>> {
+        let uids: Vec<AlertId> = self
+            .list_by_database(bdb_uid)
+            .await?
+            .into_iter()
+            .map(|alert| alert.uid)
+            .collect();
+        Ok(self.acknowledge_many(&uids).await)
+    }
 }
@@ -68,10 +68,18 @@
 //! ```
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::cluster::ClusterInfo;
+use crate::error::{RestError, Result};
+use crate::license::LicenseHandler;
+use crate::retry::Backoff;
+use crate::shards::Shard;
+use crate::stats::StatsHandler;
+use chrono::{DateTime, Utc};
 use futures::stream::Stream;
+use futures::try_join;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::pin::Pin;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -102,6 +110,106 @@ pub struct BackupResponse {
     pub backup_uid: Option<String>,
 }
 
+/// A past backup of a database, as listed by [`DatabaseHandler::backup_history`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupRecord {
+    /// Backup UID, which can be passed to [`DatabaseHandler::restore`]
+    pub backup_uid: Option<String>,
+    /// Time the backup started
+    #[serde(alias = "time", alias = "start_time")]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Where the backup was written (e.g., an S3 or FTP URL)
+    pub location: Option<String>,
+    /// Backup status (e.g., "succeeded", "failed", "running")
+    pub status: Option<String>,
+    /// Size of the backup in bytes
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
+    pub size: Option<u64>,
+    /// Why the backup failed, for failed backups
+    pub backup_failure_reason: Option<String>,
+}
+
+impl BackupRecord {
+    /// Whether the backup failed
+    pub fn is_failed(&self) -> bool {
+        self.backup_failure_reason.is_some()
+            || self
+                .status
+                .as_deref()
+                .is_some_and(|status| status.eq_ignore_ascii_case("failed"))
+    }
+}
+
+/// Result of a database availability check
+///
+/// Returned by [`DatabaseHandler::availability_status`] and
+/// [`DatabaseHandler::endpoint_availability_status`]. The cluster answers
+/// `200 OK` when the database is available and `503 Service Unavailable`
+/// with an error code and description when it is not.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvailabilityStatus {
+    /// Whether the database can serve traffic
+    pub available: bool,
+    /// Why the database is unavailable, e.g. `"Master shard is down"`
+    pub reason: Option<String>,
+    /// Machine-readable cause, e.g. `"no_quorum"`
+    pub error_code: Option<String>,
+    /// Per-endpoint detail, when the cluster reports it
+    pub endpoints: Vec<EndpointAvailability>,
+}
+
+/// Availability of a single database endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawAvailability")]
+pub struct EndpointAvailability {
+    /// Endpoint UID
+    pub uid: Option<String>,
+    /// Whether the endpoint can serve traffic
+    pub available: bool,
+    /// Why the endpoint is unavailable
+    pub reason: Option<String>,
+}
+
+/// Availability body as sent by the cluster, for the database or an endpoint
+#[derive(Default, Deserialize)]
+struct RawAvailability {
+    uid: Option<String>,
+    available: Option<bool>,
+    error_code: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    endpoints: Vec<EndpointAvailability>,
+}
+
+impl From<RawAvailability> for EndpointAvailability {
+    fn from(raw: RawAvailability) -> Self {
+        EndpointAvailability {
+            uid: raw.uid,
+            available: raw.available.unwrap_or(raw.error_code.is_none()),
+            reason: raw.description.or(raw.error_code),
+        }
+    }
+}
+
+impl AvailabilityStatus {
+    /// Interpret an availability response by its status code and body
+    fn from_response(status: reqwest::StatusCode, body: Value) -> Result<Self> {
+        let raw: RawAvailability = if body.is_null() {
+            RawAvailability::default()
+        } else {
+            serde_json::from_value(body).map_err(|e| {
+                RestError::ParseError(format!("Invalid availability response: {}", e))
+            })?
+        };
+        Ok(AvailabilityStatus {
+            available: status.is_success(),
+            reason: raw.description.or_else(|| raw.error_code.clone()),
+            error_code: raw.error_code,
+            endpoints: raw.endpoints,
+        })
+    }
+}
+
 /// Response from import operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportResponse {
@@ -198,14 +306,21 @@ pub struct DatabaseUpgradeRequest {
 }
 
 /// Database information from the REST API - 100% field coverage (152/152 fields)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields the cluster sends beyond these are kept in [`extra`](Self::extra).
+/// When serializing, typed fields win: an `extra` entry named like a typed
+/// field is never emitted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct DatabaseInfo {
     // Core database identification and status
     pub uid: u32,
     pub name: String,
     pub port: Option<u16>,
     pub status: Option<String>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub memory_size: Option<u64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub memory_used: Option<u64>,
 
     /// Database type (e.g., "redis", "memcached")
@@ -359,6 +474,7 @@ pub struct DatabaseInfo {
 
     // BigStore/Flash storage settings
     pub bigstore: Option<bool>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub bigstore_ram_size: Option<u64>,
     pub bigstore_max_ram_ratio: Option<u32>,
     pub bigstore_ram_weights: Option<Vec<Value>>,
@@ -433,10 +549,489 @@ pub struct DatabaseInfo {
     pub roles_permissions: Option<Vec<Value>>,
     pub tags: Option<Vec<String>>,
     pub topology_epoch: Option<u32>,
+
+    /// Fields not modelled above, by API name
+    #[serde(flatten, skip_serializing)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+impl Serialize for DatabaseInfo {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        // Every typed field is always written, so any `extra` key already
+        // present here shadows a typed field and is dropped
+        let Value::Object(mut fields) =
+            DatabaseInfo::serialize(self, serde_json::value::Serializer)
+                .map_err(serde::ser::Error::custom)?
+        else {
+            return Err(serde::ser::Error::custom(
+                "DatabaseInfo must serialize to a map",
+            ));
+        };
+        for (key, value) in &self.extra {
+            fields.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        fields.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DatabaseInfo {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        DatabaseInfo::deserialize(deserializer)
+    }
+}
+
+impl DatabaseInfo {
+    /// Build a `redis://` (or `rediss://` when TLS is enabled) URL for the database
+    ///
+    /// Uses the endpoint whose address type matches `prefer`, falling back to
+    /// any endpoint. When several endpoints qualify, the master endpoint (the
+    /// first one listed) is used. The DNS name is preferred over a raw address.
+    /// Returns `None` if the database has no usable endpoint yet.
+    pub fn connection_string(&self, prefer: AddrType) -> Option<String> {
+        let endpoints = self.endpoints.as_deref().unwrap_or_default();
+        let endpoint = endpoints
+            .iter()
+            .find(|e| e.addr_type.as_deref() == Some(prefer.as_str()))
+            .or_else(|| endpoints.first())?;
+
+        let host = endpoint.dns_name.clone().or_else(|| {
+            endpoint.addr.as_ref()?.first().map(|addr| {
+                // IPv6 literals must be bracketed in URLs
+                if addr.contains(':') {
+                    format!("[{}]", addr)
+                } else {
+                    addr.clone()
+                }
+            })
+        })?;
+        let port = endpoint.port.or(self.port)?;
+
+        let tls = self.ssl == Some(true)
+            || self
+                .tls_mode
+                .as_deref()
+                .is_some_and(|mode| mode.eq_ignore_ascii_case("enabled"));
+        let scheme = if tls { "rediss" } else { "redis" };
+
+        Some(format!("{}://{}:{}", scheme, host, port))
+    }
+
+    /// Parse `disabled_commands` into individual command names
+    ///
+    /// The API stores the list as a single string separated by commas and/or
+    /// whitespace. Returns an empty list when no commands are disabled.
+    pub fn disabled_commands_list(&self) -> Vec<String> {
+        self.disabled_commands
+            .as_deref()
+            .unwrap_or_default()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|command| !command.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// The `search` field as a [`ModuleFeature`]
+    ///
+    /// Returns `None` when the cluster does not report the field or reports it
+    /// in a shape that is neither a bool nor an object.
+    pub fn search_feature(&self) -> Option<ModuleFeature> {
+        ModuleFeature::from_value(self.search.as_ref()?)
+    }
+
+    /// The `timeseries` field as a [`ModuleFeature`]
+    ///
+    /// Returns `None` when the cluster does not report the field or reports it
+    /// in a shape that is neither a bool nor an object.
+    pub fn timeseries_feature(&self) -> Option<ModuleFeature> {
+        ModuleFeature::from_value(self.timeseries.as_ref()?)
+    }
+
+    /// An update request holding every field of this database that can be changed
+    ///
+    /// Only fields an update may change are included; read-only fields (UID,
+    /// status, endpoints, shard list, progress and timestamps, ...) and unset
+    /// fields are left out.
+    pub fn to_update_request(&self) -> UpdateDatabaseRequest {
+        let mut fields = self.mutable_fields();
+        fields.retain(|_, value| !value.is_null());
+        UpdateDatabaseRequest { fields }
+    }
+
+    /// The changeable fields whose value in `other` differs from this database
+    ///
+    /// Returns a JSON object with `other`'s value for each changed key, ready to
+    /// pass to [`DatabaseHandler::update`]. A field cleared in `other` is sent
+    /// as `null`.
+    pub fn diff(&self, other: &DatabaseInfo) -> Value {
+        let before = self.mutable_fields();
+        let changed = other
+            .mutable_fields()
+            .into_iter()
+            .filter(|(key, value)| before.get(key).unwrap_or(&Value::Null) != value)
+            .collect();
+        Value::Object(changed)
+    }
+
+    /// The updatable fields, as serialized
+    fn mutable_fields(&self) -> serde_json::Map<String, Value> {
+        let Ok(Value::Object(mut fields)) = serde_json::to_value(self) else {
+            return serde_json::Map::new();
+        };
+        fields.retain(|key, _| DATABASE_UPDATABLE_FIELDS.contains(&key.as_str()));
+        fields
+    }
+}
+
+/// Database fields an update may change
+///
+/// Everything else the cluster reports (UID, status, endpoints, shard list,
+/// progress, timestamps, versions, statistics, and settings fixed at creation
+/// such as `type`, `bigstore` and `module_list`) is left out.
+const DATABASE_UPDATABLE_FIELDS: &[&str] = &[
+    "name",
+    "port",
+    "memory_size",
+    "shards_count",
+    "sharding",
+    "shards_placement",
+    "replication",
+    "persistence",
+    "data_persistence",
+    "eviction_policy",
+    "mtls_allow_weak_hashing",
+    "mtls_allow_outdated_certs",
+    "authentication_redis_pass",
+    "authentication_admin_pass",
+    "authentication_sasl_pass",
+    "authentication_sasl_uname",
+    "authentication_ssl_client_certs",
+    "authentication_ssl_crdt_certs",
+    "authorized_subjects",
+    "data_internode_encryption",
+    "ssl",
+    "tls_mode",
+    "enforce_client_authentication",
+    "default_user",
+    "acl",
+    "client_cert_subject_validation_type",
+    "compare_key_hslot",
+    "dns_suffixes",
+    "group_uid",
+    "redis_cluster_enabled",
+    "crdt_sources",
+    "crdt_sync",
+    "crdt_sync_connection_alarm_timeout_seconds",
+    "crdt_sync_dist",
+    "crdt_syncer_auto_oom_unlatch",
+    "crdt_xadd_id_uniqueness_mode",
+    "crdt_causal_consistency",
+    "crdt_repl_backlog_size",
+    "master_persistence",
+    "slave_ha",
+    "slave_ha_priority",
+    "replica_read_only",
+    "replica_sources",
+    "replica_sync",
+    "replica_sync_connection_alarm_timeout_seconds",
+    "replica_sync_dist",
+    "repl_backlog_size",
+    "max_connections",
+    "maxclients",
+    "conns",
+    "conns_type",
+    "max_client_pipeline",
+    "max_pipelined",
+    "aof_policy",
+    "max_aof_file_size",
+    "max_aof_load_time",
+    "activedefrag",
+    "active_defrag_cycle_max",
+    "active_defrag_cycle_min",
+    "active_defrag_ignore_bytes",
+    "active_defrag_max_scan_fields",
+    "active_defrag_threshold_lower",
+    "active_defrag_threshold_upper",
+    "backup",
+    "backup_interval",
+    "backup_interval_offset",
+    "backup_location",
+    "skip_import_analyze",
+    "metrics_export_all",
+    "generate_text_monitor",
+    "email_alerts",
+    "bigstore_ram_size",
+    "bigstore_max_ram_ratio",
+    "bigstore_ram_weights",
+    "proxy_policy",
+    "oss_cluster",
+    "oss_cluster_api_preferred_endpoint_type",
+    "oss_cluster_api_preferred_ip_type",
+    "oss_sharding",
+    "resp3",
+    "disabled_commands",
+    "hash_slots_policy",
+    "shard_key_regex",
+    "shard_block_crossslot_keys",
+    "shard_block_foreign_keys",
+    "implicit_shard_key",
+    "avoid_nodes",
+    "use_nodes",
+    "rack_aware",
+    "auto_upgrade",
+    "db_conns_auditing",
+    "flush_on_fullsync",
+    "use_selective_flush",
+    "sync",
+    "sync_sources",
+    "sync_dedicated_threads",
+    "syncer_mode",
+    "syncer_log_level",
+    "support_syncer_reconf",
+    "gradual_src_mode",
+    "gradual_src_max_sources",
+    "gradual_sync_mode",
+    "gradual_sync_max_shards_per_source",
+    "slave_buffer",
+    "snapshot_policy",
+    "sched_policy",
+    "recovery_wait_time",
+    "multi_commands_opt",
+    "tracking_table_max_keys",
+    "wait_command",
+    "mkms",
+    "tags",
+];
+
+/// Fields to change on a database, built with [`DatabaseInfo::to_update_request`]
+///
+/// Holds the raw JSON fields so every changeable setting round-trips without
+/// being modeled here. Convert into a `Value` for [`DatabaseHandler::update`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateDatabaseRequest {
+    /// Fields to send, by API name
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, Value>,
+}
+
+impl UpdateDatabaseRequest {
+    /// Set a field, replacing any previous value
+    pub fn set(&mut self, field: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.fields.insert(field.into(), value.into());
+        self
+    }
+
+    /// The value of a field, if set
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        self.fields.get(field)
+    }
+
+    /// Stop sending a field
+    pub fn remove(&mut self, field: &str) -> Option<Value> {
+        self.fields.remove(field)
+    }
+
+    /// Whether no fields are set
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl From<UpdateDatabaseRequest> for Value {
+    fn from(request: UpdateDatabaseRequest) -> Self {
+        Value::Object(request.fields)
+    }
+}
+
+/// State of a built-in module feature (`search`, `timeseries`) on a database
+///
+/// Older clusters report these fields as a bool; newer ones send a
+/// configuration object.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModuleFeature {
+    /// Reported as `false` or `null`
+    Disabled,
+    /// Reported as `true`
+    Enabled,
+    /// Reported as a configuration object
+    Configured(SearchConfig),
+}
+
+impl ModuleFeature {
+    /// Interpret a raw `search` or `timeseries` value
+    pub fn from_value(value: &Value) -> Option<Self> {
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// Whether the feature is turned on
+    ///
+    /// A configuration object counts as enabled unless it has `"enabled": false`.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            ModuleFeature::Disabled => false,
+            ModuleFeature::Enabled => true,
+            ModuleFeature::Configured(config) => config.enabled != Some(false),
+        }
+    }
+
+    /// The configuration object, when the cluster sent one
+    pub fn config(&self) -> Option<&SearchConfig> {
+        match self {
+            ModuleFeature::Configured(config) => Some(config),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for ModuleFeature {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            ModuleFeature::Disabled => serializer.serialize_bool(false),
+            ModuleFeature::Enabled => serializer.serialize_bool(true),
+            ModuleFeature::Configured(config) => config.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ModuleFeature {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Null | Value::Bool(false) => Ok(ModuleFeature::Disabled),
+            Value::Bool(true) => Ok(ModuleFeature::Enabled),
+            object @ Value::Object(_) => serde_json::from_value(object)
+                .map(ModuleFeature::Configured)
+                .map_err(serde::de::Error::custom),
+            other => Err(serde::de::Error::custom(format!(
+                "expected a bool or an object, found {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Configuration object for a built-in module feature
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// Explicit on/off switch, when the cluster includes one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Additional configuration fields
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Proposed shard placement for a database, computed by the cluster without applying it
+///
+/// Returned by [`DatabaseHandler::optimize_preview`] and
+/// [`DatabaseHandler::rebalance_dry_run`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardPlacementPlan {
+    /// Target placement of every shard, as sent by the cluster
+    pub shards_blueprint: Vec<ShardBlueprint>,
+    /// Shards that would change node, derived from the blueprint and the current placement
+    #[serde(default)]
+    pub moves: Vec<ShardMove>,
+}
+
+impl ShardPlacementPlan {
+    /// Whether applying the plan would leave every shard where it is
+    pub fn is_noop(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Pair each blueprint entry with the current shards of the same slot range and role
+    ///
+    /// Shards already on one of their target nodes stay put; the rest are
+    /// matched in order to the remaining target nodes of the same role.
+    fn new(shards_blueprint: Vec<ShardBlueprint>, current: &[Shard]) -> Self {
+        let mut moves = Vec::new();
+        for entry in &shards_blueprint {
+            let mut targets: Vec<&ShardBlueprintNode> = entry.nodes.iter().collect();
+            let mut unplaced = Vec::new();
+            for shard in current.iter().filter(|shard| {
+                shard.assigned_slots.as_deref().or(shard.slots.as_deref())
+                    == Some(entry.slot_range.as_str())
+            }) {
+                match targets
+                    .iter()
+                    .position(|node| node.node_uid == shard.node_uid && node.role == shard.role)
+                {
+                    Some(index) => {
+                        targets.remove(index);
+                    }
+                    None => unplaced.push(shard),
+                }
+            }
+            for shard in unplaced {
+                if let Some(index) = targets.iter().position(|node| node.role == shard.role) {
+                    let target = targets.remove(index);
+                    moves.push(ShardMove {
+                        shard_uid: shard.uid.clone(),
+                        slot_range: entry.slot_range.clone(),
+                        role: shard.role.clone(),
+                        from_node: shard.node_uid.clone(),
+                        to_node: target.node_uid.clone(),
+                    });
+                }
+            }
+        }
+        ShardPlacementPlan {
+            shards_blueprint,
+            moves,
+        }
+    }
+}
+
+/// Target placement of one slot range in a [`ShardPlacementPlan`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardBlueprint {
+    /// Hash slots served by the shard, e.g. `"0-8191"`
+    pub slot_range: String,
+    /// Nodes that would hold the master and its replicas
+    pub nodes: Vec<ShardBlueprintNode>,
+}
+
+/// A node holding one copy of a shard in a [`ShardBlueprint`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardBlueprintNode {
+    /// Node UID
+    pub node_uid: String,
+    /// `"master"` or `"slave"`
+    pub role: String,
+}
+
+/// A shard that would move to another node under a [`ShardPlacementPlan`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardMove {
+    /// UID of the shard that moves
+    pub shard_uid: String,
+    /// Hash slots served by the shard
+    pub slot_range: String,
+    /// `"master"` or `"slave"`
+    pub role: String,
+    /// Node the shard is on now
+    pub from_node: String,
+    /// Node the shard would move to
+    pub to_node: String,
+}
+
+/// Blueprint body returned by placement dry runs
+#[derive(Deserialize)]
+struct ShardsBlueprintResponse {
+    shards_blueprint: Vec<ShardBlueprint>,
 }
 
 /// Database endpoint information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EndpointInfo {
     /// Unique identifier for the endpoint
     pub uid: Option<String>,
@@ -458,6 +1053,25 @@ pub struct EndpointInfo {
     pub include_proxies: Option<Vec<u32>>,
 }
 
+/// Address type of a database endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddrType {
+    /// Address on the cluster's internal network
+    Internal,
+    /// Address reachable from outside the cluster
+    External,
+}
+
+impl AddrType {
+    fn as_str(self) -> &'static str {
+        match self {
+            AddrType::Internal => "internal",
+            AddrType::External => "external",
+        }
+    }
+}
+
 /// Module configuration for database creation
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct ModuleConfig {
@@ -531,6 +1145,1201 @@ pub struct CreateDatabaseRequest {
     pub authentication_redis_pass: Option<String>,
 }
 
+impl CreateDatabaseRequest {
+    /// Check the fields the cluster requires to create a database
+    ///
+    /// `build()` stays permissive so requests can be assembled incrementally;
+    /// call this (or use [`DatabaseHandler::create_checked`]) to catch a missing
+    /// `memory_size` locally instead of as a `400` from the cluster.
+    pub fn validate(&self) -> Result<()> {
+        match self.required_field_issues().into_iter().next() {
+            Some(issue) => Err(RestError::ValidationError(issue.message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Problems with the fields the cluster requires, checked by [`validate`](Self::validate)
+    fn required_field_issues(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if self.name.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                "name",
+                "database name must not be empty",
+            ));
+        }
+        match self.memory_size {
+            None => issues.push(ValidationIssue::new(
+                "memory_size",
+                format!("memory_size is required to create database '{}'", self.name),
+            )),
+            Some(0) => issues.push(ValidationIssue::new(
+                "memory_size",
+                format!(
+                    "memory_size for database '{}' must be greater than zero",
+                    self.name
+                ),
+            )),
+            Some(_) => {}
+        }
+        issues
+    }
+}
+
+/// Eviction policies accepted by `eviction_policy`
+const EVICTION_POLICIES: &[&str] = &[
+    "noeviction",
+    "allkeys-lru",
+    "allkeys-lfu",
+    "allkeys-random",
+    "volatile-lru",
+    "volatile-lfu",
+    "volatile-random",
+    "volatile-ttl",
+];
+
+/// Persistence modes accepted by `persistence`
+const PERSISTENCE_MODES: &[&str] = &["disabled", "aof", "snapshot"];
+
+/// A problem with a create request, found by [`DatabaseHandler::validate_create`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// Request field the issue is about, e.g. `memory_size`
+    pub field: String,
+    /// What is wrong
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        ValidationIssue {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Request body for the v2 create endpoint, `POST /v2/bdbs`
+///
+/// Wraps the database definition together with an optional recovery plan that
+/// seeds the new database's shards from persistence files already on the nodes,
+/// as in a disaster-recovery restore.
+#[derive(Debug, Serialize, Deserialize, TypedBuilder)]
+pub struct CreateDatabaseV2Request {
+    /// The database to create
+    pub bdb: CreateDatabaseRequest,
+    /// Files to recover the database's data from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub recovery_plan: Option<RecoveryPlan>,
+}
+
+/// Where each shard of a recovered database loads its data from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoveryPlan {
+    /// One entry per shard (hash slot range) to recover
+    pub data_files: Vec<RecoveryDataFile>,
+}
+
+/// A persistence file to recover a range of hash slots from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct RecoveryDataFile {
+    /// Name of the RDB or AOF file in the node's persistence directory
+    #[builder(setter(into))]
+    pub filename: String,
+    /// UID of the node holding the file
+    #[builder(setter(into))]
+    pub node_uid: String,
+    /// Hash slots the file covers, e.g. `"0-8191"`
+    #[builder(setter(into))]
+    pub shard_slots: String,
+}
+
+impl CreateDatabaseV2Request {
+    /// Check the database definition and that every recovery file is fully specified
+    pub fn validate(&self) -> Result<()> {
+        self.bdb.validate()?;
+        let Some(plan) = &self.recovery_plan else {
+            return Ok(());
+        };
+        if plan.data_files.is_empty() {
+            return Err(RestError::ValidationError(
+                "recovery plan must list at least one data file".to_string(),
+            ));
+        }
+        for file in &plan.data_files {
+            for (field, value) in [
+                ("filename", &file.filename),
+                ("node_uid", &file.node_uid),
+                ("shard_slots", &file.shard_slots),
+            ] {
+                if value.trim().is_empty() {
+                    return Err(RestError::ValidationError(format!(
+                        "recovery data file {} must not be empty",
+                        field
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A location to import a dataset from
+///
+/// Serialized with the `type` tag the API expects in `dataset_import_sources`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DatasetImportSource {
+    /// An FTP, FTPS, HTTP or HTTPS URL; credentials may be embedded in the URL
+    Url {
+        /// Location of the RDB file
+        url: String,
+    },
+    /// An SFTP server
+    Sftp {
+        /// `sftp://user@host[:port]/path` location of the RDB file
+        sftp_url: String,
+        /// PEM private key used to authenticate, if not the cluster's key
+        #[serde(skip_serializing_if = "Option::is_none")]
+        key: Option<String>,
+    },
+    /// An Amazon S3 (or S3-compatible) bucket
+    S3 {
+        /// Bucket name
+        bucket_name: String,
+        /// Path of the file inside the bucket
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdir: Option<String>,
+        /// File name
+        filename: String,
+        /// Access key ID, when not using the cluster's IAM role
+        #[serde(skip_serializing_if = "Option::is_none")]
+        access_key_id: Option<String>,
+        /// Secret access key, required together with `access_key_id`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        secret_access_key: Option<String>,
+    },
+    /// A Google Cloud Storage bucket
+    #[serde(rename = "gs")]
+    Gcs {
+        /// Bucket name
+        bucket_name: String,
+        /// Path of the file inside the bucket
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdir: Option<String>,
+        /// File name
+        filename: String,
+        /// Service account client ID
+        client_id: String,
+        /// Service account email
+        client_email: String,
+        /// Service account private key ID
+        private_key_id: String,
+        /// Service account private key
+        private_key: String,
+    },
+    /// An Azure Blob Storage container
+    #[serde(rename = "abs")]
+    AzureBlob {
+        /// Container name
+        container: String,
+        /// Path of the file inside the container
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdir: Option<String>,
+        /// File name
+        filename: String,
+        /// Storage account name
+        account_name: String,
+        /// Storage account key
+        account_key: String,
+    },
+    /// A path mounted on every node
+    MountPoint {
+        /// Absolute path of the RDB file
+        path: String,
+    },
+}
+
+/// `url` with any userinfo and query string masked, for error messages
+///
+/// Import and export URLs routinely embed credentials or signed tokens.
+fn redact_url(url: &str) -> String {
+    let (base, query) = match url.split_once(['?', '#']) {
+        Some((base, _)) => (base, "?***"),
+        None => (url, ""),
+    };
+    let Some((scheme, rest)) = base.split_once("://") else {
+        return format!("{}{}", base, query);
+    };
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    match authority.rsplit_once('@') {
+        Some((_, host)) => format!("{}://***@{}{}{}", scheme, host, path, query),
+        None => format!("{}{}", base, query),
+    }
+}
+
+impl DatasetImportSource {
+    /// Check the source for missing or malformed fields before sending it
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| RestError::ValidationError(message);
+        let require = |field: &str, value: &str| {
+            if value.trim().is_empty() {
+                Err(invalid(format!(
+                    "import source {} must not be empty",
+                    field
+                )))
+            } else {
+                Ok(())
+            }
+        };
+
+        match self {
+            DatasetImportSource::Url { url } => {
+                let parsed = url::Url::parse(url).map_err(|e| {
+                    invalid(format!("invalid import URL '{}': {}", redact_url(url), e))
+                })?;
+                if !matches!(parsed.scheme(), "ftp" | "ftps" | "http" | "https") {
+                    return Err(invalid(format!(
+                        "unsupported import URL scheme '{}' (expected ftp, ftps, http or https)",
+                        parsed.scheme()
+                    )));
+                }
+                Ok(())
+            }
+            DatasetImportSource::Sftp { sftp_url, .. } => {
+                let parsed = url::Url::parse(sftp_url).map_err(|e| {
+                    invalid(format!(
+                        "invalid SFTP URL '{}': {}",
+                        redact_url(sftp_url),
+                        e
+                    ))
+                })?;
+                if parsed.scheme() != "sftp" {
+                    return Err(invalid(format!(
+                        "SFTP URL must start with sftp://, got '{}'",
+                        redact_url(sftp_url)
+                    )));
+                }
+                Ok(())
+            }
+            DatasetImportSource::S3 {
+                bucket_name,
+                filename,
+                access_key_id,
+                secret_access_key,
+                ..
+            } => {
+                require("bucket_name", bucket_name)?;
+                require("filename", filename)?;
+                if access_key_id.is_some() != secret_access_key.is_some() {
+                    return Err(invalid(
+                        "S3 access_key_id and secret_access_key must be set together".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            DatasetImportSource::Gcs {
+                bucket_name,
+                filename,
+                client_id,
+                client_email,
+                private_key_id,
+                private_key,
+                ..
+            } => {
+                require("bucket_name", bucket_name)?;
+                require("filename", filename)?;
+                require("client_id", client_id)?;
+                require("client_email", client_email)?;
+                require("private_key_id", private_key_id)?;
+                require("private_key", private_key)
+            }
+            DatasetImportSource::AzureBlob {
+                container,
+                filename,
+                account_name,
+                account_key,
+                ..
+            } => {
+                require("container", container)?;
+                require("filename", filename)?;
+                require("account_name", account_name)?;
+                require("account_key", account_key)
+            }
+            DatasetImportSource::MountPoint { path } => {
+                if !path.starts_with('/') {
+                    return Err(invalid(format!(
+                        "mount point path must be absolute, got '{}'",
+                        path
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Request to import data into a database from one or more sources
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use redis_enterprise::bdb::{DatasetImportSource, ImportRequest};
+///
+/// let request = ImportRequest::builder()
+///     .dataset_import_sources(vec![
+///         DatasetImportSource::Url { url: "https://backups.example.com/shard-1.rdb".into() },
+///         DatasetImportSource::S3 {
+///             bucket_name: "backups".into(),
+///             subdir: Some("db1".into()),
+///             filename: "shard-2.rdb".into(),
+///             access_key_id: None,
+///             secret_access_key: None,
+///         },
+///     ])
+///     .flush(true)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct ImportRequest {
+    /// Sources to import, typically one RDB file per shard
+    pub dataset_import_sources: Vec<DatasetImportSource>,
+    /// Flush the database before importing
+    #[builder(default)]
+    pub flush: bool,
+    /// Email the cluster admins when the import finishes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub email_notification: Option<bool>,
+}
+
+impl ImportRequest {
+    /// Check that there is at least one source and that every source is valid
+    pub fn validate(&self) -> Result<()> {
+        if self.dataset_import_sources.is_empty() {
+            return Err(RestError::ValidationError(
+                "at least one import source is required".to_string(),
+            ));
+        }
+        self.dataset_import_sources
+            .iter()
+            .try_for_each(DatasetImportSource::validate)
+    }
+}
+
+/// Where to export a database to
+///
+/// Serialized as the typed `export_location` object the API expects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportDestination {
+    /// An FTP or FTPS URL; credentials may be embedded in the URL
+    #[serde(rename = "url")]
+    Ftp {
+        /// Location to write the export to
+        url: String,
+    },
+    /// An Amazon S3 (or S3-compatible) bucket
+    S3 {
+        /// Bucket name
+        #[serde(rename = "bucket_name")]
+        bucket: String,
+        /// Path inside the bucket
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdir: Option<String>,
+        /// Bucket region
+        #[serde(rename = "region_name", skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        /// Access key ID, when not using the cluster's IAM role
+        #[serde(rename = "access_key_id", skip_serializing_if = "Option::is_none")]
+        access_key: Option<String>,
+        /// Secret access key, required together with `access_key`
+        #[serde(rename = "secret_access_key", skip_serializing_if = "Option::is_none")]
+        secret: Option<String>,
+    },
+    /// An Azure Blob Storage container
+    #[serde(rename = "abs")]
+    AzureBlob {
+        /// Container name
+        container: String,
+        /// Path inside the container
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdir: Option<String>,
+        /// Storage account name
+        account_name: String,
+        /// Storage account key; set this or `sas_token`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        account_key: Option<String>,
+        /// Shared access signature token; set this or `account_key`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sas_token: Option<String>,
+    },
+    /// An OpenStack Swift container
+    Swift {
+        /// Keystone authentication URL
+        auth_url: String,
+        /// Swift user
+        user: String,
+        /// Swift key or password
+        key: String,
+        /// Container name
+        container: String,
+        /// Object name prefix
+        #[serde(skip_serializing_if = "Option::is_none")]
+        prefix: Option<String>,
+    },
+}
+
+impl ExportDestination {
+    /// Check the destination for missing or malformed fields before sending it
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| RestError::ValidationError(message);
+        let require = |field: &str, value: &str| {
+            if value.trim().is_empty() {
+                Err(invalid(format!(
+                    "export destination {} must not be empty",
+                    field
+                )))
+            } else {
+                Ok(())
+            }
+        };
+
+        match self {
+            ExportDestination::Ftp { url } => {
+                let parsed = url::Url::parse(url).map_err(|e| {
+                    invalid(format!("invalid export URL '{}': {}", redact_url(url), e))
+                })?;
+                if !matches!(parsed.scheme(), "ftp" | "ftps") {
+                    return Err(invalid(format!(
+                        "unsupported export URL scheme '{}' (expected ftp or ftps)",
+                        parsed.scheme()
+                    )));
+                }
+                Ok(())
+            }
+            ExportDestination::S3 {
+                bucket,
+                access_key,
+                secret,
+                ..
+            } => {
+                require("bucket", bucket)?;
+                if access_key.is_some() != secret.is_some() {
+                    return Err(invalid(
+                        "S3 access_key and secret must be set together".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            ExportDestination::AzureBlob {
+                container,
+                account_name,
+                account_key,
+                sas_token,
+                ..
+            } => {
+                require("container", container)?;
+                require("account_name", account_name)?;
+                if account_key.is_none() && sas_token.is_none() {
+                    return Err(invalid(
+                        "Azure Blob export needs an account_key or a sas_token".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            ExportDestination::Swift {
+                auth_url,
+                user,
+                key,
+                container,
+                ..
+            } => {
+                require("auth_url", auth_url)?;
+                require("user", user)?;
+                require("key", key)?;
+                require("container", container)
+            }
+        }
+    }
+}
+
+/// Request to export a database
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use redis_enterprise::bdb::{ExportDestination, ExportRequest};
+///
+/// let request = ExportRequest::builder()
+///     .export_location(ExportDestination::S3 {
+///         bucket: "backups".into(),
+///         subdir: Some("db1".into()),
+///         region: Some("us-east-1".into()),
+///         access_key: Some("AKIA...".into()),
+///         secret: Some("...".into()),
+///     })
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct ExportRequest {
+    /// Where to write the export
+    pub export_location: ExportDestination,
+    /// Email the cluster admins when the export finishes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub email_notification: Option<bool>,
+}
+
+/// Client certificate subject validation mode for mTLS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientCertSubjectValidation {
+    /// Accept any client certificate signed by a trusted CA
+    #[default]
+    Disabled,
+    /// Validate the certificate's SAN or CN against the authorized subjects
+    SanCn,
+    /// Validate the full certificate subject against the authorized subjects
+    FullSubject,
+}
+
+/// Certificate subject allowed to authenticate to a database
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct AuthorizedSubject {
+    /// Common name
+    #[serde(rename = "CN", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub common_name: Option<String>,
+    /// Organization
+    #[serde(rename = "O", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub organization: Option<String>,
+    /// Organizational units
+    #[serde(rename = "OU", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub organizational_units: Option<Vec<String>>,
+    /// Locality
+    #[serde(rename = "L", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub locality: Option<String>,
+    /// State or province
+    #[serde(rename = "ST", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub state: Option<String>,
+    /// Country code
+    #[serde(rename = "C", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub country: Option<String>,
+}
+
+/// mTLS client authentication settings for a database
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use redis_enterprise::bdb::{AuthorizedSubject, ClientCertSubjectValidation, MtlsConfig};
+///
+/// let config = MtlsConfig::builder()
+///     .validation_type(ClientCertSubjectValidation::SanCn)
+///     .authorized_subjects(vec![
+///         AuthorizedSubject::builder().common_name("app.example.com").build(),
+///     ])
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct MtlsConfig {
+    /// How client certificate subjects are validated
+    #[serde(rename = "client_cert_subject_validation_type", default)]
+    #[builder(default)]
+    pub validation_type: ClientCertSubjectValidation,
+    /// Subjects allowed to connect when validation is enabled
+    #[serde(default)]
+    #[builder(default)]
+    pub authorized_subjects: Vec<AuthorizedSubject>,
+}
+
+/// An external Redis source a database replicates from (Replica Of)
+///
+/// Only `uri` is required when configuring a source; `status`, `lag` and
+/// `last_error` are reported by the cluster once replication is running.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis_enterprise::bdb::ReplicaSource;
+///
+/// let source = ReplicaSource::builder()
+///     .uri("redis://:secret@legacy-redis.example.com:6379")
+///     .compression(2)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TypedBuilder)]
+pub struct ReplicaSource {
+    /// Source URI, e.g. `redis://:password@host:port`
+    #[builder(setter(into))]
+    pub uri: String,
+    /// Compression level for the replication link (0-6)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub compression: Option<u8>,
+    /// Whether the replication link uses TLS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub encryption: Option<bool>,
+    /// PEM certificate of the source server, for TLS links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub server_cert: Option<String>,
+    /// SNI name presented to the source, for TLS links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub tls_sni_name: Option<String>,
+    /// Sync status (e.g. "syncing", "in-sync", "out-of-sync")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub status: Option<String>,
+    /// Replication lag in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub lag: Option<i64>,
+    /// Last replication error reported for this source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub last_error: Option<String>,
+}
+
+/// The typed `replica_sources` of a database
+fn replica_sources(db: &DatabaseInfo) -> Result<Vec<ReplicaSource>> {
+    db.replica_sources
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|source| Ok(serde_json::from_value(source)?))
+        .collect()
+}
+
+/// Reply from the database command passthrough (`/v1/bdbs/{uid}/command`)
+///
+/// The endpoint wraps the Redis reply as `{"response": ...}`, with RESP types
+/// mapped to their JSON equivalents. Error replies come back as `{"error": ...}`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandResult {
+    /// Successful reply
+    Ok(Value),
+    /// Error reply, e.g. `ERR unknown command`
+    Error(String),
+}
+
+impl CommandResult {
+    /// Build a result from the raw passthrough response
+    pub fn from_value(value: Value) -> Self {
+        match value {
+            Value::Object(mut map) => {
+                if let Some(error) = map.remove("error") {
+                    return CommandResult::Error(match error {
+                        Value::String(message) => message,
+                        other => other.to_string(),
+                    });
+                }
+                match map.remove("response") {
+                    Some(response) => CommandResult::Ok(response),
+                    None => CommandResult::Ok(Value::Object(map)),
+                }
+            }
+            other => CommandResult::Ok(other),
+        }
+    }
+
+    /// Whether the command succeeded
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CommandResult::Ok(_))
+    }
+
+    /// Whether the command returned an error reply
+    pub fn is_error(&self) -> bool {
+        matches!(self, CommandResult::Error(_))
+    }
+
+    /// The error message of an error reply
+    pub fn error_message(&self) -> Option<&str> {
+        match self {
+            CommandResult::Error(message) => Some(message),
+            CommandResult::Ok(_) => None,
+        }
+    }
+
+    /// The reply value of a successful command
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            CommandResult::Ok(value) => Some(value),
+            CommandResult::Error(_) => None,
+        }
+    }
+
+    /// The reply as a string (simple or bulk string replies)
+    pub fn as_str(&self) -> Option<&str> {
+        self.value().and_then(Value::as_str)
+    }
+
+    /// The reply as an integer (integer replies, e.g. `DBSIZE`)
+    pub fn as_integer(&self) -> Option<i64> {
+        self.value().and_then(Value::as_i64)
+    }
+
+    /// The reply as an array (array replies, e.g. `KEYS`)
+    pub fn as_array(&self) -> Option<&[Value]> {
+        self.value().and_then(Value::as_array).map(Vec::as_slice)
+    }
+}
+
+/// A Redis reply from the command passthrough, modelling RESP3 types
+///
+/// The passthrough maps RESP3 replies onto plain JSON, so decoding is purely
+/// structural: objects become [`RedisValue::Map`], arrays [`RedisValue::Array`],
+/// and integers too large for `i64` [`RedisValue::BigNumber`]. Sets and pushes
+/// arrive as arrays and verbatim strings as strings; those variants are never
+/// produced by decoding and exist for values built by callers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RedisValue {
+    /// Null reply
+    Nil,
+    /// Simple or bulk string
+    String(String),
+    /// Error reply, e.g. `ERR unknown command`
+    Error(String),
+    /// Integer reply
+    Integer(i64),
+    /// Double reply (may be `inf`, `-inf` or `nan`)
+    Double(f64),
+    /// Boolean reply
+    Boolean(bool),
+    /// Big number reply, as its decimal digits
+    BigNumber(String),
+    /// Verbatim string with its three-letter format, e.g. `txt`
+    Verbatim { format: String, text: String },
+    /// Array reply
+    Array(Vec<RedisValue>),
+    /// Set reply
+    Set(Vec<RedisValue>),
+    /// Push (out-of-band) reply
+    Push(Vec<RedisValue>),
+    /// Map reply
+    ///
+    /// Entries keep server order when decoded from the response body (as
+    /// [`EnterpriseClient::execute_command_typed`](crate::EnterpriseClient::execute_command_typed)
+    /// does); a [`Value`] object has already sorted its keys.
+    Map(Vec<(RedisValue, RedisValue)>),
+}
+
+impl RedisValue {
+    /// Build a value from the raw passthrough response
+    ///
+    /// Unwraps the `{"response": ...}` envelope; `{"error": ...}` becomes
+    /// [`RedisValue::Error`].
+    pub fn from_response(value: Value) -> Self {
+        match CommandResult::from_value(value) {
+            CommandResult::Ok(reply) => RedisValue::from_value(reply),
+            CommandResult::Error(message) => RedisValue::Error(message),
+        }
+    }
+
+    /// Decode a single reply value
+    pub fn from_value(value: Value) -> Self {
+        match value {
+            Value::Null => RedisValue::Nil,
+            Value::Bool(b) => RedisValue::Boolean(b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => RedisValue::Integer(i),
+                None if n.is_u64() => RedisValue::BigNumber(n.to_string()),
+                None => RedisValue::Double(n.as_f64().unwrap_or(f64::NAN)),
+            },
+            Value::String(s) => RedisValue::String(s),
+            Value::Array(items) => RedisValue::Array(Self::decode_list(items)),
+            Value::Object(map) => RedisValue::Map(
+                map.into_iter()
+                    .map(|(k, v)| (RedisValue::String(k), RedisValue::from_value(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn decode_list(items: Vec<Value>) -> Vec<RedisValue> {
+        items.into_iter().map(RedisValue::from_value).collect()
+    }
+
+    /// Unwrap the passthrough envelope from a reply decoded off the response body
+    ///
+    /// Mirrors [`RedisValue::from_response`] without going through [`Value`], so
+    /// map entries keep server order.
+    pub(crate) fn into_reply(self) -> Self {
+        let RedisValue::Map(mut entries) = self else {
+            return self;
+        };
+        let mut take = |key: &str| {
+            let index = entries.iter().position(|(k, _)| k.as_str() == Some(key))?;
+            Some(entries.remove(index).1)
+        };
+        if let Some(error) = take("error") {
+            return RedisValue::Error(match error {
+                RedisValue::String(message) => message,
+                other => format!("{:?}", other),
+            });
+        }
+        match take("response") {
+            Some(response) => response,
+            None => RedisValue::Map(entries),
+        }
+    }
+
+    /// Whether this is a null reply
+    pub fn is_nil(&self) -> bool {
+        matches!(self, RedisValue::Nil)
+    }
+
+    /// Whether this is an error reply
+    pub fn is_error(&self) -> bool {
+        matches!(self, RedisValue::Error(_))
+    }
+
+    /// The error message of an error reply
+    pub fn error_message(&self) -> Option<&str> {
+        match self {
+            RedisValue::Error(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// The reply as a string (simple, bulk and verbatim strings)
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            RedisValue::String(s) => Some(s),
+            RedisValue::Verbatim { text, .. } => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The reply as an integer
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            RedisValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The reply as a double (doubles and integers)
+    pub fn as_double(&self) -> Option<f64> {
+        match self {
+            RedisValue::Double(d) => Some(*d),
+            RedisValue::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    /// The reply as a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            RedisValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// The elements of an array, set or push reply
+    pub fn as_sequence(&self) -> Option<&[RedisValue]> {
+        match self {
+            RedisValue::Array(items) | RedisValue::Set(items) | RedisValue::Push(items) => {
+                Some(items)
+            }
+            _ => None,
+        }
+    }
+
+    /// The entries of a map reply
+    pub fn as_map(&self) -> Option<&[(RedisValue, RedisValue)]> {
+        match self {
+            RedisValue::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Look up a string key in a map reply
+    pub fn get(&self, key: &str) -> Option<&RedisValue> {
+        self.as_map()?
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, v)| v)
+    }
+
+    /// The reply as string pairs, e.g. for `CONFIG GET`
+    ///
+    /// Accepts a RESP3 map, or the flat `[key, value, ...]` array RESP2 returns.
+    pub fn to_string_map(&self) -> Option<BTreeMap<String, String>> {
+        fn scalar(value: &RedisValue) -> Option<String> {
+            match value {
+                RedisValue::Integer(i) => Some(i.to_string()),
+                RedisValue::Double(d) => Some(d.to_string()),
+                RedisValue::Boolean(b) => Some(b.to_string()),
+                RedisValue::BigNumber(n) => Some(n.clone()),
+                other => other.as_str().map(str::to_owned),
+            }
+        }
+
+        match self {
+            RedisValue::Map(entries) => entries
+                .iter()
+                .map(|(k, v)| Some((scalar(k)?, scalar(v)?)))
+                .collect(),
+            RedisValue::Array(items) if items.len() % 2 == 0 => items
+                .chunks(2)
+                .map(|pair| Some((scalar(&pair[0])?, scalar(&pair[1])?)))
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RedisValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct ReplyVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ReplyVisitor {
+            type Value = RedisValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a JSON-encoded Redis reply")
+            }
+
+            fn visit_unit<E>(self) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::Nil)
+            }
+
+            fn visit_none<E>(self) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::Nil)
+            }
+
+            fn visit_some<D: serde::Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> std::result::Result<RedisValue, D::Error> {
+                RedisValue::deserialize(deserializer)
+            }
+
+            fn visit_bool<E>(self, b: bool) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::Boolean(b))
+            }
+
+            fn visit_i64<E>(self, i: i64) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::Integer(i))
+            }
+
+            fn visit_u64<E>(self, u: u64) -> std::result::Result<RedisValue, E> {
+                Ok(match i64::try_from(u) {
+                    Ok(i) => RedisValue::Integer(i),
+                    Err(_) => RedisValue::BigNumber(u.to_string()),
+                })
+            }
+
+            fn visit_f64<E>(self, d: f64) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::Double(d))
+            }
+
+            fn visit_str<E>(self, s: &str) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::String(s.to_string()))
+            }
+
+            fn visit_string<E>(self, s: String) -> std::result::Result<RedisValue, E> {
+                Ok(RedisValue::String(s))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> std::result::Result<RedisValue, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(RedisValue::Array(items))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<RedisValue, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((key, value)) = map.next_entry::<String, RedisValue>()? {
+                    entries.push((RedisValue::String(key), value));
+                }
+                Ok(RedisValue::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ReplyVisitor)
+    }
+}
+
+/// An entry of a database's slow log, as returned by `SLOWLOG GET`
+///
+/// Returned by [`DatabaseHandler::slowlog`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlowlogEntry {
+    /// Entry ID, increasing for every slow command
+    pub id: i64,
+    /// When the command was run
+    pub timestamp: DateTime<Utc>,
+    /// Execution time, in microseconds
+    pub duration_us: u64,
+    /// The command and its arguments; arguments are `?` when `sanitized`
+    pub command: Vec<String>,
+    /// Client address (`ip:port`), when reported
+    pub client: Option<String>,
+    /// Client name set with `CLIENT SETNAME`, when reported
+    pub client_name: Option<String>,
+    /// Argument values were withheld because the cluster sanitizes the slow log
+    pub sanitized: bool,
+}
+
+impl SlowlogEntry {
+    /// The command name, e.g. `HGETALL`
+    pub fn command_name(&self) -> Option<&str> {
+        self.command.first().map(String::as_str)
+    }
+
+    /// Decode one `SLOWLOG GET` entry, redacting arguments if `sanitize` is set
+    fn from_reply(reply: &RedisValue, sanitize: bool) -> Result<Self> {
+        let invalid = || RestError::ParseError(format!("Invalid slowlog entry: {:?}", reply));
+        let fields = reply.as_sequence().ok_or_else(invalid)?;
+        let integer = |index: usize| -> Option<i64> {
+            match fields.get(index)? {
+                RedisValue::Integer(i) => Some(*i),
+                other => other.as_str()?.parse().ok(),
+            }
+        };
+        let text = |index: usize| {
+            fields
+                .get(index)
+                .and_then(RedisValue::as_str)
+                .filter(|s| !s.is_empty())
+                .map(str::to_owned)
+        };
+
+        let id = integer(0).ok_or_else(invalid)?;
+        let timestamp = integer(1)
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .ok_or_else(invalid)?;
+        let duration_us = integer(2)
+            .and_then(|us| u64::try_from(us).ok())
+            .ok_or_else(invalid)?;
+        let mut command: Vec<String> = fields
+            .get(3)
+            .and_then(RedisValue::as_sequence)
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|arg| match arg {
+                RedisValue::Integer(i) => i.to_string(),
+                other => other.as_str().unwrap_or_default().to_string(),
+            })
+            .collect();
+        if sanitize {
+            command
+                .iter_mut()
+                .skip(1)
+                .for_each(|arg| *arg = "?".to_string());
+        }
+
+        Ok(SlowlogEntry {
+            id,
+            timestamp,
+            duration_us,
+            command,
+            client: text(4),
+            client_name: text(5),
+            sanitized: sanitize,
+        })
+    }
+}
+
+/// A client connection to a database, parsed from a `CLIENT LIST` line
+///
+/// Returned by [`DatabaseHandler::client_list`]. Fields not named here stay in
+/// `extra` as the raw strings Redis reports.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClientConnection {
+    /// Client ID
+    pub id: Option<u64>,
+    /// Client address (`ip:port`)
+    pub addr: Option<String>,
+    /// Name set with `CLIENT SETNAME`
+    pub name: Option<String>,
+    /// Connection age, in seconds
+    pub age: Option<u64>,
+    /// Seconds since the last command
+    pub idle: Option<u64>,
+    /// Client flags, e.g. `N` for a normal client
+    pub flags: Option<String>,
+    /// Selected database number
+    pub db: Option<u32>,
+    /// Last command run, e.g. `client|list`
+    pub cmd: Option<String>,
+    /// ACL user of the connection
+    pub user: Option<String>,
+    /// Every other field, by name
+    pub extra: BTreeMap<String, String>,
+}
+
+impl ClientConnection {
+    /// Parse one `key=value ...` line of `CLIENT LIST` output
+    pub fn parse_line(line: &str) -> Self {
+        let mut connection = ClientConnection::default();
+        for (key, value) in line
+            .split_whitespace()
+            .filter_map(|field| field.split_once('='))
+        {
+            let text = || (!value.is_empty()).then(|| value.to_string());
+            match key {
+                "id" => connection.id = value.parse().ok(),
+                "addr" => connection.addr = text(),
+                "name" => connection.name = text(),
+                "age" => connection.age = value.parse().ok(),
+                "idle" => connection.idle = value.parse().ok(),
+                "flags" => connection.flags = text(),
+                "db" => connection.db = value.parse().ok(),
+                "cmd" => connection.cmd = text(),
+                "user" => connection.user = text(),
+                _ => {
+                    connection.extra.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+        connection
+    }
+}
+
+/// Filter and page for [`DatabaseHandler::client_list_with`]
+#[derive(Debug, Clone, Default, TypedBuilder)]
+pub struct ClientListQuery {
+    /// Only list clients of this type: `normal`, `master`, `replica` or `pubsub`
+    #[builder(default, setter(into, strip_option))]
+    pub client_type: Option<String>,
+    /// Skip this many connections
+    #[builder(default)]
+    pub offset: usize,
+    /// Return at most this many connections
+    #[builder(default, setter(strip_option))]
+    pub limit: Option<usize>,
+}
+
+/// Database statuses from which creation will not become active
+const DATABASE_FAILURE_STATES: &[&str] = &["creation-failed", "error"];
+
 /// Database handler for executing database commands
 pub struct DatabaseHandler {
     client: RestClient,
@@ -561,7 +2370,176 @@ impl DatabaseHandler {
         self.client.post("/v1/bdbs", &request).await
     }
 
+    /// Create a new database after [validating](CreateDatabaseRequest::validate) the request
+    ///
+    /// An invalid request is rejected with a `ValidationError` without being sent.
+    pub async fn create_checked(&self, request: CreateDatabaseRequest) -> Result<DatabaseInfo> {
+        request.validate()?;
+        self.create(request).await
+    }
+
+    /// Check a create request against the cluster without creating anything
+    ///
+    /// Reads the license and the latest cluster stats, then reports every
+    /// problem found: missing fields, unknown eviction policy or persistence
+    /// mode, an `allkeys-*` eviction policy on a persistent database, more
+    /// shards than the license has left, and more memory (including
+    /// replicas) than the cluster can provision. An empty list means the
+    /// request is expected to fit; the cluster may still reject it for reasons
+    /// not checked here.
+    pub async fn validate_create(
+        &self,
+        request: &CreateDatabaseRequest,
+    ) -> Result<Vec<ValidationIssue>> {
+        let license = LicenseHandler::new(self.client.clone());
+        let stats = StatsHandler::new(self.client.clone());
+        let (license, snapshot) = try_join!(license.get(), stats.cluster_last_typed())?;
+
+        let mut issues = request.required_field_issues();
+        if let Some(policy) = request.eviction_policy.as_deref()
+            && !EVICTION_POLICIES.contains(&policy)
+        {
+            issues.push(ValidationIssue::new(
+                "eviction_policy",
+                format!(
+                    "unknown policy '{}'; expected one of {}",
+                    policy,
+                    EVICTION_POLICIES.join(", ")
+                ),
+            ));
+        }
+        if let Some(persistence) = request.persistence.as_deref()
+            && !PERSISTENCE_MODES.contains(&persistence)
+        {
+            issues.push(ValidationIssue::new(
+                "persistence",
+                format!(
+                    "unknown mode '{}'; expected one of {}",
+                    persistence,
+                    PERSISTENCE_MODES.join(", ")
+                ),
+            ));
+        }
+        // A persistent database keeps its data; evicting any key under memory
+        // pressure silently drops persisted data
+        if let (Some(policy), Some(persistence @ ("aof" | "snapshot"))) = (
+            request.eviction_policy.as_deref(),
+            request.persistence.as_deref(),
+        ) && policy.starts_with("allkeys-")
+        {
+            issues.push(ValidationIssue::new(
+                "eviction_policy",
+                format!(
+                    "'{}' can evict any key, which loses data persisted with '{}'; \
+                     use noeviction or a volatile-* policy",
+                    policy, persistence
+                ),
+            ));
+        }
+
+        let copies = if request.replication.unwrap_or(false) {
+            2
+        } else {
+            1
+        };
+        let shards = request.shards_count.or(request.shard_count).unwrap_or(1) * copies;
+        if license.expired {
+            issues.push(ValidationIssue::new(
+                "license",
+                "the cluster license has expired",
+            ));
+        }
+        // Each limit is checked against its own usage: RAM shards against the
+        // RAM shard limit, all shards against the overall limit
+        let all_in_use = license
+            .ram_shards_in_use
+            .zip(license.flash_shards_in_use)
+            .map(|(ram, flash)| ram + flash)
+            .or(license.ram_shards_in_use);
+        for (limit, in_use) in [
+            (license.ram_shards_limit, license.ram_shards_in_use),
+            (license.shards_limit, all_in_use),
+        ] {
+            let (Some(limit), Some(in_use)) = (limit, in_use) else {
+                continue;
+            };
+            let available = limit.saturating_sub(in_use);
+            if shards > available {
+                issues.push(ValidationIssue::new(
+                    "shards_count",
+                    format!(
+                        "needs {} shards but the license allows {} more ({} of {} in use)",
+                        shards, available, in_use, limit
+                    ),
+                ));
+                break;
+            }
+        }
+
+        let available_memory = ["provisional_memory", "free_memory"]
+            .iter()
+            .find_map(|metric| match snapshot.extra.get(*metric)? {
+                Value::Number(n) => n.as_f64(),
+                Value::String(s) => s.trim().parse().ok(),
+                _ => None,
+            });
+        if let (Some(memory_size), Some(available)) = (request.memory_size, available_memory) {
+            let needed = memory_size.saturating_mul(copies as u64);
+            if needed as f64 > available {
+                issues.push(ValidationIssue::new(
+                    "memory_size",
+                    format!(
+                        "needs {} bytes but the cluster can provision {} bytes",
+                        needed, available as u64
+                    ),
+                ));
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Create a database and wait until it is active
+    ///
+    /// Creates the database, then polls it every `poll_interval` until its
+    /// status is `active`. Fails early with [`RestError::ServerError`] if
+    /// creation fails on the cluster, and with [`RestError::Timeout`] if the
+    /// database is not active within `timeout`.
+    pub async fn create_and_wait(
+        &self,
+        request: CreateDatabaseRequest,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<DatabaseInfo> {
+        let created = self.create(request).await?;
+        let uid = created.uid;
+
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let db = self.info(uid).await?;
+            match db.status.as_deref() {
+                Some("active") => return Ok(db),
+                Some(status) if DATABASE_FAILURE_STATES.contains(&status) => {
+                    return Err(RestError::ServerError {
+                        message: format!("Database {} entered state '{}'", uid, status),
+                        request_id: None,
+                    });
+                }
+                _ => {}
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Update database configuration (BDB.UPDATE)
+    ///
+    /// The API offers no conditional update (no `ETag`/`If-Match` or version
+    /// precondition on `/v1/bdbs`), so concurrent read-modify-write cycles can
+    /// overwrite each other; the last `PUT` wins. A `409` or `412` from the
+    /// cluster is returned as [`RestError::Conflict`].
     pub async fn update(&self, uid: u32, updates: Value) -> Result<DatabaseInfo> {
         self.client
             .put(&format!("/v1/bdbs/{}", uid), &updates)
@@ -573,6 +2551,38 @@ impl DatabaseHandler {
         self.client.delete(&format!("/v1/bdbs/{}", uid)).await
     }
 
+    /// List databases whose memory usage exceeds a ratio of their memory limit
+    ///
+    /// Returns each matching database with its `memory_used / memory_size` ratio,
+    /// sorted from highest to lowest usage. Databases that do not report both
+    /// `memory_used` and a non-zero `memory_size` are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use redis_enterprise::EnterpriseClient;
+    /// # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// for (db, usage) in client.databases().over_memory_threshold(0.8).await? {
+    ///     println!("{} is at {:.0}% of its memory limit", db.name, usage * 100.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn over_memory_threshold(&self, ratio: f64) -> Result<Vec<(DatabaseInfo, f64)>> {
+        let mut over: Vec<(DatabaseInfo, f64)> = self
+            .list()
+            .await?
+            .into_iter()
+            .filter_map(|db| {
+                let used = db.memory_used? as f64;
+                let limit = db.memory_size.filter(|size| *size > 0)? as f64;
+                let usage = used / limit;
+                (usage > ratio).then_some((db, usage))
+            })
+            .collect();
+        over.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(over)
+    }
+
     /// Get database stats (BDB.STATS)
     pub async fn stats(&self, uid: u32) -> Result<Value> {
         self.client.get(&format!("/v1/bdbs/stats/{}", uid)).await
@@ -584,6 +2594,9 @@ impl DatabaseHandler {
     }
 
     /// Export database (BDB.EXPORT)
+    ///
+    /// Sends `export_location` as-is; use [`export_with`](Self::export_with)
+    /// for typed, locally validated destinations.
     pub async fn export(&self, uid: u32, export_location: &str) -> Result<ExportResponse> {
         let body = serde_json::json!({
             "export_location": export_location
@@ -593,7 +2606,21 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Export database to a typed destination (BDB.EXPORT)
+    ///
+    /// The destination is validated locally first, so a malformed destination
+    /// fails with `ValidationError` without calling the API.
+    pub async fn export_with(&self, uid: u32, request: ExportRequest) -> Result<ExportResponse> {
+        request.export_location.validate()?;
+        self.client
+            .post(&format!("/v1/bdbs/{}/actions/export", uid), &request)
+            .await
+    }
+
     /// Import database (BDB.IMPORT)
+    ///
+    /// Sends `import_location` as-is; use [`import_with`](Self::import_with)
+    /// for typed, locally validated sources.
     pub async fn import(
         &self,
         uid: u32,
@@ -609,6 +2636,17 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Import database from one or more sources (BDB.IMPORT)
+    ///
+    /// The request is validated locally first, so a malformed source fails with
+    /// `ValidationError` without calling the API.
+    pub async fn import_with(&self, uid: u32, request: ImportRequest) -> Result<ImportResponse> {
+        request.validate()?;
+        self.client
+            .post(&format!("/v1/bdbs/{}/actions/import", uid), &request)
+            .await
+    }
+
     /// Flush database (BDB.FLUSH)
     pub async fn flush(&self, uid: u32) -> Result<DatabaseActionResponse> {
         self.client
@@ -629,6 +2667,13 @@ impl DatabaseHandler {
             .await
     }
 
+    /// List past backups of a database - GET /v1/bdbs/{uid}/backups
+    ///
+    /// Failed backups are included, with their `backup_failure_reason`.
+    pub async fn backup_history(&self, uid: u32) -> Result<Vec<BackupRecord>> {
+        self.client.get(&format!("/v1/bdbs/{}/backups", uid)).await
+    }
+
     /// Restore database from backup (BDB.RESTORE)
     pub async fn restore(
         &self,
@@ -667,6 +2712,20 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Preview the placement `optimize_shards_placement` proposes, without applying it
+    ///
+    /// Fetches the proposed blueprint together with the current shards and
+    /// lists the shards that would move.
+    pub async fn optimize_preview(&self, uid: u32) -> Result<ShardPlacementPlan> {
+        let blueprint_path = format!("/v1/bdbs/{}/actions/optimize_shards_placement", uid);
+        let shards_path = format!("/v1/bdbs/{}/shards", uid);
+        let (blueprint, shards) = try_join!(
+            self.client.get::<ShardsBlueprintResponse>(&blueprint_path),
+            self.client.get::<Vec<Shard>>(&shards_path),
+        )?;
+        Ok(ShardPlacementPlan::new(blueprint.shards_blueprint, &shards))
+    }
+
     /// Recover database (status) - GET
     pub async fn recover_status(&self, uid: u32) -> Result<Value> {
         self.client
@@ -714,6 +2773,22 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Preview a rebalance without moving any shards - PUT with `dry_run=true`
+    ///
+    /// Returns the placement the rebalance would produce and the shards that
+    /// would move. Use [`rebalance`](Self::rebalance) to apply it.
+    pub async fn rebalance_dry_run(&self, uid: u32) -> Result<ShardPlacementPlan> {
+        let rebalance_path = format!("/v1/bdbs/{}/actions/rebalance?dry_run=true", uid);
+        let shards_path = format!("/v1/bdbs/{}/shards", uid);
+        let body = serde_json::json!({});
+        let (blueprint, shards) = try_join!(
+            self.client
+                .put::<_, ShardsBlueprintResponse>(&rebalance_path, &body),
+            self.client.get::<Vec<Shard>>(&shards_path),
+        )?;
+        Ok(ShardPlacementPlan::new(blueprint.shards_blueprint, &shards))
+    }
+
     /// Revamp database - PUT
     pub async fn revamp(&self, uid: u32) -> Result<DatabaseActionResponse> {
         self.client
@@ -974,6 +3049,178 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Get the mTLS client authentication settings of a database
+    pub async fn mtls_config(&self, uid: u32) -> Result<MtlsConfig> {
+        self.client.get(&format!("/v1/bdbs/{}", uid)).await
+    }
+
+    /// Configure mTLS client certificate subject validation for a database
+    pub async fn configure_mtls(&self, uid: u32, config: MtlsConfig) -> Result<DatabaseInfo> {
+        self.client.put(&format!("/v1/bdbs/{}", uid), &config).await
+    }
+
+    /// Execute a Redis command on a database and return a typed reply
+    ///
+    /// See [`EnterpriseClient::execute_command`](crate::EnterpriseClient::execute_command)
+    /// for the raw response.
+    pub async fn command(&self, uid: u32, command: &str) -> Result<CommandResult> {
+        let response = self.client.execute_command(uid, command).await?;
+        Ok(CommandResult::from_value(response))
+    }
+
+    /// Get the `count` most recent slow log entries of a database, newest first
+    ///
+    /// Runs `SLOWLOG GET` through the command passthrough. When the cluster
+    /// reports `slowlog_in_sanitized_support`, argument values are replaced with
+    /// `?` so only command names are exposed.
+    pub async fn slowlog(&self, uid: u32, count: u32) -> Result<Vec<SlowlogEntry>> {
+        let count = count.to_string();
+        let args = ["SLOWLOG", "GET", count.as_str()];
+        let (cluster, reply) = try_join!(
+            self.client.get::<ClusterInfo>("/v1/cluster"),
+            self.client.execute_command_typed(uid, &args),
+        )?;
+        if let Some(message) = reply.error_message() {
+            return Err(RestError::ServerError {
+                message: format!("SLOWLOG GET failed on database {}: {}", uid, message),
+                request_id: None,
+            });
+        }
+        let sanitize = cluster.slowlog_in_sanitized_support.unwrap_or(false);
+        reply
+            .as_sequence()
+            .ok_or_else(|| RestError::ParseError(format!("Invalid slowlog reply: {:?}", reply)))?
+            .iter()
+            .map(|entry| SlowlogEntry::from_reply(entry, sanitize))
+            .collect()
+    }
+
+    /// List the client connections of a database
+    ///
+    /// Runs `CLIENT LIST` through the command passthrough.
+    pub async fn client_list(&self, uid: u32) -> Result<Vec<ClientConnection>> {
+        self.client_list_with(uid, ClientListQuery::default()).await
+    }
+
+    /// List the client connections of a database, filtered by type and paged
+    ///
+    /// The type filter is applied by Redis (`CLIENT LIST TYPE ...`); `offset`
+    /// and `limit` are applied to the returned list, so large lists are still
+    /// transferred in full but only the requested page is parsed.
+    pub async fn client_list_with(
+        &self,
+        uid: u32,
+        query: ClientListQuery,
+    ) -> Result<Vec<ClientConnection>> {
+        let mut args = vec!["CLIENT", "LIST"];
+        if let Some(client_type) = query.client_type.as_deref() {
+            args.extend(["TYPE", client_type]);
+        }
+        let reply = self.client.execute_command_typed(uid, &args).await?;
+        if let Some(message) = reply.error_message() {
+            return Err(RestError::ServerError {
+                message: format!("CLIENT LIST failed on database {}: {}", uid, message),
+                request_id: None,
+            });
+        }
+        let text = reply.as_str().ok_or_else(|| {
+            RestError::ParseError(format!("Invalid CLIENT LIST reply: {:?}", reply))
+        })?;
+        Ok(text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .skip(query.offset)
+            .take(query.limit.unwrap_or(usize::MAX))
+            .map(ClientConnection::parse_line)
+            .collect())
+    }
+
+    /// Set the Redis commands disabled on a database
+    ///
+    /// Commands are sent as the comma-separated string the API expects. Pass an
+    /// empty list to re-enable all commands.
+    pub async fn set_disabled_commands(
+        &self,
+        uid: u32,
+        commands: Vec<String>,
+    ) -> Result<DatabaseInfo> {
+        let body = serde_json::json!({
+            "disabled_commands": commands.join(",")
+        });
+        self.client.put(&format!("/v1/bdbs/{}", uid), &body).await
+    }
+
+    /// Sync status of a database's replica sources
+    ///
+    /// Each source carries its `status`, replication `lag` and `last_error`
+    /// as reported by the cluster.
+    pub async fn replica_sync_status(&self, uid: u32) -> Result<Vec<ReplicaSource>> {
+        replica_sources(&self.info(uid).await?)
+    }
+
+    /// Seed a database from external Redis sources and wait for the initial sync
+    ///
+    /// Configures `sources` as the database's replica sources with sync enabled,
+    /// then polls every `poll` until every source reports `in-sync`. Returns the
+    /// synced database; see [`replica_sync_status`](Self::replica_sync_status)
+    /// for the per-source lag. Fails with [`RestError::ServerError`] as soon as
+    /// a source reports `out-of-sync` or an error, or a `last_error`, and with
+    /// [`RestError::Timeout`] if sync does not complete within `timeout`.
+    pub async fn recover_from_replica_and_wait(
+        &self,
+        uid: u32,
+        sources: Vec<ReplicaSource>,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<DatabaseInfo> {
+        if sources.is_empty() {
+            return Err(RestError::ValidationError(
+                "at least one replica source is required".to_string(),
+            ));
+        }
+
+        let body = serde_json::json!({
+            "replica_sources": sources,
+            "replica_sync": "enabled"
+        });
+        let _: DatabaseInfo = self.client.put(&format!("/v1/bdbs/{}", uid), &body).await?;
+
+        let mut delays = Backoff::fixed(poll).timeout(timeout);
+        loop {
+            let db = self.info(uid).await?;
+            let statuses = replica_sources(&db)?;
+            // Source URIs may embed passwords, so sources are named by position
+            for (index, source) in statuses.iter().enumerate() {
+                let status = source.status.as_deref().unwrap_or("unknown");
+                let last_error = source.last_error.as_deref().filter(|e| !e.is_empty());
+                if matches!(status, "out-of-sync" | "error" | "failed") || last_error.is_some() {
+                    return Err(RestError::ServerError {
+                        message: format!(
+                            "Replica source {} of database {} is {}: {}",
+                            index + 1,
+                            uid,
+                            status,
+                            last_error.unwrap_or("no error detail reported")
+                        ),
+                        request_id: None,
+                    });
+                }
+            }
+            if !statuses.is_empty()
+                && statuses
+                    .iter()
+                    .all(|source| source.status.as_deref() == Some("in-sync"))
+            {
+                return Ok(db);
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Check database availability
     pub async fn availability(&self, uid: u32) -> Result<Value> {
         self.client
@@ -988,11 +3235,53 @@ impl DatabaseHandler {
             .await
     }
 
+    /// Check whether a database can serve traffic
+    ///
+    /// Unlike [`availability`](Self::availability), an unavailable database is
+    /// an `Ok` result with `available: false` and the cluster's reason, which
+    /// suits readiness probes.
+    pub async fn availability_status(&self, uid: u32) -> Result<AvailabilityStatus> {
+        let (status, body) = self
+            .client
+            .get_accepting(
+                &format!("/v1/bdbs/{}/availability", uid),
+                &[reqwest::StatusCode::SERVICE_UNAVAILABLE],
+            )
+            .await?;
+        AvailabilityStatus::from_response(status, body)
+    }
+
+    /// Check whether the database endpoint on the node serving the request is available
+    ///
+    /// Typed counterpart of [`endpoint_availability`](Self::endpoint_availability);
+    /// see [`availability_status`](Self::availability_status).
+    pub async fn endpoint_availability_status(&self, uid: u32) -> Result<AvailabilityStatus> {
+        let (status, body) = self
+            .client
+            .get_accepting(
+                &format!("/v1/local/bdbs/{}/endpoint/availability", uid),
+                &[reqwest::StatusCode::SERVICE_UNAVAILABLE],
+            )
+            .await?;
+        AvailabilityStatus::from_response(status, body)
+    }
+
     /// Create database using v2 API (supports recovery plan)
+    ///
+    /// Takes the raw request body; prefer [`create_v2_with`](Self::create_v2_with)
+    /// unless you need fields it does not model.
     pub async fn create_v2(&self, request: Value) -> Result<DatabaseInfo> {
         self.client.post("/v2/bdbs", &request).await
     }
 
+    /// Create database using the v2 API with a typed request
+    ///
+    /// The request is [validated](CreateDatabaseV2Request::validate) before it is sent.
+    pub async fn create_v2_with(&self, request: CreateDatabaseV2Request) -> Result<DatabaseInfo> {
+        request.validate()?;
+        self.client.post("/v2/bdbs", &request).await
+    }
+
     /// Watch database status changes in real-time
     ///
     /// Polls the database endpoint and yields updates when status changes occur.
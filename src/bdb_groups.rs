@@ -6,7 +6,7 @@
 //! - Query group membership
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use serde::{Deserialize, Serialize};
 
 /// Database group information
@@ -56,6 +56,62 @@ impl BdbGroupsHandler {
     pub async fn delete(&self, uid: u32) -> Result<()> {
         self.client.delete(&format!("/v1/bdb_groups/{}", uid)).await
     }
+
+    /// Add a database to a group, returning the updated group
+    ///
+    /// Membership is a property of the database, so this sets the database's
+    /// `group_uid` and then re-reads the group. A database can only belong to
+    /// one group; adding a member of another group fails with
+    /// [`RestError::Conflict`].
+    pub async fn add_database(&self, group_uid: u32, bdb_uid: u32) -> Result<BdbGroup> {
+        self.set_database_group(bdb_uid, serde_json::json!(group_uid))
+            .await
+            .map_err(|err| membership_conflict(err, group_uid, bdb_uid))?;
+        self.get(group_uid).await
+    }
+
+    /// Remove a database from a group, returning the updated group
+    ///
+    /// Fails with [`RestError::NotFound`] if the database is not a member of
+    /// the group, rather than clearing its membership of another group.
+    pub async fn remove_database(&self, group_uid: u32, bdb_uid: u32) -> Result<BdbGroup> {
+        let group = self.get(group_uid).await?;
+        let member = bdb_uid.to_string();
+        if !group.members.unwrap_or_default().contains(&member) {
+            return Err(RestError::NotFound { request_id: None });
+        }
+        self.set_database_group(bdb_uid, serde_json::Value::Null)
+            .await?;
+        self.get(group_uid).await
+    }
+
+    /// Set a database's `group_uid`, `null` to leave its group
+    async fn set_database_group(&self, bdb_uid: u32, group_uid: serde_json::Value) -> Result<()> {
+        self.client
+            .put_raw(
+                &format!("/v1/bdbs/{}", bdb_uid),
+                serde_json::json!({ "group_uid": group_uid }),
+            )
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Explain a membership conflict, keeping the server's message and request ID
+fn membership_conflict(err: RestError, group_uid: u32, bdb_uid: u32) -> RestError {
+    match err {
+        RestError::Conflict {
+            message,
+            request_id,
+        } => RestError::Conflict {
+            message: format!(
+                "database {} cannot be added to group {} (it may already belong to another group): {}",
+                bdb_uid, group_uid, message
+            ),
+            request_id,
+        },
+        other => other,
+    }
 }
 
 /// Request to create a new database group
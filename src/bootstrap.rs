@@ -6,9 +6,12 @@
 //! - Configure initial settings
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
 use typed_builder::TypedBuilder;
 
 /// Bootstrap configuration for cluster initialization
@@ -111,6 +114,18 @@ pub struct BootstrapStatus {
     pub message: Option<String>,
 }
 
+impl BootstrapStatus {
+    /// Whether the bootstrap operation finished successfully
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+
+    /// Whether the bootstrap operation failed
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status.as_str(), "error" | "failed")
+    }
+}
+
 /// Bootstrap handler for cluster initialization
 pub struct BootstrapHandler {
     client: RestClient,
@@ -131,6 +146,43 @@ impl BootstrapHandler {
         self.client.get("/v1/bootstrap").await
     }
 
+    /// Poll bootstrap status until the operation completes
+    ///
+    /// Returns the final status once it is `completed`. A failed bootstrap is
+    /// reported as [`RestError::ServerError`] carrying the status message, and
+    /// [`RestError::Timeout`] is returned if the operation has not finished
+    /// within `timeout`.
+    pub async fn wait_until_done(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<BootstrapStatus> {
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let status = self.status().await?;
+            if status.is_completed() {
+                return Ok(status);
+            }
+            if status.is_failed() {
+                return Err(RestError::ServerError {
+                    message: format!(
+                        "Bootstrap {}: {}",
+                        status.status,
+                        status
+                            .message
+                            .as_deref()
+                            .unwrap_or("no error detail reported")
+                    ),
+                    request_id: None,
+                });
+            }
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Join node to existing cluster
     pub async fn join(&self, config: BootstrapConfig) -> Result<BootstrapStatus> {
         self.client.post("/v1/bootstrap/join", &config).await
@@ -5,7 +5,7 @@ use crate::alerts::AlertHandler;
 use crate::bdb::BdbHandler;
 use crate::bdb_groups::BdbGroupsHandler;
 use crate::bootstrap::BootstrapHandler;
-use crate::cluster::ClusterHandler;
+use crate::cluster::{ClusterCapabilities, ClusterHandler};
 use crate::cm_settings::CmSettingsHandler;
 use crate::crdb::CrdbHandler;
 use crate::crdb_tasks::CrdbTasksHandler;
@@ -14,7 +14,7 @@ use crate::diagnostics::DiagnosticsHandler;
 use crate::endpoints::EndpointsHandler;
 use crate::error::{RestError, Result};
 use crate::job_scheduler::JobSchedulerHandler;
-use crate::jsonschema::JsonSchemaHandler;
+use crate::jsonschema::{JsonSchemaHandler, SchemaResource};
 use crate::ldap_mappings::LdapMappingHandler;
 use crate::license::LicenseHandler;
 use crate::local::LocalHandler;
@@ -25,6 +25,7 @@ use crate::nodes::NodeHandler;
 use crate::ocsp::OcspHandler;
 use crate::proxies::ProxyHandler;
 use crate::redis_acls::RedisAclHandler;
+use crate::retry::{Backoff, retry_async_when};
 use crate::roles::RolesHandler;
 use crate::services::ServicesHandler;
 use crate::shards::ShardHandler;
@@ -32,16 +33,95 @@ use crate::stats::StatsHandler;
 use crate::suffixes::SuffixesHandler;
 use crate::usage_report::UsageReportHandler;
 use crate::users::UserHandler;
-use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
-use reqwest::{Client, Response};
+use reqwest::header::{
+    ETAG, HeaderMap, HeaderName, HeaderValue, IF_NONE_MATCH, LOCATION, USER_AGENT,
+};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode, redirect};
 use serde::{Serialize, de::DeserializeOwned};
-use std::sync::Arc;
-use std::time::Duration;
-use tracing::{debug, trace};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{Instrument, debug, debug_span, trace, warn};
+use typed_builder::TypedBuilder;
 
 /// Default user agent for the Redis Enterprise client
 const DEFAULT_USER_AGENT: &str = concat!("redis-enterprise/", env!("CARGO_PKG_VERSION"));
 
+/// Redirects followed per request unless overridden with `max_redirects`
+const DEFAULT_MAX_REDIRECTS: usize = 5;
+
+/// URLs kept in the `ETag` cache unless overridden with `cache_capacity`
+const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+/// Header carrying the request correlation ID
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Correlation ID of a sent request, stored in its response's extensions
+#[derive(Clone)]
+struct RequestId(String);
+
+/// Per-client request options
+///
+/// Applied with [`EnterpriseClient::with_request_options`] to every request made
+/// through the returned client.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use redis_enterprise::{EnterpriseClient, RequestOptions};
+///
+/// # fn example(client: EnterpriseClient) {
+/// let traced = client.with_request_options(
+///     RequestOptions::builder().request_id("deploy-7f3a").build(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, TypedBuilder)]
+pub struct RequestOptions {
+    /// Correlation ID sent as `X-Request-Id`, overriding a generated one
+    #[builder(default, setter(into, strip_option))]
+    pub request_id: Option<String>,
+}
+
+/// Minimum TLS protocol version for connections to the cluster
+///
+/// The client uses rustls, which implements TLS 1.2 and 1.3 only; asking for
+/// an older floor makes [`EnterpriseClientBuilder::build`] fail with
+/// [`RestError::TlsError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TlsVersion {
+    /// TLS 1.0
+    Tls1_0,
+    /// TLS 1.1
+    Tls1_1,
+    /// TLS 1.2
+    Tls1_2,
+    /// TLS 1.3
+    Tls1_3,
+}
+
+impl TlsVersion {
+    /// The equivalent rustls-backed version, if the backend implements it
+    fn to_reqwest(self) -> Option<reqwest::tls::Version> {
+        match self {
+            TlsVersion::Tls1_0 | TlsVersion::Tls1_1 => None,
+            TlsVersion::Tls1_2 => Some(reqwest::tls::Version::TLS_1_2),
+            TlsVersion::Tls1_3 => Some(reqwest::tls::Version::TLS_1_3),
+        }
+    }
+}
+
+impl std::fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TlsVersion::Tls1_0 => "TLS 1.0",
+            TlsVersion::Tls1_1 => "TLS 1.1",
+            TlsVersion::Tls1_2 => "TLS 1.2",
+            TlsVersion::Tls1_3 => "TLS 1.3",
+        })
+    }
+}
+
 // Legacy alias for backwards compatibility during migration
 pub type RestConfig = EnterpriseClientBuilder;
 
@@ -49,26 +129,46 @@ pub type RestConfig = EnterpriseClientBuilder;
 #[derive(Debug, Clone)]
 pub struct EnterpriseClientBuilder {
     base_url: String,
+    base_path: Option<String>,
     username: Option<String>,
     password: Option<String>,
     timeout: Duration,
+    connect_timeout: Option<Duration>,
+    min_tls_version: Option<TlsVersion>,
     insecure: bool,
     user_agent: String,
+    default_headers: Vec<(String, String)>,
     ca_cert_path: Option<std::path::PathBuf>,
     ca_cert_pem: Option<Vec<u8>>,
+    slow_request_threshold: Option<Duration>,
+    caching: bool,
+    cache_capacity: usize,
+    request_ids: bool,
+    max_redirects: usize,
+    redirect_hosts: Vec<String>,
 }
 
 impl Default for EnterpriseClientBuilder {
     fn default() -> Self {
         Self {
             base_url: "https://localhost:9443".to_string(),
+            base_path: None,
             username: None,
             password: None,
             timeout: Duration::from_secs(30),
+            connect_timeout: None,
+            min_tls_version: None,
             insecure: false,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: Vec::new(),
             ca_cert_path: None,
             ca_cert_pem: None,
+            slow_request_threshold: None,
+            caching: false,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+            request_ids: false,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            redirect_hosts: Vec::new(),
         }
     }
 }
@@ -87,6 +187,18 @@ impl EnterpriseClientBuilder {
         self
     }
 
+    /// Set a path prefix for clusters served behind a reverse proxy or gateway
+    ///
+    /// The prefix is inserted between the host and every API path, so with
+    /// `base_path("/redis-enterprise/")` a request for `/v1/bdbs` goes to
+    /// `/redis-enterprise/v1/bdbs`. Leading, trailing and repeated slashes are
+    /// collapsed.
+    #[must_use]
+    pub fn base_path(mut self, path: impl Into<String>) -> Self {
+        self.base_path = Some(path.into());
+        self
+    }
+
     /// Set the username
     #[must_use]
     pub fn username(mut self, username: impl Into<String>) -> Self {
@@ -102,12 +214,68 @@ impl EnterpriseClientBuilder {
     }
 
     /// Set the timeout
+    ///
+    /// This caps the whole request, from connecting until the response body
+    /// has been read. Defaults to 30 seconds.
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
     }
 
+    /// Set a separate timeout for establishing the connection
+    ///
+    /// Lets requests to an unreachable host fail fast while [`timeout`](Self::timeout)
+    /// still allows slow responses such as large stats downloads. The overall
+    /// `timeout` still applies. Unset by default, so only `timeout` applies.
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Refuse TLS handshakes below `version`
+    ///
+    /// Use [`TlsVersion::Tls1_3`] for clusters that set `min_data_TLS_version`
+    /// or `min_control_TLS_version` to 1.3. Unset by default, which allows
+    /// TLS 1.2 and 1.3.
+    #[must_use]
+    pub fn min_tls_version(mut self, version: TlsVersion) -> Self {
+        self.min_tls_version = Some(version);
+        self
+    }
+
+    /// Follow at most `max` redirects per request
+    ///
+    /// After a master failover, some endpoints answer with a `307` or `308`
+    /// pointing at the new master. These are followed with the same method,
+    /// body and credentials as long as the target keeps the scheme and port of
+    /// the base URL and its host is the base host, a node address reported by
+    /// `/v1/nodes` (`addr` or `external_addr`), or one of the
+    /// [`redirect_hosts`](Self::redirect_hosts). A redirect anywhere else is
+    /// refused with [`RestError::ConnectionError`] rather than sending
+    /// credentials off-cluster. Other redirects are followed without
+    /// credentials, as usual. `0` disables redirects; defaults to 5.
+    #[must_use]
+    pub fn max_redirects(mut self, max: usize) -> Self {
+        self.max_redirects = max;
+        self
+    }
+
+    /// Trust these hosts as nodes of the cluster when following a `307`/`308`
+    ///
+    /// Useful when nodes are reached through names that `/v1/nodes` does not
+    /// report, e.g. DNS names in front of the node addresses.
+    #[must_use]
+    pub fn redirect_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redirect_hosts = hosts.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Allow insecure TLS connections (self-signed certificates)
     #[must_use]
     pub fn insecure(mut self, insecure: bool) -> Self {
@@ -126,6 +294,18 @@ impl EnterpriseClientBuilder {
         self
     }
 
+    /// Add a header sent with every request
+    ///
+    /// Can be called repeatedly to add several headers, e.g. a tenant ID
+    /// required by an API gateway. Setting the same header name again replaces
+    /// the earlier value. Invalid names or values are reported by
+    /// [`build`](Self::build).
+    #[must_use]
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
     /// Set a custom CA certificate from a file path
     ///
     /// This allows connecting to Redis Enterprise clusters that use self-signed
@@ -172,6 +352,51 @@ impl EnterpriseClientBuilder {
         self
     }
 
+    /// Log a warning for requests that take longer than `threshold`
+    ///
+    /// When set, any request whose response takes longer than the threshold
+    /// emits a `tracing::warn!` event with the method, path, and elapsed time.
+    /// Disabled by default.
+    #[must_use]
+    pub fn slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Cache GET responses by `ETag` and revalidate with `If-None-Match`
+    ///
+    /// When enabled, GET responses carrying an `ETag` header are cached per URL.
+    /// Later GETs for the same URL send `If-None-Match`, and a `304 Not Modified`
+    /// reply is answered from the cache. Useful for hot read paths such as
+    /// cluster info and settings. At most [`cache_capacity`](Self::cache_capacity)
+    /// URLs are kept, evicting the least recently used. Disabled by default.
+    #[must_use]
+    pub fn caching(mut self, enabled: bool) -> Self {
+        self.caching = enabled;
+        self
+    }
+
+    /// Maximum number of URLs kept in the `ETag` cache; defaults to 64
+    ///
+    /// Only used with [`caching`](Self::caching). `0` caches nothing.
+    #[must_use]
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Send a generated `X-Request-Id` with every request
+    ///
+    /// Each request gets a random UUID, which is also recorded on the request's
+    /// `tracing` span and attached to any resulting error (see
+    /// [`RestError::request_id`]). An ID supplied through [`RequestOptions`]
+    /// takes precedence. Disabled by default.
+    #[must_use]
+    pub fn request_ids(mut self, enabled: bool) -> Self {
+        self.request_ids = enabled;
+        self
+    }
+
     /// Build the client
     pub fn build(self) -> Result<EnterpriseClient> {
         let username = self.username.unwrap_or_default();
@@ -183,10 +408,48 @@ impl EnterpriseClientBuilder {
             HeaderValue::from_str(&self.user_agent)
                 .map_err(|e| RestError::ConnectionError(format!("Invalid user agent: {}", e)))?,
         );
+        for (name, value) in &self.default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+                RestError::ConnectionError(format!("Invalid header name '{}': {}", name, e))
+            })?;
+            let header_value = HeaderValue::from_str(value).map_err(|e| {
+                RestError::ConnectionError(format!("Invalid value for header '{}': {}", name, e))
+            })?;
+            default_headers.insert(header_name, header_value);
+        }
 
+        // 307/308 are followed in `send` so that credentials survive a hop to
+        // another node; reqwest strips them on any cross-host redirect.
+        let max_redirects = self.max_redirects;
+        let redirect_policy = redirect::Policy::custom(move |attempt| {
+            if matches!(
+                attempt.status(),
+                StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT
+            ) {
+                attempt.stop()
+            } else if attempt.previous().len() > max_redirects {
+                attempt.error(format!("too many redirects (limit {})", max_redirects))
+            } else {
+                attempt.follow()
+            }
+        });
         let mut client_builder = Client::builder()
             .timeout(self.timeout)
-            .default_headers(default_headers);
+            .default_headers(default_headers)
+            .redirect(redirect_policy);
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+        if let Some(version) = self.min_tls_version {
+            let supported = version.to_reqwest().ok_or_else(|| {
+                RestError::TlsError(format!(
+                    "{} is not supported by the TLS backend (rustls); the lowest available \
+                     minimum is TLS 1.2",
+                    version
+                ))
+            })?;
+            client_builder = client_builder.tls_version_min(supported);
+        }
 
         // Add custom CA certificate if provided (merged with system roots)
         if let Some(ca_cert_path) = &self.ca_cert_path {
@@ -217,15 +480,183 @@ impl EnterpriseClientBuilder {
             .map_err(|e| RestError::ConnectionError(e.to_string()))?;
 
         Ok(EnterpriseClient {
-            base_url: self.base_url,
+            base_url: normalize_base_url(&self.base_url, self.base_path.as_deref())?,
             username,
             password,
             timeout: self.timeout,
+            slow_request_threshold: self.slow_request_threshold,
+            etag_cache: self
+                .caching
+                .then(|| Arc::new(Mutex::new(EtagCache::new(self.cache_capacity)))),
+            generate_request_ids: self.request_ids,
+            max_redirects: self.max_redirects,
+            redirect_hosts: Arc::new(self.redirect_hosts),
+            request_options: RequestOptions::default(),
+            capabilities: Default::default(),
+            schemas: Default::default(),
             client: Arc::new(client),
         })
     }
 }
 
+/// Validate a base URL and put it in canonical form without a trailing slash
+///
+/// IPv6 hosts must be bracketed (`https://[fd00::1]:9443`); the brackets are
+/// kept and the address is compressed and lowercased by the URL parser. An
+/// optional `base_path` is appended with empty segments removed.
+fn normalize_base_url(base_url: &str, base_path: Option<&str>) -> Result<String> {
+    let base_url = base_url.trim();
+    let parsed = url::Url::parse(base_url).map_err(|e| {
+        let hint = match e {
+            url::ParseError::InvalidPort if base_url.matches(':').count() > 2 => {
+                " (IPv6 hosts must be enclosed in brackets, e.g. https://[fd00::1]:9443)"
+            }
+            _ => "",
+        };
+        RestError::ValidationError(format!("Invalid base URL '{}': {}{}", base_url, e, hint))
+    })?;
+    if parsed.host().is_none() {
+        return Err(RestError::ValidationError(format!(
+            "Invalid base URL '{}': missing host",
+            base_url
+        )));
+    }
+    let mut normalized = parsed.as_str().trim_end_matches('/').to_string();
+    for segment in base_path.unwrap_or_default().split('/') {
+        if !segment.is_empty() {
+            normalized.push('/');
+            normalized.push_str(segment);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Generate a random (version 4) UUID for use as a request ID
+fn generate_request_id() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// The correlation ID a response's request was sent with
+fn request_id_of(response: &Response) -> Option<String> {
+    response
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone())
+}
+
+/// Map an unsuccessful response to a [`RestError::ApiError`] with its raw status code
+async fn api_error(response: Response) -> RestError {
+    let code = response.status().as_u16();
+    let request_id = request_id_of(&response);
+    let message = response.text().await.unwrap_or_default();
+    RestError::ApiError {
+        code,
+        message,
+        request_id,
+    }
+}
+
+/// Copy a response body to `writer` chunk by chunk, then flush it
+async fn write_body<W, E>(mut response: Response, writer: &mut W, write_error: E) -> Result<u64>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+    E: Fn(std::io::Error) -> RestError,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let mut written = 0u64;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk).await.map_err(&write_error)?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await.map_err(&write_error)?;
+    Ok(written)
+}
+
+/// Quote a command argument the way `redis-cli` would parse it back
+pub(crate) fn quote_command_arg(arg: &str) -> String {
+    let needs_quotes = arg.is_empty()
+        || arg
+            .chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '\'');
+    if !needs_quotes {
+        return arg.to_string();
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Deserialize a JSON response body, reporting the failing field path on error
+///
+/// Some action endpoints answer `200 OK` with an empty body. That is read as
+/// JSON `null`, so `()`, `Option<T>` and `serde_json::Value` still succeed.
+fn parse_body<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    if bytes.iter().all(u8::is_ascii_whitespace) {
+        return T::deserialize(serde_json::Value::Null)
+            .map_err(|err| RestError::ParseError(format!("Empty response body: {}", err)));
+    }
+    // Use serde_path_to_error for better deserialization error messages
+    let deserializer = &mut serde_json::Deserializer::from_slice(bytes);
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let inner = err.inner();
+        let path = err.path().to_string();
+        if inner.is_data() {
+            RestError::ParseErrorAt {
+                path,
+                message: inner.to_string(),
+                snippet: body_snippet(bytes, inner.line(), inner.column()),
+            }
+        } else {
+            // Malformed JSON has no meaningful field to point at
+            RestError::ParseError(format!("Failed to deserialize field '{}': {}", path, inner))
+        }
+    })
+}
+
+/// Characters of context kept on each side of a parse error position
+const SNIPPET_CONTEXT: usize = 40;
+
+/// The text of `bytes` around a 1-based `line`/`column`, for error reports
+fn body_snippet(bytes: &[u8], line: usize, column: usize) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    let line_text = text.lines().nth(line.saturating_sub(1)).unwrap_or("");
+    let chars: Vec<char> = line_text.chars().collect();
+    let at = column.min(chars.len());
+    let start = at.saturating_sub(SNIPPET_CONTEXT);
+    let end = (at + SNIPPET_CONTEXT).min(chars.len());
+    let mut snippet: String = chars[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert_str(0, "...");
+    }
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
 /// REST API client for Redis Enterprise
 #[derive(Clone)]
 pub struct EnterpriseClient {
@@ -233,9 +664,79 @@ pub struct EnterpriseClient {
     username: String,
     password: String,
     timeout: Duration,
+    slow_request_threshold: Option<Duration>,
+    etag_cache: Option<Arc<Mutex<EtagCache>>>,
+    generate_request_ids: bool,
+    max_redirects: usize,
+    redirect_hosts: Arc<Vec<String>>,
+    request_options: RequestOptions,
+    capabilities: Arc<tokio::sync::Mutex<Option<Arc<ClusterCapabilities>>>>,
+    schemas: Arc<Mutex<HashMap<SchemaResource, Arc<serde_json::Value>>>>,
     client: Arc<Client>,
 }
 
+/// Cached GET response bodies keyed by URL, with the `ETag` they were served with
+///
+/// Holds at most `capacity` URLs; inserting beyond that evicts the least
+/// recently used entry.
+struct EtagCache {
+    capacity: usize,
+    entries: HashMap<String, (String, Vec<u8>)>,
+    /// URLs from least to most recently used
+    order: VecDeque<String>,
+}
+
+impl EtagCache {
+    fn new(capacity: usize) -> Self {
+        EtagCache {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn touch(&mut self, url: &str) {
+        if let Some(index) = self.order.iter().position(|u| u == url) {
+            let url = self.order.remove(index).expect("index in bounds");
+            self.order.push_back(url);
+        }
+    }
+
+    fn etag(&mut self, url: &str) -> Option<String> {
+        let etag = self.entries.get(url).map(|(etag, _)| etag.clone())?;
+        self.touch(url);
+        Some(etag)
+    }
+
+    fn body(&mut self, url: &str) -> Option<Vec<u8>> {
+        let body = self.entries.get(url).map(|(_, body)| body.clone())?;
+        self.touch(url);
+        Some(body)
+    }
+
+    fn insert(&mut self, url: String, etag: String, body: Vec<u8>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(url.clone(), (etag, body)).is_some() {
+            self.touch(&url);
+            return;
+        }
+        self.order.push_back(url);
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.entries.remove(&evicted);
+            }
+        }
+    }
+
+    fn remove(&mut self, url: &str) {
+        if self.entries.remove(url).is_some() {
+            self.order.retain(|u| u != url);
+        }
+    }
+}
+
 // Alias for backwards compatibility
 pub type RestClient = EnterpriseClient;
 
@@ -256,13 +757,215 @@ impl EnterpriseClient {
         self.timeout
     }
 
+    /// Get the base URL requests are sent to
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get a client that applies `options` to every request
+    ///
+    /// The returned client shares the connection pool with this one.
+    #[must_use]
+    pub fn with_request_options(&self, options: RequestOptions) -> Self {
+        Self {
+            request_options: options,
+            ..self.clone()
+        }
+    }
+
+    /// Get a client for another node of this cluster, reached at `address`
+    ///
+    /// Keeps the scheme, port, base path, credentials and TLS settings of this
+    /// client; only the host changes.
+    pub(crate) fn for_node(&self, address: &str) -> Result<Self> {
+        let invalid = || RestError::ValidationError(format!("Invalid node address '{}'", address));
+        let mut url = url::Url::parse(&self.base_url).map_err(|_| invalid())?;
+        let host = if address.contains(':') && !address.starts_with('[') {
+            format!("[{}]", address)
+        } else {
+            address.to_string()
+        };
+        url.set_host(Some(&host)).map_err(|_| invalid())?;
+        Ok(Self {
+            base_url: url.as_str().trim_end_matches('/').to_string(),
+            capabilities: Default::default(),
+            ..self.clone()
+        })
+    }
+
     /// Normalize URL path concatenation to avoid double slashes
-    fn normalize_url(&self, path: &str) -> String {
+    pub(crate) fn normalize_url(&self, path: &str) -> String {
         let base = self.base_url.trim_end_matches('/');
         let path = path.trim_start_matches('/');
         format!("{}/{}", base, path)
     }
 
+    /// Send an authenticated request, warning if it exceeds the slow request threshold
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        configure: impl FnOnce(RequestBuilder) -> RequestBuilder,
+    ) -> Result<Response> {
+        let request_id = self
+            .request_options
+            .request_id
+            .clone()
+            .or_else(|| self.generate_request_ids.then(generate_request_id));
+        let mut request = self
+            .client
+            .request(method.clone(), url)
+            .basic_auth(&self.username, Some(&self.password));
+        if let Some(request_id) = &request_id {
+            request = request.header(REQUEST_ID_HEADER, request_id);
+        }
+
+        let span = debug_span!("request", %method, request_id = request_id.as_deref());
+        let start = Instant::now();
+        let mut response = self
+            .send_following_redirects(configure(request), url)
+            .instrument(span)
+            .await?;
+        let elapsed = start.elapsed();
+
+        trace!("Response status: {}", response.status());
+        if let Some(threshold) = self.slow_request_threshold
+            && elapsed > threshold
+        {
+            let path = url
+                .strip_prefix(self.base_url.trim_end_matches('/'))
+                .unwrap_or(url);
+            warn!(
+                method = %method,
+                path,
+                request_id = request_id.as_deref(),
+                elapsed_ms = elapsed.as_millis() as u64,
+                "Slow Redis Enterprise API request"
+            );
+        }
+
+        if let Some(request_id) = request_id {
+            response.extensions_mut().insert(RequestId(request_id));
+        }
+        Ok(response)
+    }
+
+    /// Send a request, following `307`/`308` redirects to other cluster nodes
+    ///
+    /// The request is re-sent as-is (method, body and `Authorization`) to the
+    /// new location. Streaming bodies can't be replayed, so their redirect
+    /// response is returned unchanged.
+    async fn send_following_redirects(
+        &self,
+        request: RequestBuilder,
+        url: &str,
+    ) -> Result<Response> {
+        let mut request = request
+            .build()
+            .map_err(|e| self.map_reqwest_error(e, url))?;
+        let mut redirects = 0;
+        loop {
+            let replay = request.try_clone();
+            let current = request.url().clone();
+            let response = self
+                .client
+                .execute(request)
+                .await
+                .map_err(|e| self.map_reqwest_error(e, current.as_str()))?;
+            if !matches!(
+                response.status(),
+                StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT
+            ) {
+                return Ok(response);
+            }
+            let Some(mut next) = replay else {
+                return Ok(response);
+            };
+            let Some(location) = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|location| current.join(location).ok())
+            else {
+                return Ok(response);
+            };
+            if redirects >= self.max_redirects {
+                return Err(RestError::ConnectionError(format!(
+                    "Too many redirects from {} (limit {})",
+                    url, self.max_redirects
+                )));
+            }
+            if !self.is_cluster_url(&location).await {
+                return Err(RestError::ConnectionError(format!(
+                    "Refusing to follow redirect from {} to {}: the target is not a known \
+                     node of this cluster",
+                    current, location
+                )));
+            }
+            debug!("Following {} redirect to {}", response.status(), location);
+            *next.url_mut() = location;
+            request = next;
+            redirects += 1;
+        }
+    }
+
+    /// Whether `url` points at a node of this cluster
+    ///
+    /// The scheme and port must match the base URL, and the host must be the
+    /// base host, one of the configured redirect hosts, or a node address
+    /// reported by `/v1/nodes`.
+    async fn is_cluster_url(&self, url: &url::Url) -> bool {
+        let Ok(base) = url::Url::parse(&self.base_url) else {
+            return false;
+        };
+        if base.scheme() != url.scheme()
+            || base.port_or_known_default() != url.port_or_known_default()
+        {
+            return false;
+        }
+        let Some(host) = url.host_str().map(|h| h.trim_matches(['[', ']'])) else {
+            return false;
+        };
+        let same_host = |other: &str| other.trim_matches(['[', ']']).eq_ignore_ascii_case(host);
+        base.host_str().is_some_and(same_host)
+            || self.redirect_hosts.iter().any(|h| same_host(h))
+            || self.node_addresses().await.iter().any(|h| same_host(h))
+    }
+
+    /// Internal and external addresses of all nodes, empty if they can't be read
+    ///
+    /// Sent directly rather than through [`get`](Self::get), which would
+    /// recurse back into redirect handling.
+    async fn node_addresses(&self) -> Vec<String> {
+        let response = self
+            .client
+            .get(self.normalize_url("/v1/nodes"))
+            .basic_auth(&self.username, Some(&self.password))
+            .send()
+            .await;
+        let nodes: Vec<serde_json::Value> = match response {
+            Ok(response) if response.status().is_success() => {
+                response.json().await.unwrap_or_default()
+            }
+            _ => return Vec::new(),
+        };
+        nodes
+            .iter()
+            .flat_map(|node| {
+                let addr = node.get("addr").and_then(|a| a.as_str());
+                let external = node
+                    .get("external_addr")
+                    .and_then(|a| a.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|a| a.as_str());
+                addr.into_iter().chain(external)
+            })
+            .map(str::to_string)
+            .collect()
+    }
+
     /// Create a client from environment variables
     ///
     /// Reads configuration from:
@@ -299,21 +1002,165 @@ impl EnterpriseClient {
         builder.build()
     }
 
+    /// Create a client from environment variables and verify it can reach the cluster
+    ///
+    /// Reads the same variables as [`from_env`](Self::from_env), then calls
+    /// [`ping`](Self::ping) so misconfiguration is reported up front.
+    pub async fn from_env_checked() -> Result<Self> {
+        let client = Self::from_env().map_err(|err| match err {
+            RestError::InvalidUrl(msg) | RestError::ValidationError(msg) => {
+                RestError::InvalidUrl(format!("REDIS_ENTERPRISE_URL is invalid: {}", msg))
+            }
+            other => other,
+        })?;
+        client.ping().await?;
+        Ok(client)
+    }
+
+    /// Verify the cluster is reachable and accepts the configured credentials
+    ///
+    /// Issues a `GET /v1/cluster` and reports the likely cause of a failure:
+    /// [`RestError::ConnectionError`] for an unreachable or wrong URL,
+    /// [`RestError::AuthenticationFailed`] for rejected credentials, and
+    /// [`RestError::TlsError`] when the server certificate is not trusted.
+    pub async fn ping(&self) -> Result<()> {
+        match self.get::<serde_json::Value>("/v1/cluster").await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(match err {
+                RestError::Unauthorized { .. } | RestError::AuthenticationFailed => {
+                    RestError::AuthenticationFailed
+                }
+                RestError::TlsError(msg) => RestError::TlsError(format!(
+                    "{} (or set REDIS_ENTERPRISE_INSECURE / REDIS_ENTERPRISE_CA_CERT)",
+                    msg
+                )),
+                RestError::NotFound { .. } => RestError::ConnectionError(format!(
+                    "{} answered but has no /v1/cluster endpoint. Check that the URL points \
+                     at the Redis Enterprise REST API (usually port 9443).",
+                    self.base_url
+                )),
+                _ => err,
+            }),
+        }
+    }
+
+    /// Get the cluster's capabilities, fetching them on first use
+    ///
+    /// Version, CRDT support, module capabilities and password policy are
+    /// fetched once and shared by every clone of this client; concurrent first
+    /// calls make a single round trip. A failed fetch is not cached. Use
+    /// [`refresh_capabilities`](Self::refresh_capabilities) after an upgrade
+    /// or a policy change.
+    pub async fn capabilities(&self) -> Result<Arc<ClusterCapabilities>> {
+        let mut cached = self.capabilities.lock().await;
+        if let Some(capabilities) = cached.as_ref() {
+            return Ok(capabilities.clone());
+        }
+        let capabilities = Arc::new(self.cluster().capabilities().await?);
+        *cached = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// Re-fetch the cluster's capabilities and replace the cached copy
+    pub async fn refresh_capabilities(&self) -> Result<Arc<ClusterCapabilities>> {
+        let mut cached = self.capabilities.lock().await;
+        let capabilities = Arc::new(self.cluster().capabilities().await?);
+        *cached = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// A JSON Schema previously fetched by [`JsonSchemaHandler::schema`]
+    pub(crate) fn cached_schema(
+        &self,
+        resource: &SchemaResource,
+    ) -> Option<Arc<serde_json::Value>> {
+        self.schemas
+            .lock()
+            .expect("schema cache poisoned")
+            .get(resource)
+            .cloned()
+    }
+
+    /// Remember a JSON Schema for every clone of this client
+    pub(crate) fn cache_schema(&self, resource: SchemaResource, schema: Arc<serde_json::Value>) {
+        self.schemas
+            .lock()
+            .expect("schema cache poisoned")
+            .insert(resource, schema);
+    }
+
     /// Make a GET request
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = self.normalize_url(path);
         debug!("GET {}", url);
 
+        let Some(cache) = &self.etag_cache else {
+            let response = self.send(Method::GET, &url, |req| req).await?;
+            return self.handle_response(response).await;
+        };
+
+        let cached_etag = cache.lock().expect("etag cache poisoned").etag(&url);
         let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
+            .send(Method::GET, &url, |req| match &cached_etag {
+                Some(etag) => req.header(IF_NONE_MATCH, etag),
+                None => req,
+            })
+            .await?;
 
-        trace!("Response status: {}", response.status());
-        self.handle_response(response).await
+        if response.status() == StatusCode::NOT_MODIFIED {
+            let cached = cache.lock().expect("etag cache poisoned").body(&url);
+            if let Some(body) = cached {
+                trace!("Serving {} from ETag cache", url);
+                return parse_body(&body);
+            }
+        }
+        if !response.status().is_success() {
+            return Err(self.error_from_response(response).await);
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await?;
+        let value = parse_body(&body)?;
+        let mut cache = cache.lock().expect("etag cache poisoned");
+        match etag {
+            Some(etag) => cache.insert(url, etag, body.to_vec()),
+            None => cache.remove(&url),
+        }
+        Ok(value)
+    }
+
+    /// Make a GET request whose response body may be empty
+    ///
+    /// Returns `None` for an empty body or a JSON `null`, instead of the
+    /// `ParseError` [`get`](Self::get) reports when `T` can't be built from nothing.
+    pub async fn get_optional<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>> {
+        self.get(path).await
+    }
+
+    /// Make a GET request that also accepts the given unsuccessful statuses
+    ///
+    /// For probe endpoints whose failure status carries a meaningful body.
+    /// Returns the status with the JSON body (`null` when empty); other
+    /// unsuccessful statuses are mapped to errors as usual.
+    pub(crate) async fn get_accepting(
+        &self,
+        path: &str,
+        accept: &[StatusCode],
+    ) -> Result<(StatusCode, serde_json::Value)> {
+        let url = self.normalize_url(path);
+        debug!("GET {}", url);
+
+        let response = self.send(Method::GET, &url, |req| req).await?;
+        let status = response.status();
+        if !status.is_success() && !accept.contains(&status) {
+            return Err(self.error_from_response(response).await);
+        }
+        let bytes = response.bytes().await?;
+        Ok((status, parse_body(&bytes)?))
     }
 
     /// Make a GET request for text content
@@ -321,29 +1168,13 @@ impl EnterpriseClient {
         let url = self.normalize_url(path);
         debug!("GET {} (text)", url);
 
-        let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::GET, &url, |req| req).await?;
 
         if response.status().is_success() {
             let text = response.text().await?;
             Ok(text)
         } else {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            Err(crate::error::RestError::ApiError {
-                code: status.as_u16(),
-                message: error_text,
-            })
+            Err(api_error(response).await)
         }
     }
 
@@ -352,15 +1183,7 @@ impl EnterpriseClient {
         let url = self.normalize_url(path);
         debug!("GET {} (binary)", url);
 
-        let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::GET, &url, |req| req).await?;
         trace!(
             "Response content-type: {:?}",
             response.headers().get("content-type")
@@ -370,16 +1193,50 @@ impl EnterpriseClient {
             let bytes = response.bytes().await?;
             Ok(bytes.to_vec())
         } else {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            Err(crate::error::RestError::ApiError {
-                code: status.as_u16(),
-                message: error_text,
-            })
+            Err(api_error(response).await)
+        }
+    }
+
+    /// Stream a binary GET response to a file, returning the number of bytes written
+    ///
+    /// The body is written chunk by chunk, so large downloads (e.g. debug info
+    /// packages) are never held in memory in full.
+    pub async fn get_binary_to_file(&self, path: &str, dest: &std::path::Path) -> Result<u64> {
+        let url = self.normalize_url(path);
+        debug!("GET {} (binary) -> {}", url, dest.display());
+
+        let response = self.send(Method::GET, &url, |req| req).await?;
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
         }
+
+        let write_error = |e: std::io::Error| {
+            RestError::IoError(format!("Failed to write {}: {}", dest.display(), e))
+        };
+        let mut file = tokio::fs::File::create(dest).await.map_err(write_error)?;
+        write_body(response, &mut file, write_error).await
+    }
+
+    /// Stream a GET response body to `writer`, returning the number of bytes written
+    ///
+    /// Like [`get_binary_to_file`](Self::get_binary_to_file), for any destination:
+    /// an open file, a pipe, or an upload. The writer is flushed but not shut down.
+    pub async fn get_to_writer<W>(&self, path: &str, writer: &mut W) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let url = self.normalize_url(path);
+        debug!("GET {} (streamed)", url);
+
+        let response = self.send(Method::GET, &url, |req| req).await?;
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+
+        write_body(response, writer, |e| {
+            RestError::ConnectionError(format!("Failed to write response body: {}", e))
+        })
+        .await
     }
 
     /// Make a POST request
@@ -388,16 +1245,7 @@ impl EnterpriseClient {
         debug!("POST {}", url);
         trace!("Request body: {:?}", serde_json::to_value(body).ok());
 
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::POST, &url, |req| req.json(body)).await?;
         self.handle_response(response).await
     }
 
@@ -407,16 +1255,7 @@ impl EnterpriseClient {
         debug!("PUT {}", url);
         trace!("Request body: {:?}", serde_json::to_value(body).ok());
 
-        let response = self
-            .client
-            .put(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::PUT, &url, |req| req.json(body)).await?;
         self.handle_response(response).await
     }
 
@@ -425,24 +1264,11 @@ impl EnterpriseClient {
         let url = self.normalize_url(path);
         debug!("DELETE {}", url);
 
-        let response = self
-            .client
-            .delete(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::DELETE, &url, |req| req).await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
+            Err(api_error(response).await)
         }
     }
 
@@ -461,32 +1287,68 @@ impl EnterpriseClient {
         self.put(path, &body).await
     }
 
-    /// POST request for actions that return no content
-    pub async fn post_action<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
+    /// POST request for actions that return no content
+    pub async fn post_action<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
+        let url = self.normalize_url(path);
+        debug!("POST {}", url);
+        trace!("Request body: {:?}", serde_json::to_value(body).ok());
+
+        let response = self.send(Method::POST, &url, |req| req.json(body)).await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(api_error(response).await)
+        }
+    }
+
+    /// POST request for actions, returning the `action_uid` the cluster reports
+    ///
+    /// Unlike [`post_action`](Self::post_action), the response body is read so
+    /// the action can be followed with [`ActionHandler::wait_for`]. Returns
+    /// `None` for an empty body or one without an `action_uid`.
+    pub async fn post_action_tracked<B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Option<String>> {
         let url = self.normalize_url(path);
-        debug!("POST {}", url);
+        debug!("POST {} (tracked)", url);
         trace!("Request body: {:?}", serde_json::to_value(body).ok());
 
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(body)
-            .send()
+        let response = self.send(Method::POST, &url, |req| req.json(body)).await?;
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let bytes = response
+            .bytes()
             .await
             .map_err(|e| self.map_reqwest_error(e, &url))?;
+        let body: Option<serde_json::Value> = serde_json::from_slice(&bytes).ok();
+        Ok(
+            match body.as_ref().and_then(|body| body.get("action_uid")) {
+                Some(serde_json::Value::String(uid)) => Some(uid.clone()),
+                Some(serde_json::Value::Number(uid)) => Some(uid.to_string()),
+                _ => None,
+            },
+        )
+    }
 
-        trace!("Response status: {}", response.status());
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
-        }
+    /// [`post_action_tracked`](Self::post_action_tracked), retried with `backoff`
+    ///
+    /// Timeouts, rate limiting, `503 Cluster Busy` and other server errors are
+    /// retried; any other error is returned at once. Only use this for
+    /// idempotent actions: a request that timed out may still have started
+    /// the action, and the retry starts it again.
+    pub async fn post_action_tracked_with_retry<B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        backoff: Backoff,
+    ) -> Result<Option<String>> {
+        retry_async_when(backoff, RestError::is_retryable, || {
+            self.post_action_tracked(path, body)
+        })
+        .await
     }
 
     /// PUT request for actions that return no content (or may return an empty body)
@@ -495,25 +1357,11 @@ impl EnterpriseClient {
         debug!("PUT {}", url);
         trace!("Request body: {:?}", serde_json::to_value(body).ok());
 
-        let response = self
-            .client
-            .put(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+        let response = self.send(Method::PUT, &url, |req| req.json(body)).await?;
         if response.status().is_success() {
             Ok(())
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
+            Err(api_error(response).await)
         }
     }
 
@@ -533,15 +1381,8 @@ impl EnterpriseClient {
         let form = reqwest::multipart::Form::new().part(field_name.to_string(), part);
 
         let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .multipart(form)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
-
-        trace!("Response status: {}", response.status());
+            .send(Method::POST, &url, |req| req.multipart(form))
+            .await?;
         self.handle_response(response).await
     }
 
@@ -558,14 +1399,7 @@ impl EnterpriseClient {
     ) -> Result<serde_json::Value> {
         let url = self.normalize_url(path);
 
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
+        let response = self.send(Method::POST, &url, |req| req.json(body)).await?;
 
         let status = response.status();
         if status.is_success() {
@@ -578,11 +1412,7 @@ impl EnterpriseClient {
                     .unwrap_or_else(|_| serde_json::json!({"status": "success", "response": text})))
             }
         } else {
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
+            Err(api_error(response).await)
         }
     }
 
@@ -594,13 +1424,8 @@ impl EnterpriseClient {
     ) -> Result<serde_json::Value> {
         let url = self.normalize_url(path);
         let response = self
-            .client
-            .patch(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
+            .send(Method::PATCH, &url, |req| req.json(&body))
+            .await?;
 
         if response.status().is_success() {
             response
@@ -608,25 +1433,14 @@ impl EnterpriseClient {
                 .await
                 .map_err(|e| RestError::ParseError(e.to_string()))
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
+            Err(api_error(response).await)
         }
     }
 
     /// Execute raw DELETE request returning any response body
     pub async fn delete_raw(&self, path: &str) -> Result<serde_json::Value> {
         let url = self.normalize_url(path);
-        let response = self
-            .client
-            .delete(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
+        let response = self.send(Method::DELETE, &url, |req| req).await?;
 
         if response.status().is_success() {
             if response.content_length() == Some(0) {
@@ -638,12 +1452,7 @@ impl EnterpriseClient {
                     .map_err(|e| RestError::ParseError(e.to_string()))
             }
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            Err(RestError::ApiError {
-                code: status.as_u16(),
-                message: text,
-            })
+            Err(api_error(response).await)
         }
     }
 
@@ -699,6 +1508,7 @@ impl EnterpriseClient {
             RestError::ApiError {
                 code: status.as_u16(),
                 message: format!("HTTP {} from {}: {}", status.as_u16(), url, error),
+                request_id: None,
             }
         } else if error.is_request() {
             RestError::ConnectionError(format!(
@@ -715,33 +1525,49 @@ impl EnterpriseClient {
         if response.status().is_success() {
             // Get the response bytes for better error reporting
             let bytes = response.bytes().await.map_err(Into::<RestError>::into)?;
-
-            // Use serde_path_to_error for better deserialization error messages
-            let deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(deserializer).map_err(|err| {
-                let path = err.path().to_string();
-                RestError::ParseError(format!(
-                    "Failed to deserialize field '{}': {}",
-                    path,
-                    err.inner()
-                ))
-            })
+            parse_body(&bytes)
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
+            Err(self.error_from_response(response).await)
+        }
+    }
 
-            match status.as_u16() {
-                401 => Err(RestError::Unauthorized),
-                404 => Err(RestError::NotFound),
-                409 => Err(RestError::Conflict(text)),
-                429 => Err(RestError::RateLimited { retry_after: None }),
-                503 => Err(RestError::ClusterBusy),
-                500..=599 => Err(RestError::ServerError(text)),
-                _ => Err(RestError::ApiError {
-                    code: status.as_u16(),
-                    message: text,
-                }),
-            }
+    /// Map an unsuccessful response to the matching [`RestError`] variant
+    async fn error_from_response(&self, response: Response) -> RestError {
+        let status = response.status();
+        let request_id = request_id_of(&response);
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let text = response.text().await.unwrap_or_default();
+
+        match status.as_u16() {
+            401 => RestError::Unauthorized { request_id },
+            403 => RestError::Forbidden {
+                message: text,
+                request_id,
+            },
+            404 => RestError::NotFound { request_id },
+            409 | 412 => RestError::Conflict {
+                message: text,
+                request_id,
+            },
+            429 => RestError::RateLimited {
+                retry_after,
+                request_id,
+            },
+            503 => RestError::ClusterBusy { request_id },
+            500..=599 => RestError::ServerError {
+                message: text,
+                request_id,
+            },
+            _ => RestError::ApiError {
+                code: status.as_u16(),
+                message: text,
+                request_id,
+            },
         }
     }
 
@@ -755,18 +1581,42 @@ impl EnterpriseClient {
 
         debug!("Executing command on database {}: {}", db_uid, command);
 
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.username, Some(&self.password))
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| self.map_reqwest_error(e, &url))?;
+        let response = self.send(Method::POST, &url, |req| req.json(&body)).await?;
 
         self.handle_response(response).await
     }
 
+    /// Execute a Redis command on a specific database and decode the RESP3 reply
+    ///
+    /// Arguments are quoted as needed, so values containing spaces or quotes are
+    /// passed through intact. Error replies are returned as
+    /// [`RedisValue::Error`](crate::bdb::RedisValue::Error) rather than an `Err`.
+    pub async fn execute_command_typed(
+        &self,
+        db_uid: u32,
+        args: &[&str],
+    ) -> Result<crate::bdb::RedisValue> {
+        if args.is_empty() {
+            return Err(RestError::ValidationError(
+                "command must have at least one argument".to_string(),
+            ));
+        }
+        let command = args
+            .iter()
+            .map(|arg| quote_command_arg(arg))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let url = self.normalize_url(&format!("/v1/bdbs/{}/command", db_uid));
+        let body = serde_json::json!({ "command": command });
+
+        debug!("Executing command on database {}: {}", db_uid, command);
+
+        let response = self.send(Method::POST, &url, |req| req.json(&body)).await?;
+        // Decode straight from the body so map replies keep server order
+        let reply: crate::bdb::RedisValue = self.handle_response(response).await?;
+        Ok(reply.into_reply())
+    }
+
     // ========================================================================
     // Fluent API - Handler Accessors
     // ========================================================================
@@ -997,7 +1847,7 @@ pub mod tower_support {
     use std::future::Future;
     use std::pin::Pin;
     use std::task::{Context, Poll};
-    use tower::Service;
+    use tower::{Layer, Service};
 
     /// HTTP method for API requests
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1073,6 +1923,44 @@ pub mod tower_support {
                 body: None,
             }
         }
+
+        /// Add an HTTP header to the request
+        pub fn with_header(
+            self,
+            name: impl Into<String>,
+            value: impl Into<String>,
+        ) -> HeaderedRequest {
+            HeaderedRequest::from(self).with_header(name, value)
+        }
+    }
+
+    /// An [`ApiRequest`] carrying extra HTTP headers
+    ///
+    /// The headers replace any the client would otherwise send, e.g.
+    /// `Authorization` to use a bearer token instead of basic auth.
+    #[derive(Debug, Clone)]
+    pub struct HeaderedRequest {
+        /// The underlying request
+        pub request: ApiRequest,
+        /// Extra HTTP headers as name/value pairs
+        pub headers: Vec<(String, String)>,
+    }
+
+    impl HeaderedRequest {
+        /// Add another HTTP header to the request
+        pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+            self.headers.push((name.into(), value.into()));
+            self
+        }
+    }
+
+    impl From<ApiRequest> for HeaderedRequest {
+        fn from(request: ApiRequest) -> Self {
+            Self {
+                request,
+                headers: Vec::new(),
+            }
+        }
     }
 
     /// Tower-compatible response type
@@ -1131,39 +2019,397 @@ pub mod tower_support {
         }
 
         fn call(&mut self, req: ApiRequest) -> Self::Future {
+            self.call_with_headers(req.into())
+        }
+    }
+
+    /// Tower service that sends [`HeaderedRequest`]s through an [`EnterpriseClient`]
+    ///
+    /// Created with [`EnterpriseClient::into_headered_service`]; this is the
+    /// service [`TokenRefreshLayer`] wraps.
+    #[derive(Clone)]
+    pub struct HeaderedService(EnterpriseClient);
+
+    impl std::fmt::Debug for HeaderedService {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("HeaderedService").finish_non_exhaustive()
+        }
+    }
+
+    impl Service<HeaderedRequest> for HeaderedService {
+        type Response = ApiResponse;
+        type Error = RestError;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response>> + Send>>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: HeaderedRequest) -> Self::Future {
+            self.0.call_with_headers(req)
+        }
+    }
+
+    impl EnterpriseClient {
+        /// Convert this client into a Tower service accepting [`HeaderedRequest`]s
+        pub fn into_headered_service(self) -> HeaderedService {
+            HeaderedService(self)
+        }
+
+        fn call_with_headers(
+            &self,
+            req: HeaderedRequest,
+        ) -> Pin<Box<dyn Future<Output = Result<ApiResponse>> + Send>> {
             let client = self.clone();
             Box::pin(async move {
-                let response: serde_json::Value = match req.method {
-                    Method::Get => client.get(&req.path).await?,
-                    Method::Post => {
-                        let body = req.body.ok_or_else(|| {
-                            RestError::ValidationError("POST request requires a body".to_string())
-                        })?;
-                        client.post(&req.path, &body).await?
-                    }
-                    Method::Put => {
-                        let body = req.body.ok_or_else(|| {
-                            RestError::ValidationError("PUT request requires a body".to_string())
-                        })?;
-                        client.put(&req.path, &body).await?
-                    }
-                    Method::Patch => {
-                        let body = req.body.ok_or_else(|| {
-                            RestError::ValidationError("PATCH request requires a body".to_string())
-                        })?;
-                        client.patch_raw(&req.path, body).await?
-                    }
-                    Method::Delete => {
-                        client.delete(&req.path).await?;
-                        serde_json::json!({"status": "deleted"})
-                    }
+                let HeaderedRequest {
+                    request: req,
+                    headers: extra_headers,
+                } = req;
+                let method = match req.method {
+                    Method::Get => reqwest::Method::GET,
+                    Method::Post => reqwest::Method::POST,
+                    Method::Put => reqwest::Method::PUT,
+                    Method::Patch => reqwest::Method::PATCH,
+                    Method::Delete => reqwest::Method::DELETE,
+                };
+                if matches!(req.method, Method::Post | Method::Put | Method::Patch)
+                    && req.body.is_none()
+                {
+                    return Err(RestError::ValidationError(format!(
+                        "{} request requires a body",
+                        method
+                    )));
+                }
+
+                let mut headers = reqwest::header::HeaderMap::new();
+                for (name, value) in &extra_headers {
+                    let name = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                        .map_err(|e| RestError::ValidationError(e.to_string()))?;
+                    let value = reqwest::header::HeaderValue::from_str(value)
+                        .map_err(|e| RestError::ValidationError(e.to_string()))?;
+                    headers.insert(name, value);
+                }
+
+                let url = client.normalize_url(&req.path);
+                debug!("{} {} (tower)", method, url);
+                let response = client
+                    .send(method, &url, |request| {
+                        let request = match &req.body {
+                            Some(body) => request.json(body),
+                            None => request,
+                        };
+                        // `headers` replaces rather than appends, so a caller-supplied
+                        // Authorization header overrides basic auth
+                        request.headers(headers)
+                    })
+                    .await?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(client.error_from_response(response).await);
+                }
+                // 201/202/204 responses may carry no body at all
+                let bytes = response.bytes().await?;
+                let body = if !bytes.iter().all(u8::is_ascii_whitespace) {
+                    serde_json::from_slice(&bytes)
+                        .map_err(|e| RestError::ParseError(e.to_string()))?
+                } else if req.method == Method::Delete && status.as_u16() == 204 {
+                    serde_json::json!({"status": "deleted"})
+                } else {
+                    serde_json::Value::Null
                 };
 
                 Ok(ApiResponse {
-                    status: 200,
-                    body: response,
+                    status: status.as_u16(),
+                    body,
                 })
             })
         }
     }
+
+    /// Tower layer that authenticates requests with a refreshable bearer token
+    ///
+    /// The token is fetched with the supplied refresh closure on first use and
+    /// cached for subsequent requests. When the API responds with 401, the token
+    /// is refreshed and the request is retried once. The cache is shared by every
+    /// service this layer produces.
+    ///
+    /// The wrapped service receives each request as a [`HeaderedRequest`] carrying
+    /// the `Authorization` header; use [`EnterpriseClient::into_headered_service`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use redis_enterprise::EnterpriseClient;
+    /// use redis_enterprise::tower_support::{ApiRequest, TokenRefreshLayer};
+    /// use tower::{ServiceBuilder, ServiceExt};
+    ///
+    /// # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = ServiceBuilder::new()
+    ///     .layer(TokenRefreshLayer::new(|| async { fetch_token().await }))
+    ///     .service(client.into_headered_service());
+    ///
+    /// let response = service.oneshot(ApiRequest::get("/v1/cluster")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub struct TokenRefreshLayer<F> {
+        refresh: Arc<F>,
+        token: Arc<std::sync::Mutex<Option<String>>>,
+    }
+
+    impl<F, Fut> TokenRefreshLayer<F>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        /// Create a layer that obtains tokens from `refresh`
+        pub fn new(refresh: F) -> Self {
+            Self {
+                refresh: Arc::new(refresh),
+                token: Arc::new(std::sync::Mutex::new(None)),
+            }
+        }
+    }
+
+    impl<F> Clone for TokenRefreshLayer<F> {
+        fn clone(&self) -> Self {
+            Self {
+                refresh: Arc::clone(&self.refresh),
+                token: Arc::clone(&self.token),
+            }
+        }
+    }
+
+    impl<F> std::fmt::Debug for TokenRefreshLayer<F> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("TokenRefreshLayer").finish_non_exhaustive()
+        }
+    }
+
+    impl<S, F> Layer<S> for TokenRefreshLayer<F> {
+        type Service = TokenRefresh<S, F>;
+
+        fn layer(&self, inner: S) -> Self::Service {
+            TokenRefresh {
+                inner,
+                refresh: Arc::clone(&self.refresh),
+                token: Arc::clone(&self.token),
+            }
+        }
+    }
+
+    /// Service produced by [`TokenRefreshLayer`]
+    pub struct TokenRefresh<S, F> {
+        inner: S,
+        refresh: Arc<F>,
+        token: Arc<std::sync::Mutex<Option<String>>>,
+    }
+
+    impl<S: Clone, F> Clone for TokenRefresh<S, F> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+                refresh: Arc::clone(&self.refresh),
+                token: Arc::clone(&self.token),
+            }
+        }
+    }
+
+    impl<S: std::fmt::Debug, F> std::fmt::Debug for TokenRefresh<S, F> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("TokenRefresh")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<S, F, Fut> TokenRefresh<S, F>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<String>>,
+    {
+        async fn refresh_token(
+            refresh: &F,
+            cache: &std::sync::Mutex<Option<String>>,
+        ) -> Result<String> {
+            debug!("Refreshing bearer token");
+            let token = refresh().await?;
+            *cache.lock().expect("token cache poisoned") = Some(token.clone());
+            Ok(token)
+        }
+    }
+
+    impl<S, F, Fut> Service<ApiRequest> for TokenRefresh<S, F>
+    where
+        S: Service<HeaderedRequest, Response = ApiResponse, Error = RestError>
+            + Clone
+            + Send
+            + 'static,
+        S::Future: Send,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        type Response = ApiResponse;
+        type Error = RestError;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response>> + Send>>;
+
+        fn poll_ready(
+            &mut self,
+            cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, req: ApiRequest) -> Self::Future {
+            // Take the service that was driven to readiness and leave a clone behind
+            let clone = self.inner.clone();
+            let mut inner = std::mem::replace(&mut self.inner, clone);
+            let refresh = Arc::clone(&self.refresh);
+            let cache = Arc::clone(&self.token);
+
+            Box::pin(async move {
+                let cached = cache.lock().expect("token cache poisoned").clone();
+                let token = match cached {
+                    Some(token) => token,
+                    None => Self::refresh_token(&refresh, &cache).await?,
+                };
+
+                let result = inner.call(with_bearer(req.clone(), &token)).await;
+                match result {
+                    Err(e) if e.is_unauthorized() => {
+                        let token = Self::refresh_token(&refresh, &cache).await?;
+                        std::future::poll_fn(|cx| inner.poll_ready(cx)).await?;
+                        inner.call(with_bearer(req, &token)).await
+                    }
+                    other => other,
+                }
+            })
+        }
+    }
+
+    fn with_bearer(req: ApiRequest, token: &str) -> HeaderedRequest {
+        req.with_header("Authorization", format!("Bearer {}", token))
+    }
+
+    /// Tower layer that transparently retries rate-limited requests
+    ///
+    /// When the inner service fails with [`RestError::RateLimited`], the request is
+    /// retried after the server's `Retry-After` delay (or [`default_delay`] when the
+    /// server gave none), up to `max_retries` times. Unlike `tower::limit`, which
+    /// throttles blindly on the client side, this honors the cluster's own guidance.
+    ///
+    /// [`default_delay`]: RateLimitRetryLayer::default_delay
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use redis_enterprise::tower_support::{ApiRequest, RateLimitRetryLayer};
+    /// use std::time::Duration;
+    /// use tower::{ServiceBuilder, ServiceExt};
+    ///
+    /// let service = ServiceBuilder::new()
+    ///     .layer(RateLimitRetryLayer::new(3).max_delay(Duration::from_secs(30)))
+    ///     .service(client.into_service());
+    ///
+    /// let response = service.oneshot(ApiRequest::get("/v1/bdbs")).await?;
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub struct RateLimitRetryLayer {
+        max_retries: u32,
+        default_delay: Duration,
+        max_delay: Duration,
+    }
+
+    impl RateLimitRetryLayer {
+        /// Create a layer that retries rate-limited requests up to `max_retries` times
+        pub fn new(max_retries: u32) -> Self {
+            Self {
+                max_retries,
+                default_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(60),
+            }
+        }
+
+        /// Delay to use when the server does not send `Retry-After` (default: 1s)
+        #[must_use]
+        pub fn default_delay(mut self, delay: Duration) -> Self {
+            self.default_delay = delay;
+            self
+        }
+
+        /// Upper bound on any single delay, including server-provided ones (default: 60s)
+        #[must_use]
+        pub fn max_delay(mut self, delay: Duration) -> Self {
+            self.max_delay = delay;
+            self
+        }
+    }
+
+    impl<S> Layer<S> for RateLimitRetryLayer {
+        type Service = RateLimitRetry<S>;
+
+        fn layer(&self, inner: S) -> Self::Service {
+            RateLimitRetry {
+                inner,
+                config: *self,
+            }
+        }
+    }
+
+    /// Service produced by [`RateLimitRetryLayer`]
+    #[derive(Debug, Clone)]
+    pub struct RateLimitRetry<S> {
+        inner: S,
+        config: RateLimitRetryLayer,
+    }
+
+    impl<S> Service<ApiRequest> for RateLimitRetry<S>
+    where
+        S: Service<ApiRequest, Response = ApiResponse, Error = RestError> + Clone + Send + 'static,
+        S::Future: Send,
+    {
+        type Response = ApiResponse;
+        type Error = RestError;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response>> + Send>>;
+
+        fn poll_ready(
+            &mut self,
+            cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, req: ApiRequest) -> Self::Future {
+            let clone = self.inner.clone();
+            let mut inner = std::mem::replace(&mut self.inner, clone);
+            let config = self.config;
+
+            Box::pin(async move {
+                let mut attempt = 0;
+                loop {
+                    match inner.call(req.clone()).await {
+                        Err(e) if e.is_rate_limited() && attempt < config.max_retries => {
+                            attempt += 1;
+                            let delay = e
+                                .retry_after()
+                                .unwrap_or(config.default_delay)
+                                .min(config.max_delay);
+                            debug!(
+                                "Rate limited on {}, retrying in {:?} (attempt {}/{})",
+                                req.path, delay, attempt, config.max_retries
+                            );
+                            tokio::time::sleep(delay).await;
+                            std::future::poll_fn(|cx| inner.poll_ready(cx)).await?;
+                        }
+                        other => return other,
+                    }
+                }
+            })
+        }
+    }
 }
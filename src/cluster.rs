@@ -43,10 +43,20 @@
 //! # }
 //! ```
 
+use crate::alerts::{Alert, AlertHandler, AlertThreshold};
+use crate::bootstrap::BootstrapHandler;
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::license::LicenseHandler;
+use crate::retry::Backoff;
+use crate::shards::Shard;
+use crate::users::PasswordPolicy;
+use chrono::{DateTime, Utc};
+use futures::try_join;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
 use typed_builder::TypedBuilder;
 
 /// Response from cluster action operations
@@ -58,6 +68,78 @@ pub struct ClusterActionResponse {
     pub description: Option<String>,
 }
 
+/// A cluster-level action, as listed by `/v1/cluster/actions`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClusterAction {
+    /// Action name, e.g. `optimize_shards_placement`
+    pub name: String,
+    /// Unique identifier of a running or finished action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_uid: Option<String>,
+    /// Current status, e.g. `running`, `completed`, `failed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Percent of completed steps (0-100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f32>,
+    /// ISO 8601 timestamp when the action was started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    /// ISO 8601 timestamp when the action completed or failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    /// Fields this client does not model
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+impl ClusterAction {
+    /// Whether the action has finished: `completed`, `failed` or `cancelled`
+    ///
+    /// An action with no status (only its name was listed) is not running.
+    pub fn is_terminal(&self) -> bool {
+        self.status.as_deref().is_none_or(|status| {
+            matches!(
+                status.to_ascii_lowercase().as_str(),
+                "completed" | "failed" | "cancelled" | "canceled"
+            )
+        })
+    }
+
+    /// Whether the action is still in flight
+    pub fn is_running(&self) -> bool {
+        !self.is_terminal()
+    }
+}
+
+/// A `/v1/cluster/actions` entry: a full action object, or just its name
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawClusterAction {
+    Name(String),
+    Full(ClusterAction),
+}
+
+impl From<RawClusterAction> for ClusterAction {
+    fn from(raw: RawClusterAction) -> Self {
+        match raw {
+            RawClusterAction::Name(name) => ClusterAction {
+                name,
+                ..Default::default()
+            },
+            RawClusterAction::Full(action) => action,
+        }
+    }
+}
+
+/// `/v1/cluster/actions` response, wrapped in `{"actions": [...]}` or a bare list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ClusterActionsResponse {
+    Wrapped { actions: Vec<RawClusterAction> },
+    List(Vec<RawClusterAction>),
+}
+
 /// Node information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClusterNode {
@@ -65,7 +147,9 @@ pub struct ClusterNode {
     pub address: String,
     pub status: String,
     pub role: Option<String>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub total_memory: Option<u64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub used_memory: Option<u64>,
     pub cpu_cores: Option<u32>,
 }
@@ -114,9 +198,11 @@ pub struct ClusterInfo {
 
     // Stats
     /// Total memory available in the cluster
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub total_memory: Option<u64>,
 
     /// Total memory used in the cluster
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub used_memory: Option<u64>,
 
     /// Total number of shards in the cluster
@@ -328,6 +414,228 @@ pub struct ClusterInfo {
     pub wait_command: Option<bool>,
 }
 
+impl ClusterInfo {
+    /// The metrics backend in use, decoded from [`metrics_system`](Self::metrics_system)
+    pub fn metrics_system(&self) -> Option<MetricsSystem> {
+        self.metrics_system.map(MetricsSystem::from)
+    }
+}
+
+/// Comparable Redis Enterprise version (e.g., `7.4.2-54`)
+///
+/// Ordering compares major, minor, patch, and then build number, so
+/// `7.4.2-54 > 7.4.2 > 7.2.0-92`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClusterVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub build: Option<u32>,
+}
+
+impl ClusterVersion {
+    /// Create a version without a build number
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            build: None,
+        }
+    }
+}
+
+impl std::str::FromStr for ClusterVersion {
+    type Err = RestError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || RestError::ParseError(format!("Invalid version: {}", s));
+        let (release, build) = match s.trim().split_once('-') {
+            Some((release, build)) => (release, Some(build.parse().map_err(|_| invalid())?)),
+            None => (s.trim(), None),
+        };
+
+        let mut parts = release.split('.').map(|part| part.parse::<u32>());
+        let major = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let minor = parts
+            .next()
+            .transpose()
+            .map_err(|_| invalid())?
+            .unwrap_or(0);
+        let patch = parts
+            .next()
+            .transpose()
+            .map_err(|_| invalid())?
+            .unwrap_or(0);
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            build,
+        })
+    }
+}
+
+impl std::fmt::Display for ClusterVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(build) = self.build {
+            write!(f, "-{}", build)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ClusterVersion {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ClusterVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Metrics backend selected by `metrics_system`
+///
+/// Serialized as the numeric code the API uses. Codes this client does not
+/// know about are preserved in [`MetricsSystem::Other`]; values compare by
+/// code, so `Other(1)` equals `V1`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum MetricsSystem {
+    /// Legacy (v1) metrics
+    V1,
+    /// Metrics stream engine (v2), exposed through the Prometheus endpoint
+    V2,
+    /// Any other code reported by the cluster
+    Other(u32),
+}
+
+impl From<u32> for MetricsSystem {
+    fn from(code: u32) -> Self {
+        match code {
+            1 => MetricsSystem::V1,
+            2 => MetricsSystem::V2,
+            other => MetricsSystem::Other(other),
+        }
+    }
+}
+
+impl From<MetricsSystem> for u32 {
+    fn from(system: MetricsSystem) -> Self {
+        match system {
+            MetricsSystem::V1 => 1,
+            MetricsSystem::V2 => 2,
+            MetricsSystem::Other(code) => code,
+        }
+    }
+}
+
+impl PartialEq for MetricsSystem {
+    fn eq(&self, other: &Self) -> bool {
+        u32::from(*self) == u32::from(*other)
+    }
+}
+
+impl Eq for MetricsSystem {}
+
+impl std::hash::Hash for MetricsSystem {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        u32::from(*self).hash(state);
+    }
+}
+
+/// Cluster identity fields, without the rest of the cluster configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterIdentity {
+    /// Cluster unique ID
+    pub uid: Option<u32>,
+    /// Cluster's fully qualified domain name
+    pub name: String,
+    /// Cluster software version
+    pub version: Option<ClusterVersion>,
+    /// Cluster creation date
+    pub created: Option<String>,
+    /// Version of the Cluster Manager server
+    pub cm_server_version: Option<u32>,
+}
+
+/// Shard placement across the cluster, as returned by `/v1/cluster/topology`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClusterTopology {
+    /// Nodes in the cluster
+    #[serde(default)]
+    pub nodes: Vec<TopologyNode>,
+    /// Shards and the nodes they are placed on
+    #[serde(default)]
+    pub shards: Vec<TopologyShard>,
+}
+
+impl ClusterTopology {
+    /// Look up a node by UID
+    pub fn node(&self, node_uid: u32) -> Option<&TopologyNode> {
+        self.nodes.iter().find(|node| node.uid == node_uid)
+    }
+
+    /// Shards placed on a node
+    pub fn shards_on_node(&self, node_uid: u32) -> Vec<&TopologyShard> {
+        let node_uid = node_uid.to_string();
+        self.shards
+            .iter()
+            .filter(|shard| shard.node_uid == node_uid)
+            .collect()
+    }
+
+    /// Shards belonging to a database
+    pub fn shards_for_database(&self, bdb_uid: u32) -> Vec<&TopologyShard> {
+        self.shards
+            .iter()
+            .filter(|shard| shard.bdb_uid == bdb_uid)
+            .collect()
+    }
+}
+
+/// A node entry in [`ClusterTopology`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopologyNode {
+    /// Node UID
+    pub uid: u32,
+    /// Internal IP address of the node
+    pub addr: Option<String>,
+    /// Rack or availability zone of the node
+    pub rack_id: Option<String>,
+    /// Node status
+    pub status: Option<String>,
+}
+
+/// A shard entry in [`ClusterTopology`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopologyShard {
+    /// Shard UID
+    pub uid: String,
+    /// Database the shard belongs to
+    pub bdb_uid: u32,
+    /// Node the shard is placed on
+    pub node_uid: String,
+    /// Shard role ("master" or "slave")
+    pub role: String,
+    /// Hash slot range served by the shard
+    pub assigned_slots: Option<String>,
+}
+
 /// Cluster-wide settings configuration (57 fields)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClusterSettings {
@@ -402,6 +710,389 @@ pub struct ClusterSettings {
     pub use_empty_shard_backups: Option<bool>,
 }
 
+/// Cluster-wide defaults and placement policy, as returned by `/v1/cluster/policy`
+///
+/// Every field is optional so the same struct serves as a partial update: only
+/// the fields that are set are sent to [`ClusterHandler::update_policy`].
+/// Fields this client does not model are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TypedBuilder)]
+pub struct ClusterPolicy {
+    /// Default shard placement for new databases ('dense' or 'sparse')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub default_shards_placement: Option<String>,
+    /// Default proxy policy for new sharded databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub default_sharded_proxy_policy: Option<String>,
+    /// Default proxy policy for new non-sharded databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub default_non_sharded_proxy_policy: Option<String>,
+    /// Place master and replica shards in different racks or zones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub rack_aware: Option<bool>,
+    /// Default Redis version for new databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub default_provisioned_redis_version: Option<String>,
+    /// Create new databases with the OSS cluster API enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub default_oss_cluster: Option<bool>,
+    /// Use OSS hashing for new sharded databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub default_oss_sharding: Option<bool>,
+    /// Evict RAM when forking for persistence by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub default_fork_evict_ram: Option<bool>,
+    /// Number of restore actions allowed to run concurrently
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub default_concurrent_restore_actions: Option<u32>,
+    /// Migrate shards off a node before removing it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub persistent_node_removal: Option<bool>,
+    /// Redis version databases are upgraded to ('major' or 'latest')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub redis_upgrade_policy: Option<String>,
+    /// Audit database connections by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub db_conns_auditing: Option<bool>,
+    /// Policy fields not modelled above
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Where database connection audit events are sent, as returned by `/v1/cluster/auditing/db_conns`
+///
+/// Every field is optional so the same struct serves as a partial update: only
+/// the fields that are set are sent to [`ClusterHandler::update_auditing_db_conns`].
+/// Whether new databases are audited by default is the cluster policy's
+/// [`db_conns_auditing`](ClusterPolicy::db_conns_auditing).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TypedBuilder)]
+pub struct AuditingDbConnsConfig {
+    /// Address of the audit listener (a host for TCP, a socket path for local)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub audit_address: Option<String>,
+    /// Port of the audit listener, for TCP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub audit_port: Option<u16>,
+    /// How audit events are delivered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub audit_protocol: Option<AuditProtocol>,
+    /// Seconds between reconnection attempts to the listener
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub audit_reconnect_interval: Option<u32>,
+    /// Reconnection attempts before giving up (0 retries forever)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub audit_reconnect_max_attempts: Option<u32>,
+    /// Fields this client does not model
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Transport for database connection audit events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditProtocol {
+    /// A TCP listener at `audit_address:audit_port`
+    #[serde(rename = "TCP")]
+    Tcp,
+    /// A Unix socket at `audit_address` on each node
+    #[serde(rename = "local")]
+    Local,
+}
+
+/// Cluster and node alert configuration, the `alert_settings` of the cluster object
+///
+/// Alerts left as `None` are not changed by [`ClusterHandler::update_alert_settings`].
+/// Alerts this client does not model are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TypedBuilder)]
+pub struct ClusterAlertSettings {
+    /// CA certificate about to expire \[days\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cluster_ca_cert_about_to_expire: Option<ClusterAlertThreshold>,
+    /// Cluster certificates about to expire \[days\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cluster_certs_about_to_expire: Option<ClusterAlertThreshold>,
+    /// License about to expire \[days\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cluster_license_about_to_expire: Option<ClusterAlertThreshold>,
+    /// Node CPU utilization above threshold \[%\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_cpu_utilization: Option<ClusterAlertThreshold>,
+    /// Node ephemeral storage below threshold \[%\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_ephemeral_storage: Option<ClusterAlertThreshold>,
+    /// Node free flash below threshold \[%\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_free_flash: Option<ClusterAlertThreshold>,
+    /// Node internal certificates about to expire \[days\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_internal_certs_about_to_expire: Option<ClusterAlertThreshold>,
+    /// Node free memory below threshold \[%\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_memory: Option<ClusterAlertThreshold>,
+    /// Node network throughput above threshold \[bytes/sec\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_net_throughput: Option<ClusterAlertThreshold>,
+    /// Node persistent storage below threshold \[%\]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub node_persistent_storage: Option<ClusterAlertThreshold>,
+    /// Alerts this client does not model
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Whether a cluster alert is raised, and at what threshold
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TypedBuilder)]
+pub struct ClusterAlertThreshold {
+    /// True if the alert is enabled
+    #[serde(default)]
+    #[builder(default)]
+    pub enabled: bool,
+    /// Threshold at which the alert is raised
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub threshold: Option<AlertThreshold>,
+}
+
+/// Configuration of the cluster services, as returned by `/v1/cluster/services_configuration`
+///
+/// Services left as `None` are not changed by
+/// [`ClusterHandler::update_services_configuration`]. Services this client does
+/// not model are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TypedBuilder)]
+pub struct ServicesConfiguration {
+    /// Cluster Manager UI server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cm_server: Option<ClusterServiceConfig>,
+    /// Active-Active coordinator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub crdb_coordinator: Option<ClusterServiceConfig>,
+    /// Active-Active worker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub crdb_worker: Option<ClusterServiceConfig>,
+    /// Multicast DNS server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub mdns_server: Option<ClusterServiceConfig>,
+    /// Internal DNS server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub pdns_server: Option<ClusterServiceConfig>,
+    /// SASL authentication daemon used for LDAP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub saslauthd: Option<ClusterServiceConfig>,
+    /// Statistics archiver
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub stats_archiver: Option<ClusterServiceConfig>,
+    /// Services not modelled above
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Configuration of a single cluster service
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClusterServiceConfig {
+    /// Operating mode of the service ('enabled' or 'disabled')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operating_mode: Option<String>,
+    /// Whether the service is enabled, for services that report a flag instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Service-specific settings
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+impl ClusterServiceConfig {
+    /// A configuration that turns the service on
+    pub fn enabled() -> Self {
+        Self {
+            operating_mode: Some("enabled".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A configuration that turns the service off
+    pub fn disabled() -> Self {
+        Self {
+            operating_mode: Some("disabled".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the service is enabled, from either `operating_mode` or `enabled`
+    pub fn is_enabled(&self) -> bool {
+        match &self.operating_mode {
+            Some(mode) => mode == "enabled",
+            None => self.enabled.unwrap_or(false),
+        }
+    }
+}
+
+/// Witness disk status, as returned by `/v1/cluster/witness_disk`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WitnessDiskStatus {
+    /// Whether a witness disk is configured for the cluster
+    #[serde(default)]
+    pub configured: bool,
+    /// Whether the witness disk is healthy
+    pub healthy: Option<bool>,
+    /// Mount path of the witness disk
+    pub path: Option<String>,
+    /// Witness disk reachability from each node
+    #[serde(default)]
+    pub nodes: Vec<WitnessDiskNode>,
+    /// Status fields not modelled above
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+impl WitnessDiskStatus {
+    /// Whether the witness disk currently protects the cluster against split brain
+    ///
+    /// True only if the disk is configured, reported healthy, and reachable from
+    /// every node that reported its status.
+    pub fn is_quorum_protected(&self) -> bool {
+        self.configured
+            && self.healthy == Some(true)
+            && self.nodes.iter().all(|node| node.reachable)
+    }
+
+    /// Nodes that cannot reach the witness disk
+    pub fn unreachable_nodes(&self) -> Vec<u32> {
+        self.nodes
+            .iter()
+            .filter(|node| !node.reachable)
+            .map(|node| node.node_uid)
+            .collect()
+    }
+}
+
+/// Witness disk reachability from a single node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WitnessDiskNode {
+    /// Node unique ID
+    pub node_uid: u32,
+    /// Whether the node can reach the witness disk
+    pub reachable: bool,
+    /// Error reported by the node when it cannot reach the disk
+    pub error: Option<String>,
+}
+
+/// Modules available on the cluster, as returned by `/v1/cluster/module-capabilities`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModuleCapabilities {
+    /// Every capability a module can declare
+    #[serde(default)]
+    pub all_capabilities: Vec<CapabilityDescription>,
+    /// Modules installed on the cluster
+    #[serde(default)]
+    pub modules: Vec<ModuleCapability>,
+}
+
+impl ModuleCapabilities {
+    /// Look up a module by name or display name, ignoring case
+    pub fn module(&self, module_name: &str) -> Option<&ModuleCapability> {
+        self.modules.iter().find(|module| {
+            module.module_name.eq_ignore_ascii_case(module_name)
+                || module
+                    .display_name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(module_name))
+        })
+    }
+
+    /// Whether the cluster can provide `module_name` at `version`
+    ///
+    /// A module version supports any request with the same major version that is
+    /// not newer than itself, so `2.6.6` satisfies `2.6` and `2.4.1` but not `3.0`.
+    /// Versions that do not parse as `major.minor.patch` must match exactly.
+    pub fn supports(&self, module_name: &str, version: &str) -> bool {
+        let Some(module) = self.module(module_name) else {
+            return false;
+        };
+        let requested = version.parse::<ClusterVersion>().ok();
+        module
+            .supported_versions()
+            .any(|available| match requested {
+                Some(requested) => available.parse::<ClusterVersion>().is_ok_and(|available| {
+                    available.major == requested.major && available >= requested
+                }),
+                None => available == version,
+            })
+    }
+}
+
+/// Description of a module capability
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityDescription {
+    /// Capability name (e.g., "types", "crdb", "flash")
+    pub name: String,
+    /// What the capability means
+    pub desc: Option<String>,
+}
+
+/// A module installed on the cluster and the versions available for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleCapability {
+    /// Module name (e.g., "search", "ReJSON")
+    #[serde(alias = "name")]
+    pub module_name: String,
+    /// Human readable module name
+    pub display_name: Option<String>,
+    /// Latest installed version
+    pub semantic_version: Option<String>,
+    /// All installed versions, when the cluster has more than one
+    #[serde(default)]
+    pub versions: Vec<String>,
+    /// Capabilities the module declares
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+impl ModuleCapability {
+    /// Every installed version of the module
+    pub fn supported_versions(&self) -> impl Iterator<Item = &str> {
+        self.semantic_version
+            .iter()
+            .chain(&self.versions)
+            .map(String::as_str)
+    }
+}
+
 /// Bootstrap request for creating a new cluster
 #[derive(Debug, Serialize, TypedBuilder)]
 pub struct BootstrapRequest {
@@ -442,6 +1133,27 @@ impl ClusterHandler {
         self.client.get("/v1/cluster").await
     }
 
+    /// Get the cluster's identity fields (name, uid, version, creation date)
+    ///
+    /// Lighter than [`info`](Self::info) when only identity is needed; the
+    /// version is parsed into a comparable [`ClusterVersion`].
+    pub async fn identity(&self) -> Result<ClusterIdentity> {
+        self.client.get("/v1/cluster").await
+    }
+
+    /// Get the cluster's SSH public key
+    ///
+    /// New nodes must authorize this key before joining. Returns
+    /// [`RestError::NotFound`] if the cluster does not report one.
+    pub async fn ssh_public_key(&self) -> Result<String> {
+        self.info()
+            .await?
+            .cluster_ssh_public_key
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty())
+            .ok_or(RestError::NotFound { request_id: None })
+    }
+
     /// Bootstrap a new cluster (CLUSTER.BOOTSTRAP)
     pub async fn bootstrap(&self, request: BootstrapRequest) -> Result<Value> {
         // The bootstrap endpoint returns empty response on success
@@ -456,6 +1168,99 @@ impl ClusterHandler {
         self.client.put("/v1/cluster", &updates).await
     }
 
+    /// Select the cluster's metrics backend
+    pub async fn set_metrics_system(&self, system: MetricsSystem) -> Result<ClusterInfo> {
+        self.client
+            .put(
+                "/v1/cluster",
+                &serde_json::json!({ "metrics_system": system }),
+            )
+            .await
+    }
+
+    /// Get the cluster and node alert settings
+    ///
+    /// Reads `alert_settings` from the cluster object; a cluster that reports
+    /// none yields the default (empty) settings.
+    pub async fn alert_settings(&self) -> Result<ClusterAlertSettings> {
+        match self.info().await?.alert_settings {
+            Some(settings) => Ok(serde_json::from_value(settings)?),
+            None => Ok(ClusterAlertSettings::default()),
+        }
+    }
+
+    /// Update the alerts that are set in `settings` and return the resulting settings
+    ///
+    /// Alerts left as `None` keep their current configuration on the cluster.
+    /// The current settings are read first and only alerts that differ from
+    /// them are sent, so passing back settings read with
+    /// [`alert_settings`](Self::alert_settings) rewrites nothing but the
+    /// changed alerts.
+    pub async fn update_alert_settings(
+        &self,
+        settings: ClusterAlertSettings,
+    ) -> Result<ClusterAlertSettings> {
+        let current = self
+            .info()
+            .await?
+            .alert_settings
+            .unwrap_or_else(|| serde_json::json!({}));
+        let Value::Object(mut changed) = serde_json::to_value(&settings)? else {
+            return Ok(serde_json::from_value(current)?);
+        };
+        changed.retain(|name, alert| current.get(name) != Some(alert));
+        if changed.is_empty() {
+            return Ok(serde_json::from_value(current)?);
+        }
+
+        let info: ClusterInfo = self
+            .client
+            .put(
+                "/v1/cluster",
+                &serde_json::json!({ "alert_settings": changed }),
+            )
+            .await?;
+        match info.alert_settings {
+            Some(settings) => Ok(serde_json::from_value(settings)?),
+            None => self.alert_settings().await,
+        }
+    }
+
+    /// Block cluster configuration changes, e.g. before an upgrade
+    ///
+    /// Sets `block_cluster_changes` and re-reads the cluster to confirm it took
+    /// effect. Returns [`RestError::ServerError`] if the cluster still reports
+    /// changes as allowed.
+    pub async fn enter_maintenance(&self) -> Result<ClusterInfo> {
+        self.set_block_cluster_changes(true).await
+    }
+
+    /// Allow cluster configuration changes again after [`enter_maintenance`](Self::enter_maintenance)
+    pub async fn exit_maintenance(&self) -> Result<ClusterInfo> {
+        self.set_block_cluster_changes(false).await
+    }
+
+    async fn set_block_cluster_changes(&self, blocked: bool) -> Result<ClusterInfo> {
+        self.client
+            .put_action(
+                "/v1/cluster",
+                &serde_json::json!({ "block_cluster_changes": blocked }),
+            )
+            .await?;
+        let info = self.info().await?;
+        if info.block_cluster_changes.unwrap_or(false) != blocked {
+            return Err(RestError::ServerError {
+                message: format!(
+                    "cluster did not {} maintenance mode: block_cluster_changes is {}",
+                    if blocked { "enter" } else { "exit" },
+                    !blocked
+                ),
+                request_id: None,
+            });
+        }
+        Ok(info)
+    }
+
     /// Get cluster stats (CLUSTER.STATS)
     pub async fn stats(&self) -> Result<Value> {
         self.client.get("/v1/cluster/stats").await
@@ -466,6 +1271,29 @@ impl ClusterHandler {
         self.client.get("/v1/nodes").await
     }
 
+    /// Summarize cluster health in a single report
+    ///
+    /// Fetches cluster info, nodes, alerts and the license concurrently and
+    /// aggregates them into a [`ClusterHealth`].
+    pub async fn health_report(&self) -> Result<ClusterHealth> {
+        let alerts = AlertHandler::new(self.client.clone());
+        let license = LicenseHandler::new(self.client.clone());
+        let (info, nodes, alerts, license) =
+            try_join!(self.info(), self.nodes(), alerts.list(), license.get())?;
+
+        Ok(ClusterHealth {
+            cluster_name: info.name,
+            node_count: nodes.len(),
+            degraded_nodes: nodes.into_iter().filter(|n| n.status != "active").collect(),
+            active_critical_alerts: alerts
+                .into_iter()
+                .filter(|a| a.is_active() && a.is_critical())
+                .collect(),
+            license_ok: !license.expired,
+            license_expiration_date: license.expiration_date,
+        })
+    }
+
     /// Get cluster license (CLUSTER.LICENSE)
     pub async fn license(&self) -> Result<LicenseInfo> {
         self.client.get("/v1/license").await
@@ -491,6 +1319,66 @@ impl ClusterHandler {
         self.client.post("/v1/bootstrap/join", &body).await
     }
 
+    /// Join a node to the cluster and wait until it is active
+    ///
+    /// Issues [`join_node`](Self::join_node), then polls [`nodes`](Self::nodes)
+    /// every `poll_interval` until a node with `node_address` is listed with
+    /// status `active`, and returns it. Meanwhile the joining node's own
+    /// bootstrap status (`/v1/bootstrap` on `node_address`, same scheme and
+    /// port) is checked; if it reports the join failed, that failure is
+    /// returned as [`RestError::ServerError`]. Returns [`RestError::Timeout`]
+    /// if the node is not active within `timeout`.
+    pub async fn join_node_and_wait(
+        &self,
+        node_address: &str,
+        username: &str,
+        password: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<NodeInfo> {
+        self.join_node(node_address, username, password).await?;
+
+        let bootstrap = BootstrapHandler::new(self.client.for_node(node_address)?);
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let joined = self
+                .nodes()
+                .await?
+                .into_iter()
+                .find(|n| n.address == node_address);
+            if let Some(node) = joined
+                && node.status == "active"
+            {
+                return Ok(node);
+            }
+
+            // The joining node may be unreachable while it restarts its services
+            match bootstrap.status().await {
+                Ok(status) if status.is_failed() => {
+                    return Err(RestError::ServerError {
+                        message: format!(
+                            "Joining node {} failed: {}",
+                            node_address,
+                            status.message.as_deref().unwrap_or(&status.status)
+                        ),
+                        request_id: None,
+                    });
+                }
+                Ok(_) => {}
+                Err(e)
+                    if e.is_not_found()
+                        || e.is_timeout()
+                        || matches!(e, RestError::ConnectionError(_)) => {}
+                Err(e) => return Err(e),
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Remove node from cluster (CLUSTER.REMOVE_NODE)
     pub async fn remove_node(&self, node_uid: u32) -> Result<Value> {
         self.client
@@ -499,6 +1387,37 @@ impl ClusterHandler {
         Ok(serde_json::json!({"message": format!("Node {} removed", node_uid)}))
     }
 
+    /// Remove a node and wait until it has been fully decommissioned
+    ///
+    /// Issues [`remove_node`](Self::remove_node), then polls every `poll` until the
+    /// node is no longer listed in [`nodes`](Self::nodes) and no shard is placed on
+    /// it. Returns [`RestError::Timeout`] if that does not happen within `timeout`.
+    pub async fn remove_node_and_wait(
+        &self,
+        node_uid: u32,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.remove_node(node_uid).await?;
+
+        let mut delays = Backoff::fixed(poll).timeout(timeout);
+        let node_id = node_uid.to_string();
+        loop {
+            let node_listed = self.nodes().await?.iter().any(|n| n.uid == node_uid);
+            if !node_listed {
+                let shards: Vec<Shard> = self.client.get("/v1/shards").await?;
+                if !shards.iter().any(|shard| shard.node_uid == node_id) {
+                    return Ok(());
+                }
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Reset cluster to factory defaults (CLUSTER.RESET) - DANGEROUS
     pub async fn reset(&self) -> Result<ClusterActionResponse> {
         self.client
@@ -527,6 +1446,11 @@ impl ClusterHandler {
         self.client.get("/v1/cluster/topology").await
     }
 
+    /// Get cluster topology as a typed [`ClusterTopology`]
+    pub async fn topology_typed(&self) -> Result<ClusterTopology> {
+        self.client.get("/v1/cluster/topology").await
+    }
+
     /// List available cluster actions - GET /v1/cluster/actions
     pub async fn actions(&self) -> Result<Value> {
         self.client.get("/v1/cluster/actions").await
@@ -539,6 +1463,29 @@ impl ClusterHandler {
             .await
     }
 
+    /// List cluster actions as [`ClusterAction`]s
+    ///
+    /// Actions the cluster lists by name only have just `name` set.
+    pub async fn actions_typed(&self) -> Result<Vec<ClusterAction>> {
+        let actions = match self
+            .client
+            .get::<ClusterActionsResponse>("/v1/cluster/actions")
+            .await?
+        {
+            ClusterActionsResponse::Wrapped { actions } | ClusterActionsResponse::List(actions) => {
+                actions
+            }
+        };
+        Ok(actions.into_iter().map(ClusterAction::from).collect())
+    }
+
+    /// Get a specific cluster action as a [`ClusterAction`]
+    pub async fn action_detail_typed(&self, action: &str) -> Result<ClusterAction> {
+        self.client
+            .get(&format!("/v1/cluster/actions/{}", action))
+            .await
+    }
+
     /// Execute a specific cluster action - POST /v1/cluster/actions/{action}
     pub async fn action_execute(&self, action: &str, body: Value) -> Result<Value> {
         self.client
@@ -563,6 +1510,23 @@ impl ClusterHandler {
         self.client.put("/v1/cluster/auditing/db_conns", &cfg).await
     }
 
+    /// Get auditing DB connections as an [`AuditingDbConnsConfig`] - GET /v1/cluster/auditing/db_conns
+    pub async fn auditing_db_conns_typed(&self) -> Result<AuditingDbConnsConfig> {
+        self.client.get("/v1/cluster/auditing/db_conns").await
+    }
+
+    /// Update the auditing fields that are set in `config` and return the resulting configuration
+    ///
+    /// Fields left as `None` keep their current value on the cluster.
+    pub async fn update_auditing_db_conns(
+        &self,
+        config: AuditingDbConnsConfig,
+    ) -> Result<AuditingDbConnsConfig> {
+        self.client
+            .put("/v1/cluster/auditing/db_conns", &config)
+            .await
+    }
+
     /// Delete auditing DB connections - DELETE /v1/cluster/auditing/db_conns
     pub async fn auditing_db_conns_delete(&self) -> Result<()> {
         self.client.delete("/v1/cluster/auditing/db_conns").await
@@ -587,6 +1551,50 @@ impl ClusterHandler {
             .await
     }
 
+    /// Fetch the cluster metadata summarized in [`ClusterCapabilities`]
+    ///
+    /// Always makes the round trips; [`EnterpriseClient::capabilities`](crate::EnterpriseClient::capabilities)
+    /// caches the result per client.
+    pub async fn capabilities(&self) -> Result<ClusterCapabilities> {
+        let (cluster, modules) = try_join!(
+            self.client.get::<Value>("/v1/cluster"),
+            self.module_capabilities_typed()
+        )?;
+        let identity: ClusterIdentity = serde_json::from_value(cluster.clone())?;
+        let info: ClusterInfo = serde_json::from_value(cluster)?;
+        Ok(ClusterCapabilities {
+            version: identity.version,
+            crdt_featureset_version: info.crdt_supported_featureset_version,
+            password_policy: PasswordPolicy::from_cluster(&info),
+            crdt_protocol_versions: info.crdt_supported_protocol_versions.unwrap_or_default(),
+            modules,
+        })
+    }
+
+    /// List cluster certificates with their serial numbers and validity periods
+    ///
+    /// Certificates are read from the PEM entries of
+    /// [`certificates`](Self::certificates); private keys are skipped.
+    #[cfg(feature = "certificate-parsing")]
+    pub async fn certificates_typed(&self) -> Result<Vec<CertificateInfo>> {
+        Ok(CertificateInfo::from_certificates(
+            &self.certificates().await?,
+        ))
+    }
+
+    /// Rotate certificates, then re-read them to confirm which were replaced
+    ///
+    /// A certificate counts as rotated when its serial number changed.
+    /// Certificates that vanished or can no longer be parsed after the rotation
+    /// are reported in [`CertRotationResult::failed`].
+    #[cfg(feature = "certificate-parsing")]
+    pub async fn rotate_and_verify(&self) -> Result<CertRotationResult> {
+        let before = self.certificates_typed().await?;
+        self.certificates_rotate().await?;
+        let after = self.certificates_typed().await?;
+        Ok(CertRotationResult::diff(&before, &after))
+    }
+
     /// Update certificate bundle - PUT /v1/cluster/update_cert
     pub async fn update_cert(&self, body: Value) -> Result<Value> {
         self.client.put("/v1/cluster/update_cert", &body).await
@@ -602,6 +1610,11 @@ impl ClusterHandler {
         self.client.get("/v1/cluster/module-capabilities").await
     }
 
+    /// Get cluster module capabilities as [`ModuleCapabilities`] - GET /v1/cluster/module-capabilities
+    pub async fn module_capabilities_typed(&self) -> Result<ModuleCapabilities> {
+        self.client.get("/v1/cluster/module-capabilities").await
+    }
+
     /// Get cluster policy - GET /v1/cluster/policy
     pub async fn policy(&self) -> Result<Value> {
         self.client.get("/v1/cluster/policy").await
@@ -612,6 +1625,21 @@ impl ClusterHandler {
         self.client.put("/v1/cluster/policy", &policy).await
     }
 
+    /// Get cluster policy as a [`ClusterPolicy`] - GET /v1/cluster/policy
+    pub async fn policy_typed(&self) -> Result<ClusterPolicy> {
+        self.client.get("/v1/cluster/policy").await
+    }
+
+    /// Update the policy fields that are set in `policy` and return the resulting policy
+    ///
+    /// Fields left as `None` keep their current value on the cluster.
+    pub async fn update_policy(&self, policy: ClusterPolicy) -> Result<ClusterPolicy> {
+        self.client
+            .put_action("/v1/cluster/policy", &policy)
+            .await?;
+        self.policy_typed().await
+    }
+
     /// Restore default cluster policy - PUT /v1/cluster/policy/restore_default
     pub async fn policy_restore_default(&self) -> Result<Value> {
         self.client
@@ -631,11 +1659,31 @@ impl ClusterHandler {
             .await
     }
 
+    /// Get services configuration as a [`ServicesConfiguration`] - GET /v1/cluster/services_configuration
+    pub async fn services_configuration_typed(&self) -> Result<ServicesConfiguration> {
+        self.client.get("/v1/cluster/services_configuration").await
+    }
+
+    /// Update the services that are set in `cfg` - PUT /v1/cluster/services_configuration
+    pub async fn update_services_configuration(
+        &self,
+        cfg: ServicesConfiguration,
+    ) -> Result<ServicesConfiguration> {
+        self.client
+            .put("/v1/cluster/services_configuration", &cfg)
+            .await
+    }
+
     /// Get witness disk info - GET /v1/cluster/witness_disk
     pub async fn witness_disk(&self) -> Result<Value> {
         self.client.get("/v1/cluster/witness_disk").await
     }
 
+    /// Get witness disk status as a [`WitnessDiskStatus`] - GET /v1/cluster/witness_disk
+    pub async fn witness_disk_status(&self) -> Result<WitnessDiskStatus> {
+        self.client.get("/v1/cluster/witness_disk").await
+    }
+
     /// Get specific cluster alert detail - GET /v1/cluster/alerts/{alert}
     pub async fn alert_detail(&self, alert: &str) -> Result<Value> {
         self.client
@@ -644,6 +1692,215 @@ impl ClusterHandler {
     }
 }
 
+/// Cluster metadata that rarely changes, as returned by [`ClusterHandler::capabilities`]
+#[derive(Debug, Clone, Default)]
+pub struct ClusterCapabilities {
+    /// Cluster software version
+    pub version: Option<ClusterVersion>,
+    /// Supported CRDT featureset version
+    pub crdt_featureset_version: Option<u32>,
+    /// Supported CRDT protocol versions
+    pub crdt_protocol_versions: Vec<String>,
+    /// Modules installed on the cluster
+    pub modules: ModuleCapabilities,
+    /// Password rules for new users
+    pub password_policy: PasswordPolicy,
+}
+
+impl ClusterCapabilities {
+    /// Whether the cluster version is known and at least `version`
+    pub fn version_at_least(&self, version: ClusterVersion) -> bool {
+        self.version.is_some_and(|current| current >= version)
+    }
+
+    /// Whether a module is installed, by name or display name, ignoring case
+    pub fn supports_module(&self, module_name: &str) -> bool {
+        self.modules.module(module_name).is_some()
+    }
+}
+
+/// A cluster certificate, as returned by [`ClusterHandler::certificates_typed`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificateInfo {
+    /// Certificate name, e.g. `api_cert` or `proxy_cert`
+    pub name: String,
+    /// Serial number as colon-separated hex; `None` if the certificate could not be parsed
+    pub serial_number: Option<String>,
+    /// Start of the validity period
+    pub not_before: Option<DateTime<Utc>>,
+    /// End of the validity period; `None` if the certificate could not be parsed
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CertificateInfo {
+    /// Extract the certificates from a `/v1/cluster/certificates` response
+    ///
+    /// Every string entry holding a PEM certificate is included, in name order.
+    #[cfg(feature = "certificate-parsing")]
+    pub fn from_certificates(value: &Value) -> Vec<Self> {
+        let Some(entries) = value.as_object() else {
+            return Vec::new();
+        };
+        let mut certificates: Vec<Self> = entries
+            .iter()
+            .filter_map(|(name, pem)| {
+                let pem = pem
+                    .as_str()
+                    .filter(|pem| pem.contains("BEGIN CERTIFICATE"))?;
+                let parsed = parse_certificate(pem);
+                Some(CertificateInfo {
+                    name: name.clone(),
+                    serial_number: parsed.as_ref().map(|(serial, _, _)| serial.clone()),
+                    not_before: parsed.as_ref().map(|(_, not_before, _)| *not_before),
+                    expires_at: parsed.as_ref().map(|(_, _, not_after)| *not_after),
+                })
+            })
+            .collect();
+        certificates.sort_by(|a, b| a.name.cmp(&b.name));
+        certificates
+    }
+}
+
+/// Outcome of [`ClusterHandler::rotate_and_verify`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CertRotationResult {
+    /// Certificates whose serial number changed, including ones added by the rotation
+    pub rotated: Vec<RotatedCertificate>,
+    /// Certificates left as they were, e.g. user-supplied API certificates
+    pub unchanged: Vec<CertificateInfo>,
+    /// Certificates that could not be verified after the rotation
+    pub failed: Vec<CertRotationFailure>,
+}
+
+impl CertRotationResult {
+    /// Compare certificate listings from before and after a rotation
+    pub fn diff(before: &[CertificateInfo], after: &[CertificateInfo]) -> Self {
+        let mut result = CertRotationResult::default();
+        for previous in before {
+            let Some(current) = after.iter().find(|cert| cert.name == previous.name) else {
+                result.failed.push(CertRotationFailure {
+                    name: previous.name.clone(),
+                    previous_expiry: previous.expires_at,
+                    reason: CertRotationFailureReason::Missing,
+                });
+                continue;
+            };
+            match (&current.serial_number, current.expires_at) {
+                (Some(serial), Some(expiry)) if previous.serial_number.as_ref() != Some(serial) => {
+                    result.rotated.push(RotatedCertificate {
+                        name: previous.name.clone(),
+                        previous_serial: previous.serial_number.clone(),
+                        new_serial: serial.clone(),
+                        previous_expiry: previous.expires_at,
+                        new_expiry: expiry,
+                    })
+                }
+                (Some(_), Some(_)) => result.unchanged.push(current.clone()),
+                _ => result.failed.push(CertRotationFailure {
+                    name: previous.name.clone(),
+                    previous_expiry: previous.expires_at,
+                    reason: CertRotationFailureReason::Unreadable,
+                }),
+            }
+        }
+        for current in after {
+            if let (Some(serial), Some(expiry)) = (&current.serial_number, current.expires_at)
+                && !before.iter().any(|cert| cert.name == current.name)
+            {
+                result.rotated.push(RotatedCertificate {
+                    name: current.name.clone(),
+                    previous_serial: None,
+                    new_serial: serial.clone(),
+                    previous_expiry: None,
+                    new_expiry: expiry,
+                });
+            }
+        }
+        result
+    }
+
+    /// Whether at least one certificate was rotated and none failed verification
+    pub fn is_success(&self) -> bool {
+        !self.rotated.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// A certificate replaced by a rotation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotatedCertificate {
+    /// Certificate name
+    pub name: String,
+    /// Serial number before the rotation, if it was known
+    pub previous_serial: Option<String>,
+    /// Serial number of the new certificate
+    pub new_serial: String,
+    /// Expiry before the rotation, if it was known
+    pub previous_expiry: Option<DateTime<Utc>>,
+    /// Expiry of the new certificate
+    pub new_expiry: DateTime<Utc>,
+}
+
+/// A certificate that could not be verified after a rotation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertRotationFailure {
+    /// Certificate name
+    pub name: String,
+    /// Expiry before the rotation, if it was known
+    pub previous_expiry: Option<DateTime<Utc>>,
+    /// What went wrong
+    pub reason: CertRotationFailureReason,
+}
+
+/// Why a certificate failed verification after a rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CertRotationFailureReason {
+    /// The certificate is no longer listed
+    Missing,
+    /// The certificate is listed but could not be parsed
+    Unreadable,
+}
+
+/// Serial number and validity period (`notBefore`, `notAfter`) of the first
+/// certificate in `pem`
+#[cfg(feature = "certificate-parsing")]
+fn parse_certificate(pem: &str) -> Option<(String, DateTime<Utc>, DateTime<Utc>)> {
+    let pem = x509_parser::pem::Pem::iter_from_buffer(pem.as_bytes())
+        .filter_map(std::result::Result::ok)
+        .find(|pem| pem.label == "CERTIFICATE")?;
+    let certificate = pem.parse_x509().ok()?;
+    let validity = certificate.validity();
+    Some((
+        certificate.raw_serial_as_string(),
+        DateTime::from_timestamp(validity.not_before.timestamp(), 0)?,
+        DateTime::from_timestamp(validity.not_after.timestamp(), 0)?,
+    ))
+}
+
+/// Aggregated cluster health, as returned by [`ClusterHandler::health_report`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterHealth {
+    /// Cluster name
+    pub cluster_name: String,
+    /// Number of nodes in the cluster
+    pub node_count: usize,
+    /// Nodes whose status is not `active`
+    pub degraded_nodes: Vec<NodeInfo>,
+    /// Triggered alerts with CRITICAL severity
+    pub active_critical_alerts: Vec<Alert>,
+    /// Whether the cluster license is valid (not expired)
+    pub license_ok: bool,
+    /// License expiration date, if reported
+    pub license_expiration_date: Option<String>,
+}
+
+impl ClusterHealth {
+    /// Whether all nodes are active, no critical alert is triggered and the license is valid
+    pub fn is_healthy(&self) -> bool {
+        self.degraded_nodes.is_empty() && self.active_critical_alerts.is_empty() && self.license_ok
+    }
+}
+
 /// Node information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeInfo {
@@ -652,7 +1909,9 @@ pub struct NodeInfo {
     pub status: String,
     pub role: Option<String>,
     pub shards: Option<Vec<u32>>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub total_memory: Option<u64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub used_memory: Option<u64>,
 }
 
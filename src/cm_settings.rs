@@ -8,6 +8,8 @@
 use crate::client::RestClient;
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use typed_builder::TypedBuilder;
 
 /// Cluster Manager settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +37,133 @@ pub struct CmSettings {
     pub max_simultaneous_backups: Option<u32>,
 }
 
+/// Cluster Manager settings as stored, including settings without a typed field
+///
+/// Returned by [`CmSettingsHandler::get_info`] and [`CmSettingsHandler::update`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CmSettingsInfo {
+    /// Port number for the Cluster Manager service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cm_port: Option<u16>,
+    /// Session timeout for Cluster Manager connections in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cm_session_timeout: Option<u32>,
+    /// Message shown on the Cluster Manager login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_message: Option<String>,
+    /// Banner shown at the top of every Cluster Manager page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<String>,
+    /// Time zone used to display times in the Cluster Manager
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Enable automatic recovery of failed databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_recovery: Option<bool>,
+    /// Enable automatic failover for high availability
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_failover: Option<bool>,
+    /// Enable slave high availability for replica databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slave_ha: Option<bool>,
+    /// Grace period in seconds before triggering slave high availability
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slave_ha_grace_period: Option<u32>,
+    /// Maximum number of simultaneous backup operations allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_simultaneous_backups: Option<u32>,
+    /// Other settings returned by the API, by name
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A partial update of Cluster Manager settings
+///
+/// Only the fields that are set are sent, so other settings keep their
+/// current values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+pub struct CmSettingsUpdate {
+    /// Session timeout for Cluster Manager connections in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cm_session_timeout: Option<u32>,
+    /// Message shown on the Cluster Manager login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub login_message: Option<String>,
+    /// Banner shown at the top of every Cluster Manager page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub banner: Option<String>,
+    /// Time zone used to display times in the Cluster Manager
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub timezone: Option<String>,
+    /// Enable automatic recovery of failed databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub auto_recovery: Option<bool>,
+    /// Enable automatic failover for high availability
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub auto_failover: Option<bool>,
+    /// Enable slave high availability for replica databases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub slave_ha: Option<bool>,
+    /// Grace period in seconds before triggering slave high availability
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub slave_ha_grace_period: Option<u32>,
+    /// Maximum number of simultaneous backup operations allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub max_simultaneous_backups: Option<u32>,
+    /// Port number for the Cluster Manager service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub cm_port: Option<u16>,
+    /// Other settings to change, by API name
+    #[serde(flatten)]
+    #[builder(default)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<CmSettings> for CmSettingsUpdate {
+    /// Send every setting present in `settings`
+    fn from(settings: CmSettings) -> Self {
+        CmSettingsUpdate {
+            cm_session_timeout: settings.cm_session_timeout,
+            auto_recovery: settings.auto_recovery,
+            auto_failover: settings.auto_failover,
+            slave_ha: settings.slave_ha,
+            slave_ha_grace_period: settings.slave_ha_grace_period,
+            max_simultaneous_backups: settings.max_simultaneous_backups,
+            cm_port: settings.cm_port,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CmSettingsInfo> for CmSettingsUpdate {
+    /// Send every setting present in `settings`, including `extra`
+    fn from(settings: CmSettingsInfo) -> Self {
+        CmSettingsUpdate {
+            cm_session_timeout: settings.cm_session_timeout,
+            login_message: settings.login_message,
+            banner: settings.banner,
+            timezone: settings.timezone,
+            auto_recovery: settings.auto_recovery,
+            auto_failover: settings.auto_failover,
+            slave_ha: settings.slave_ha,
+            slave_ha_grace_period: settings.slave_ha_grace_period,
+            max_simultaneous_backups: settings.max_simultaneous_backups,
+            cm_port: settings.cm_port,
+            extra: settings.extra,
+        }
+    }
+}
+
 /// Cluster Manager settings handler
 pub struct CmSettingsHandler {
     client: RestClient,
@@ -50,9 +179,19 @@ impl CmSettingsHandler {
         self.client.get("/v1/cm_settings").await
     }
 
+    /// Get Cluster Manager settings, including the UI settings and any
+    /// settings without a typed field
+    pub async fn get_info(&self) -> Result<CmSettingsInfo> {
+        self.client.get("/v1/cm_settings").await
+    }
+
     /// Update Cluster Manager settings
-    pub async fn update(&self, settings: CmSettings) -> Result<CmSettings> {
-        self.client.put("/v1/cm_settings", &settings).await
+    ///
+    /// Accepts a [`CmSettingsUpdate`] to change only some settings, or a full
+    /// [`CmSettings`] or [`CmSettingsInfo`] to send every setting it holds.
+    /// Returns the settings as stored after the update.
+    pub async fn update(&self, settings: impl Into<CmSettingsUpdate>) -> Result<CmSettingsInfo> {
+        self.client.put("/v1/cm_settings", &settings.into()).await
     }
 
     /// Reset Cluster Manager settings to defaults
@@ -6,7 +6,7 @@
 //! - Monitor CRDB status
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use typed_builder::TypedBuilder;
@@ -120,6 +120,75 @@ pub struct CreateCrdbInstance {
     pub password: Option<String>,
 }
 
+impl CreateCrdbRequest {
+    /// Check the request and every instance before sending it
+    ///
+    /// See [`CreateCrdbInstance::validate`] for the per-instance checks.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(RestError::ValidationError(
+                "Active-Active database name must not be empty".to_string(),
+            ));
+        }
+        if self.memory_size == 0 {
+            return Err(RestError::ValidationError(format!(
+                "memory_size for Active-Active database '{}' must be greater than zero",
+                self.name
+            )));
+        }
+        if self.instances.is_empty() {
+            return Err(RestError::ValidationError(format!(
+                "Active-Active database '{}' needs at least one instance",
+                self.name
+            )));
+        }
+        self.instances
+            .iter()
+            .try_for_each(CreateCrdbInstance::validate)
+    }
+}
+
+impl CreateCrdbInstance {
+    /// Check that the instance's cluster can be reached
+    ///
+    /// Either `cluster_url` must be a valid `http(s)` URL, or, without one,
+    /// `cluster` must be a `host:port` address. `cluster` alone as a bare name
+    /// (e.g. `cluster1.example.com`) is a common mistake the cluster only
+    /// reports once it fails to connect.
+    pub fn validate(&self) -> Result<()> {
+        let cluster = self.cluster.trim();
+        if cluster.is_empty() {
+            return Err(RestError::ValidationError(
+                "instance cluster name must not be empty".to_string(),
+            ));
+        }
+        if let Some(cluster_url) = &self.cluster_url {
+            let valid = url::Url::parse(cluster_url).is_ok_and(|url| {
+                matches!(url.scheme(), "http" | "https") && url.host_str().is_some()
+            });
+            return if valid {
+                Ok(())
+            } else {
+                Err(RestError::ValidationError(format!(
+                    "instance '{}' has an invalid cluster_url '{}', expected e.g. https://{}:9443",
+                    cluster, cluster_url, cluster
+                )))
+            };
+        }
+        let is_host_port = cluster.rsplit_once(':').is_some_and(|(host, port)| {
+            !host.is_empty() && port.parse::<u16>().is_ok_and(|p| p > 0)
+        });
+        if is_host_port {
+            Ok(())
+        } else {
+            Err(RestError::ValidationError(format!(
+                "instance '{}' needs a cluster_url (e.g. https://{}:9443) or a cluster given as host:port",
+                cluster, cluster
+            )))
+        }
+    }
+}
+
 /// CRDB handler for managing Active-Active databases
 pub struct CrdbHandler {
     client: RestClient,
@@ -145,6 +214,14 @@ impl CrdbHandler {
         self.client.post("/v1/crdbs", &request).await
     }
 
+    /// Create a new CRDB after [validating](CreateCrdbRequest::validate) the request
+    ///
+    /// An invalid request is rejected with a `ValidationError` without being sent.
+    pub async fn create_checked(&self, request: CreateCrdbRequest) -> Result<Crdb> {
+        request.validate()?;
+        self.create(request).await
+    }
+
     /// Update CRDB
     pub async fn update(&self, guid: &str, updates: Value) -> Result<Crdb> {
         self.client
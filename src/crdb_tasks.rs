@@ -6,9 +6,12 @@
 //! - Manage replication tasks
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
 use typed_builder::TypedBuilder;
 
 /// CRDB task information
@@ -36,6 +39,66 @@ pub struct CrdbTask {
     pub error: Option<String>,
 }
 
+impl CrdbTask {
+    /// The task status as a [`CrdbTaskStatus`]
+    pub fn task_status(&self) -> CrdbTaskStatus {
+        CrdbTaskStatus::from(self.status.as_str())
+    }
+}
+
+/// Status of a CRDB task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrdbTaskStatus {
+    /// Waiting to be picked up
+    Queued,
+    /// In progress
+    Running,
+    /// Finished successfully
+    Completed,
+    /// Finished with an error
+    Failed,
+    /// Cancelled before completion
+    Cancelled,
+    /// A status this client does not know about
+    Unknown(String),
+}
+
+impl CrdbTaskStatus {
+    /// Whether the task has stopped and will not change status again
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            CrdbTaskStatus::Completed | CrdbTaskStatus::Failed | CrdbTaskStatus::Cancelled
+        )
+    }
+}
+
+impl From<&str> for CrdbTaskStatus {
+    fn from(status: &str) -> Self {
+        match status.to_ascii_lowercase().as_str() {
+            "queued" | "pending" => CrdbTaskStatus::Queued,
+            "running" | "started" | "in-progress" => CrdbTaskStatus::Running,
+            "completed" | "finished" | "done" => CrdbTaskStatus::Completed,
+            "failed" | "error" => CrdbTaskStatus::Failed,
+            "cancelled" | "canceled" => CrdbTaskStatus::Cancelled,
+            _ => CrdbTaskStatus::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CrdbTaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrdbTaskStatus::Queued => f.write_str("queued"),
+            CrdbTaskStatus::Running => f.write_str("running"),
+            CrdbTaskStatus::Completed => f.write_str("completed"),
+            CrdbTaskStatus::Failed => f.write_str("failed"),
+            CrdbTaskStatus::Cancelled => f.write_str("cancelled"),
+            CrdbTaskStatus::Unknown(status) => f.write_str(status),
+        }
+    }
+}
+
 /// CRDB task creation request
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct CreateCrdbTaskRequest {
@@ -85,6 +148,30 @@ impl CrdbTasksHandler {
             .await
     }
 
+    /// Poll a task until it reaches a terminal status and return the final task
+    ///
+    /// Failed and cancelled tasks are returned as-is; check
+    /// [`CrdbTask::task_status`] and [`CrdbTask::error`] for the outcome.
+    pub async fn wait(
+        &self,
+        task_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<CrdbTask> {
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let task = self.get(task_id).await?;
+            if task.task_status().is_terminal() {
+                return Ok(task);
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Get tasks for a specific CRDB
     pub async fn list_by_crdb(&self, crdb_guid: &str) -> Result<Vec<CrdbTask>> {
         self.client
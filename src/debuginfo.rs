@@ -6,9 +6,14 @@
 //! - Monitor status and metrics
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::time::sleep;
 use typed_builder::TypedBuilder;
 
 /// Debug info collection request
@@ -49,6 +54,47 @@ pub struct TimeRange {
     pub end: String,
 }
 
+impl TimeRange {
+    /// Split the range into consecutive windows of at most `window` length
+    ///
+    /// Both bounds must be RFC 3339 timestamps, with `start` before `end`.
+    pub fn split(&self, window: Duration) -> Result<Vec<TimeRange>> {
+        let parse = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| {
+                    RestError::ValidationError(format!("Invalid timestamp '{}': {}", value, e))
+                })
+        };
+        let start = parse(&self.start)?;
+        let end = parse(&self.end)?;
+        if start >= end {
+            return Err(RestError::ValidationError(format!(
+                "Time range start {} is not before end {}",
+                self.start, self.end
+            )));
+        }
+        let step = chrono::Duration::from_std(window)
+            .ok()
+            .filter(|step| *step > chrono::Duration::zero())
+            .ok_or_else(|| {
+                RestError::ValidationError(format!("Invalid window length {:?}", window))
+            })?;
+
+        let mut windows = Vec::new();
+        let mut window_start = start;
+        while window_start < end {
+            let window_end = (window_start + step).min(end);
+            windows.push(TimeRange {
+                start: window_start.to_rfc3339(),
+                end: window_end.to_rfc3339(),
+            });
+            window_start = window_end;
+        }
+        Ok(windows)
+    }
+}
+
 /// Debug info status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugInfoStatus {
@@ -67,6 +113,20 @@ pub struct DebugInfoStatus {
     pub error: Option<String>,
 }
 
+/// File name for the package of one collection window
+fn window_file_name(range: &TimeRange) -> String {
+    let stamp = |value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|t| t.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string())
+            .unwrap_or_else(|_| value.replace(':', ""))
+    };
+    format!(
+        "debuginfo-{}-{}.tar.gz",
+        stamp(&range.start),
+        stamp(&range.end)
+    )
+}
+
 /// Debug info handler
 pub struct DebugInfoHandler {
     client: RestClient,
@@ -99,6 +159,101 @@ impl DebugInfoHandler {
             .await
     }
 
+    /// Stream a debug info package to a file, returning the number of bytes written
+    pub async fn download_to_file(&self, task_id: &str, dest: &Path) -> Result<u64> {
+        self.client
+            .get_binary_to_file(&format!("/v1/debuginfo/{}/download", task_id), dest)
+            .await
+    }
+
+    /// Collect debug info in time windows, one package per window
+    ///
+    /// Splits `full_range` into windows of `window` length, then for each one
+    /// starts a collection task, polls it every `poll_interval` until it
+    /// completes and streams the package into `out_dir`. Returns the paths of the written packages in
+    /// chronological order.
+    ///
+    /// Each package is streamed to a `.partial` file that is renamed once the
+    /// download completes. Windows whose package already exists in `out_dir`
+    /// are skipped, so an interrupted collection can be resumed by calling
+    /// this again.
+    ///
+    /// Fails with [`RestError::Timeout`] if a task does not complete within
+    /// `timeout`, with [`RestError::ServerError`] if it fails, is cancelled, or
+    /// reports a status other than queued or running, and with
+    /// [`RestError::IoError`] if `out_dir` cannot be written.
+    pub async fn collect_windowed(
+        &self,
+        full_range: &TimeRange,
+        window: Duration,
+        out_dir: impl AsRef<Path>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<PathBuf>> {
+        let out_dir = out_dir.as_ref();
+        let windows = full_range.split(window)?;
+        tokio::fs::create_dir_all(out_dir).await.map_err(|e| {
+            RestError::IoError(format!("Failed to create {}: {}", out_dir.display(), e))
+        })?;
+
+        let mut paths = Vec::with_capacity(windows.len());
+        for range in windows {
+            let dest = out_dir.join(window_file_name(&range));
+            if tokio::fs::try_exists(&dest).await.unwrap_or(false) {
+                paths.push(dest);
+                continue;
+            }
+
+            let request = DebugInfoRequest::builder().time_range(range).build();
+            let mut status = self.create(request).await?;
+            let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+            loop {
+                match status.status.as_str() {
+                    "completed" => break,
+                    "queued" | "running" => match delays.next() {
+                        Some(delay) => {
+                            sleep(delay).await;
+                            status = self.status(&status.task_id).await?;
+                        }
+                        None => return Err(RestError::Timeout),
+                    },
+                    "failed" => {
+                        return Err(RestError::ServerError {
+                            message: format!(
+                                "Debug info task {} failed: {}",
+                                status.task_id,
+                                status
+                                    .error
+                                    .as_deref()
+                                    .unwrap_or("no error detail reported")
+                            ),
+                            request_id: None,
+                        });
+                    }
+                    other => {
+                        return Err(RestError::ServerError {
+                            message: format!(
+                                "Debug info task {} ended with status '{}'",
+                                status.task_id, other
+                            ),
+                            request_id: None,
+                        });
+                    }
+                }
+            }
+
+            let mut partial = dest.clone().into_os_string();
+            partial.push(".partial");
+            let partial = PathBuf::from(partial);
+            self.download_to_file(&status.task_id, &partial).await?;
+            tokio::fs::rename(&partial, &dest).await.map_err(|e| {
+                RestError::IoError(format!("Failed to rename {}: {}", partial.display(), e))
+            })?;
+            paths.push(dest);
+        }
+        Ok(paths)
+    }
+
     /// Cancel debug info collection
     pub async fn cancel(&self, task_id: &str) -> Result<()> {
         self.client
@@ -14,8 +14,12 @@ pub enum RestError {
     #[error("Authentication failed")]
     AuthenticationFailed,
 
-    #[error("API error: {message} (code: {code})")]
-    ApiError { code: u16, message: String },
+    #[error("API error: {message} (code: {code}){}", request_id_suffix(.request_id))]
+    ApiError {
+        code: u16,
+        message: String,
+        request_id: Option<String>,
+    },
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
@@ -23,9 +27,27 @@ pub enum RestError {
     #[error("Parse error: {0}")]
     ParseError(String),
 
+    /// A response body whose value didn't match the expected type
+    ///
+    /// Displays like the equivalent [`ParseError`](Self::ParseError), but keeps the
+    /// failing field path and an excerpt of the body around the error.
+    #[error("Parse error: Failed to deserialize field '{path}': {message}")]
+    ParseErrorAt {
+        /// Field path as reported by `serde_path_to_error`, e.g. `shards[0].status`
+        path: String,
+        /// The underlying serde error
+        message: String,
+        /// The raw body around the failing position, truncated
+        snippet: String,
+    },
+
     #[error("Connection error: {0}")]
     ConnectionError(String),
 
+    /// A local file or directory could not be read or written
+    #[error("I/O error: {0}")]
+    IoError(String),
+
     #[error("TLS certificate error: {0}")]
     TlsError(String),
 
@@ -35,29 +57,53 @@ pub enum RestError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
-    #[error("Resource not found")]
-    NotFound,
+    #[error("Resource not found{}", request_id_suffix(.request_id))]
+    NotFound { request_id: Option<String> },
+
+    #[error("Unauthorized{}", request_id_suffix(.request_id))]
+    Unauthorized { request_id: Option<String> },
 
-    #[error("Unauthorized")]
-    Unauthorized,
+    /// Authenticated, but the user's role does not permit the operation (HTTP 403)
+    #[error("Forbidden: {message}{}", request_id_suffix(.request_id))]
+    Forbidden {
+        message: String,
+        request_id: Option<String>,
+    },
 
-    #[error("Server error: {0}")]
-    ServerError(String),
+    #[error("Server error: {message}{}", request_id_suffix(.request_id))]
+    ServerError {
+        message: String,
+        request_id: Option<String>,
+    },
 
     #[error("Request timed out")]
     Timeout,
 
-    #[error("Rate limited{}", .retry_after.map(|d| format!(" (retry after {:?})", d)).unwrap_or_default())]
-    RateLimited { retry_after: Option<Duration> },
+    #[error("Rate limited{}{}", .retry_after.map(|d| format!(" (retry after {:?})", d)).unwrap_or_default(), request_id_suffix(.request_id))]
+    RateLimited {
+        retry_after: Option<Duration>,
+        request_id: Option<String>,
+    },
 
     #[error("Resource already exists")]
     AlreadyExists,
 
-    #[error("Conflict: {0}")]
-    Conflict(String),
+    #[error("Conflict: {message}{}", request_id_suffix(.request_id))]
+    Conflict {
+        message: String,
+        request_id: Option<String>,
+    },
+
+    #[error("Cluster is busy or unavailable{}", request_id_suffix(.request_id))]
+    ClusterBusy { request_id: Option<String> },
+}
 
-    #[error("Cluster is busy or unavailable")]
-    ClusterBusy,
+/// Display suffix naming the `X-Request-Id` of a failed request, if any
+fn request_id_suffix(request_id: &Option<String>) -> String {
+    request_id
+        .as_deref()
+        .map(|id| format!(" (request id: {})", id))
+        .unwrap_or_default()
 }
 
 impl From<reqwest::Error> for RestError {
@@ -73,22 +119,69 @@ impl From<serde_json::Error> for RestError {
 }
 
 impl RestError {
+    /// The `X-Request-Id` of the failed request, when request IDs are enabled
+    ///
+    /// Only errors built from an HTTP response carry one.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            RestError::ApiError { request_id, .. }
+            | RestError::NotFound { request_id }
+            | RestError::Unauthorized { request_id }
+            | RestError::Forbidden { request_id, .. }
+            | RestError::ServerError { request_id, .. }
+            | RestError::RateLimited { request_id, .. }
+            | RestError::Conflict { request_id, .. }
+            | RestError::ClusterBusy { request_id } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The field path of a response that failed to deserialize
+    pub fn parse_path(&self) -> Option<&str> {
+        match self {
+            RestError::ParseErrorAt { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The excerpt of a response body that failed to deserialize
+    pub fn body_snippet(&self) -> Option<&str> {
+        match self {
+            RestError::ParseErrorAt { snippet, .. } => Some(snippet),
+            _ => None,
+        }
+    }
+
+    /// The delay requested by the server for a rate limited request
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            RestError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Check if this is a not found error
     pub fn is_not_found(&self) -> bool {
-        matches!(self, RestError::NotFound)
+        matches!(self, RestError::NotFound { .. })
             || matches!(self, RestError::ApiError { code, .. } if *code == 404)
     }
 
     /// Check if this is an authentication error
     pub fn is_unauthorized(&self) -> bool {
-        matches!(self, RestError::Unauthorized)
+        matches!(self, RestError::Unauthorized { .. })
             || matches!(self, RestError::AuthenticationFailed)
             || matches!(self, RestError::ApiError { code, .. } if *code == 401)
     }
 
+    /// Check if this is a permission error: valid credentials, insufficient role
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, RestError::Forbidden { .. })
+            || matches!(self, RestError::ApiError { code, .. } if *code == 403)
+    }
+
     /// Check if this is a server error
     pub fn is_server_error(&self) -> bool {
-        matches!(self, RestError::ServerError(_))
+        matches!(self, RestError::ServerError { .. })
             || matches!(self, RestError::ApiError { code, .. } if *code >= 500)
     }
 
@@ -106,13 +199,13 @@ impl RestError {
     /// Check if this is a conflict/already exists error
     pub fn is_conflict(&self) -> bool {
         matches!(self, RestError::AlreadyExists)
-            || matches!(self, RestError::Conflict(_))
-            || matches!(self, RestError::ApiError { code, .. } if *code == 409)
+            || matches!(self, RestError::Conflict { .. })
+            || matches!(self, RestError::ApiError { code, .. } if *code == 409 || *code == 412)
     }
 
     /// Check if this is a cluster busy error
     pub fn is_cluster_busy(&self) -> bool {
-        matches!(self, RestError::ClusterBusy)
+        matches!(self, RestError::ClusterBusy { .. })
             || matches!(self, RestError::ApiError { code, .. } if *code == 503)
     }
 
@@ -124,6 +217,14 @@ impl RestError {
             || self.is_server_error()
     }
 
+    /// Check if this is a response parse error, with or without a field path
+    pub fn is_parse_error(&self) -> bool {
+        matches!(
+            self,
+            RestError::ParseError(_) | RestError::ParseErrorAt { .. }
+        )
+    }
+
     /// Check if this is a bad request / validation error
     pub fn is_bad_request(&self) -> bool {
         matches!(self, RestError::ValidationError(_))
@@ -5,7 +5,8 @@
 //! - Query job history
 //! - Manage job execution
 
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use typed_builder::TypedBuilder;
@@ -35,6 +36,32 @@ pub struct ScheduledJob {
     pub params: Option<Value>,
 }
 
+impl ScheduledJob {
+    /// Compute the next `count` times the job's `schedule` fires after `from`
+    ///
+    /// The schedule is evaluated in UTC. Returns an empty list if the schedule
+    /// is not a valid cron expression (see [`validate_cron`]), and fewer than
+    /// `count` times if the schedule never fires again within the following
+    /// five years (e.g. `0 0 30 2 *`).
+    pub fn upcoming_runs(&self, count: usize, from: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let Ok(schedule) = CronSchedule::parse(&self.schedule) else {
+            return Vec::new();
+        };
+        let mut runs = Vec::with_capacity(count);
+        let mut after = from;
+        while runs.len() < count {
+            match schedule.next_after(after) {
+                Some(next) => {
+                    runs.push(next);
+                    after = next;
+                }
+                None => break,
+            }
+        }
+        runs
+    }
+}
+
 /// Create scheduled job request
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct CreateScheduledJobRequest {
@@ -55,6 +82,12 @@ pub struct CreateScheduledJobRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub params: Option<Value>,
+    /// Check `schedule` with [`validate_cron`] before sending the request
+    ///
+    /// Client-side only; never sent to the server.
+    #[serde(skip)]
+    #[builder(default)]
+    pub validate: bool,
 }
 
 /// Job execution history
@@ -76,6 +109,223 @@ pub struct JobExecution {
     pub error: Option<String>,
 }
 
+/// Check that `expr` is a well-formed cron expression
+///
+/// Accepts the five standard fields (minute, hour, day of month, month, day
+/// of week) with `*`, lists, ranges, steps and month/day names, plus the
+/// `@hourly`, `@daily`, `@weekly`, `@monthly` and `@yearly` shorthands.
+/// Errors are [`RestError::ValidationError`]s naming the 0-based character
+/// position of the offending token.
+pub fn validate_cron(expr: &str) -> Result<()> {
+    CronSchedule::parse(expr).map(|_| ())
+}
+
+const MONTH_NAMES: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A parsed five-field cron expression, with each field as a bitset of allowed values
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Whether the day-of-month field is restricted (not `*`)
+    dom_restricted: bool,
+    /// Whether the day-of-week field is restricted (not `*`)
+    dow_restricted: bool,
+}
+
+impl CronSchedule {
+    /// The first time strictly after `after` that the schedule fires
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let has = |bits: u64, value: u32| bits & (1 << value) != 0;
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let give_up = after.year() + 5;
+        let mut t = start;
+        while t.year() <= give_up {
+            if !has(self.months, t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc();
+                continue;
+            }
+            if !self.matches_day(t) {
+                t = (t.date_naive() + Duration::days(1))
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc();
+                continue;
+            }
+            if !has(self.hours, t.hour()) {
+                t = t.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if !has(self.minutes, t.minute()) {
+                t += Duration::minutes(1);
+                continue;
+            }
+            return Some(t);
+        }
+        None
+    }
+
+    /// Whether `t` falls on a scheduled day
+    ///
+    /// As in standard cron, when both day fields are restricted a day matching
+    /// either one fires.
+    fn matches_day(&self, t: DateTime<Utc>) -> bool {
+        let dom = self.days_of_month & (1 << t.day()) != 0;
+        let dow = self.days_of_week & (1 << t.weekday().num_days_from_sunday()) != 0;
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            _ => dom && dow,
+        }
+    }
+
+    /// Parse a cron expression; see [`validate_cron`] for the accepted syntax
+    fn parse(expr: &str) -> Result<Self> {
+        let invalid = |position: usize, reason: String| {
+            RestError::ValidationError(format!(
+                "Invalid cron expression '{}' at position {}: {}",
+                expr, position, reason
+            ))
+        };
+
+        let trimmed = expr.trim_start();
+        let lead = expr.len() - trimmed.len();
+        if let Some(name) = trimmed.strip_prefix('@') {
+            let name = name.trim_end();
+            let expanded = match name.to_ascii_lowercase().as_str() {
+                "yearly" | "annually" => "0 0 1 1 *",
+                "monthly" => "0 0 1 * *",
+                "weekly" => "0 0 * * 0",
+                "daily" | "midnight" => "0 0 * * *",
+                "hourly" => "0 * * * *",
+                _ => return Err(invalid(lead, format!("unsupported shorthand '@{}'", name))),
+            };
+            return Self::parse(expanded);
+        }
+
+        // Split into fields, remembering where each one starts
+        let mut fields = Vec::new();
+        let mut start = None;
+        for (i, c) in expr.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    fields.push((s, &expr[s..i]));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            fields.push((s, &expr[s..]));
+        }
+        if fields.len() != 5 {
+            let position = fields.get(5).map_or(expr.len(), |(s, _)| *s);
+            return Err(invalid(
+                position,
+                format!("expected 5 fields, found {}", fields.len()),
+            ));
+        }
+
+        let field = |index: usize, min: u32, max: u32, names: Option<(&[&str], u32)>| {
+            let (offset, text) = fields[index];
+            parse_cron_field(text, min, max, names)
+                .map_err(|(position, reason)| invalid(offset + position, reason))
+        };
+        let minutes = field(0, 0, 59, None)?;
+        let hours = field(1, 0, 23, None)?;
+        let days_of_month = field(2, 1, 31, None)?;
+        let months = field(3, 1, 12, Some((MONTH_NAMES, 1)))?;
+        let mut days_of_week = field(4, 0, 7, Some((WEEKDAY_NAMES, 0)))?;
+        // Both 0 and 7 mean Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(CronSchedule {
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            dom_restricted: !fields[2].1.starts_with('*'),
+            dow_restricted: !fields[4].1.starts_with('*'),
+        })
+    }
+}
+
+/// Parse one cron field into a bitset, reporting errors as (offset, reason)
+fn parse_cron_field(
+    text: &str,
+    min: u32,
+    max: u32,
+    names: Option<(&[&str], u32)>,
+) -> std::result::Result<u64, (usize, String)> {
+    let value = |token: &str, offset: usize| {
+        let parsed = token.parse::<u32>().ok().or_else(|| {
+            names.and_then(|(names, base)| {
+                names
+                    .iter()
+                    .position(|n| n.eq_ignore_ascii_case(token))
+                    .map(|i| i as u32 + base)
+            })
+        });
+        match parsed {
+            Some(v) if (min..=max).contains(&v) => Ok(v),
+            Some(v) => Err((offset, format!("{} is outside {}-{}", v, min, max))),
+            None => Err((offset, format!("invalid value '{}'", token))),
+        }
+    };
+
+    let mut bits = 0u64;
+    let mut offset = 0;
+    for item in text.split(',') {
+        if item.is_empty() {
+            return Err((offset, "empty list item".to_string()));
+        }
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step_offset = offset + range.len() + 1;
+                match step.parse::<u32>() {
+                    Ok(step) if step > 0 => (range, Some(step)),
+                    _ => return Err((step_offset, format!("invalid step '{}'", step))),
+                }
+            }
+            None => (item, None),
+        };
+        let (low, high) = if range == "*" {
+            (min, max)
+        } else if let Some((low, high)) = range.split_once('-') {
+            let low_value = value(low, offset)?;
+            let high_value = value(high, offset + low.len() + 1)?;
+            if low_value > high_value {
+                return Err((offset, format!("range {} is reversed", range)));
+            }
+            (low_value, high_value)
+        } else {
+            let v = value(range, offset)?;
+            // `a/n` means every n-th value starting at a
+            (v, if step.is_some() { max } else { v })
+        };
+        for v in (low..=high).step_by(step.unwrap_or(1) as usize) {
+            bits |= 1 << v;
+        }
+        offset += item.len() + 1;
+    }
+    Ok(bits)
+}
+
 define_handler!(
     /// Job scheduler handler
     pub struct JobSchedulerHandler;
@@ -85,12 +335,35 @@ impl_crud!(JobSchedulerHandler {
     list => ScheduledJob, "/v1/job_scheduler";
     get(&str) => ScheduledJob, "/v1/job_scheduler/{}";
     delete(&str), "/v1/job_scheduler/{}";
-    create(CreateScheduledJobRequest) => ScheduledJob, "/v1/job_scheduler";
-    update(&str, CreateScheduledJobRequest) => ScheduledJob, "/v1/job_scheduler/{}";
 });
 
 // Custom methods
 impl JobSchedulerHandler {
+    /// Create a scheduled job
+    ///
+    /// When `request.validate` is set, the schedule is checked with
+    /// [`validate_cron`] first, so malformed expressions fail without a round trip.
+    pub async fn create(&self, request: CreateScheduledJobRequest) -> Result<ScheduledJob> {
+        if request.validate {
+            validate_cron(&request.schedule)?;
+        }
+        self.client.post("/v1/job_scheduler", &request).await
+    }
+
+    /// Update a scheduled job, validating the schedule first if `request.validate` is set
+    pub async fn update(
+        &self,
+        job_id: &str,
+        request: CreateScheduledJobRequest,
+    ) -> Result<ScheduledJob> {
+        if request.validate {
+            validate_cron(&request.schedule)?;
+        }
+        self.client
+            .put(&format!("/v1/job_scheduler/{}", job_id), &request)
+            .await
+    }
+
     /// Trigger job execution
     pub async fn trigger(&self, job_id: &str) -> Result<JobExecution> {
         self.client
@@ -2,12 +2,116 @@
 //!
 //! ## Overview
 //! - Get schema for API objects
-//! - Validate request/response formats
+//! - Validate request/response formats, on the cluster or locally (with the
+//!   `schema-validation` feature)
 //! - Query available schemas
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use redis_enterprise::{EnterpriseClient, SchemaResource};
+//!
+//! # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
+//! // Fetched once, then served from the client's cache
+//! let schema = client.json_schema().schema(SchemaResource::Bdb).await?;
+//! println!("{}", schema["properties"]["eviction_policy"]);
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::client::RestClient;
+#[cfg(feature = "schema-validation")]
+use crate::error::RestError;
 use crate::error::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::Arc;
+
+/// An API object with a JSON Schema at `/v1/jsonschema/{name}`
+///
+/// Strings convert to the matching variant, or to `Other` for names not listed
+/// here, so `get("bdb")` and `get(SchemaResource::Bdb)` are equivalent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaResource {
+    /// Database (`bdb`)
+    Bdb,
+    /// Cluster (`cluster`)
+    Cluster,
+    /// Node (`node`)
+    Node,
+    /// User (`user`)
+    User,
+    /// Role (`role`)
+    Role,
+    /// Redis ACL (`redis_acl`)
+    RedisAcl,
+    /// Active-Active database (`crdb`)
+    Crdb,
+    /// Any other schema, by name
+    Other(String),
+}
+
+impl SchemaResource {
+    /// Schema name used in the API path
+    pub fn as_str(&self) -> &str {
+        match self {
+            SchemaResource::Bdb => "bdb",
+            SchemaResource::Cluster => "cluster",
+            SchemaResource::Node => "node",
+            SchemaResource::User => "user",
+            SchemaResource::Role => "role",
+            SchemaResource::RedisAcl => "redis_acl",
+            SchemaResource::Crdb => "crdb",
+            SchemaResource::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for SchemaResource {
+    fn from(name: &str) -> Self {
+        match name {
+            "bdb" => SchemaResource::Bdb,
+            "cluster" => SchemaResource::Cluster,
+            "node" => SchemaResource::Node,
+            "user" => SchemaResource::User,
+            "role" => SchemaResource::Role,
+            "redis_acl" => SchemaResource::RedisAcl,
+            "crdb" => SchemaResource::Crdb,
+            other => SchemaResource::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for SchemaResource {
+    fn from(name: String) -> Self {
+        SchemaResource::from(name.as_str())
+    }
+}
+
+impl std::fmt::Display for SchemaResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A place where an instance breaks its schema, from `JsonSchemaHandler::validate_local`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaError {
+    /// JSON pointer to the offending value, e.g. `/port`; empty for the root
+    pub path: String,
+    /// What is wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
 
 /// JSON Schema handler for API schema definitions
 pub struct JsonSchemaHandler {
@@ -31,6 +135,72 @@ impl JsonSchemaHandler {
             .await
     }
 
+    /// Get the schema for a known resource, fetching it only once per client
+    ///
+    /// Schemas are cached on the [`EnterpriseClient`](crate::EnterpriseClient)
+    /// and shared by its clones; a failed fetch is not cached.
+    pub async fn schema(&self, resource: impl Into<SchemaResource>) -> Result<Arc<Value>> {
+        let resource = resource.into();
+        if let Some(schema) = self.client.cached_schema(&resource) {
+            return Ok(schema);
+        }
+        let schema = Arc::new(self.get(resource.as_str()).await?);
+        self.client.cache_schema(resource, schema.clone());
+        Ok(schema)
+    }
+
+    /// Check `instance` against the cluster's schema for `resource` without sending it
+    ///
+    /// Uses the cached schema (see [`schema`](Self::schema)). The outer
+    /// `Result` fails if the schema can't be fetched or compiled; the inner one
+    /// lists every violation found.
+    ///
+    /// ```no_run
+    /// use redis_enterprise::{CreateDatabaseRequest, EnterpriseClient, SchemaResource};
+    ///
+    /// # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = CreateDatabaseRequest::builder()
+    ///     .name("cache")
+    ///     .memory_size(1024 * 1024 * 1024)
+    ///     .build();
+    /// if let Err(errors) = client
+    ///     .json_schema()
+    ///     .validate_local(SchemaResource::Bdb, &request)
+    ///     .await?
+    /// {
+    ///     for error in errors {
+    ///         eprintln!("{error}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "schema-validation")]
+    pub async fn validate_local<T: Serialize>(
+        &self,
+        resource: impl Into<SchemaResource>,
+        instance: &T,
+    ) -> Result<std::result::Result<(), Vec<SchemaError>>> {
+        let resource = resource.into();
+        let schema = self.schema(resource.clone()).await?;
+        let instance = serde_json::to_value(instance)?;
+        let validator = ::jsonschema::validator_for(&schema).map_err(|e| {
+            RestError::ValidationError(format!("Invalid JSON Schema for {}: {}", resource, e))
+        })?;
+        let errors: Vec<SchemaError> = validator
+            .iter_errors(&instance)
+            .map(|error| SchemaError {
+                path: error.instance_path().to_string(),
+                message: error.to_string(),
+            })
+            .collect();
+        Ok(if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        })
+    }
+
     /// Get schema for database object
     pub async fn database_schema(&self) -> Result<Value> {
         self.client.get("/v1/jsonschema/bdb").await
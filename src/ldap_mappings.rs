@@ -90,6 +90,28 @@ pub struct LdapServer {
     pub starttls: Option<bool>,
 }
 
+/// Outcome of testing LDAP authentication and role mapping for a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LdapTestResult {
+    /// True if the user authenticated against the LDAP server
+    pub success: bool,
+    /// Distinguished name the username resolved to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dn: Option<String>,
+    /// LDAP groups the user belongs to
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// Names of the roles the user's groups map to
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// UIDs of the roles the user's groups map to
+    #[serde(default)]
+    pub role_uids: Vec<u32>,
+    /// Error reported when authentication or lookup failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 define_handler!(
     /// LDAP mapping handler
     pub struct LdapMappingHandler;
@@ -114,4 +136,17 @@ impl LdapMappingHandler {
     pub async fn update_config(&self, config: LdapConfig) -> Result<LdapConfig> {
         self.client.put("/v1/cluster/ldap", &config).await
     }
+
+    /// Test LDAP authentication and role mapping for a user
+    ///
+    /// Resolves the user's DN and groups against the current LDAP configuration
+    /// and reports which roles they map to, without logging the user in. Use it
+    /// to verify an `LdapConfig` before relying on it for admin access.
+    pub async fn test(&self, username: &str, password: &str) -> Result<LdapTestResult> {
+        let body = serde_json::json!({
+            "username": username,
+            "password": password
+        });
+        self.client.post("/v1/cluster/ldap/test", &body).await
+    }
 }
@@ -11,6 +11,10 @@
 //!   endpoints where they exist (e.g., actions, modules)
 //! - **Type-Safe Operations**: Strongly typed request/response models
 //! - **Flexible Authentication**: Basic auth with optional SSL verification
+//! - **Pure-Rust TLS**: HTTPS via rustls, so no OpenSSL is needed at build or run
+//!   time; server certificates are verified against the OS trust store (container
+//!   images still need CA certificates installed), or a custom CA via
+//!   `ca_cert`/`ca_cert_pem`, or skipped with `insecure`
 //! - **Async/Await Support**: Built on Tokio for high-performance async operations
 //! - **Error Handling**: Comprehensive error types with context
 //! - **Builder Patterns**: Ergonomic API for complex request construction
@@ -61,6 +65,9 @@
 //! // From environment variables
 //! let client = EnterpriseClient::from_env()?;
 //!
+//! // From environment variables, failing early on a bad URL, credentials or TLS setup
+//! let client = EnterpriseClient::from_env_checked().await?;
+//!
 //! // Or using the builder
 //! let client = EnterpriseClient::builder()
 //!     .base_url("https://cluster.example.com:9443")
@@ -263,10 +270,10 @@
 //! # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
 //! match client.databases().get(999).await {
 //!     Ok(db) => println!("Found database: {}", db.name),
-//!     Err(RestError::NotFound) => println!("Database not found"),
-//!     Err(RestError::Unauthorized) => println!("Invalid credentials"),
-//!     Err(RestError::ServerError(msg)) => println!("Server error: {}", msg),
-//!     Err(e) if e.is_not_found() => println!("Not found: {}", e),
+//!     Err(e) if e.is_not_found() => println!("Database not found"),
+//!     Err(RestError::Unauthorized { .. }) => println!("Invalid credentials"),
+//!     Err(RestError::Forbidden { .. }) => println!("Role does not permit this operation"),
+//!     Err(RestError::ServerError { message, .. }) => println!("Server error: {}", message),
 //!     Err(e) => println!("Unexpected error: {}", e),
 //! }
 //! # Ok(())
@@ -303,8 +310,10 @@
 //! - **Connection Pooling**: The client reuses HTTP connections automatically
 //! - **Timeout Configuration**: Set appropriate timeouts for your environment
 //! - **SSL Verification**: Always enable in production (disable only for development)
-//! - **Error Handling**: Implement retry logic for transient failures
+//! - **Error Handling**: Retry transient failures, e.g. with [`retry::retry_async_when`]
+//!   and [`RestError::is_retryable`]
 //! - **Monitoring**: Log all API operations and track response times
+//! - **Fleets**: Run the same query across many clusters with [`MultiClusterClient`]
 //!
 //! # API Coverage
 //!
@@ -343,11 +352,14 @@ pub mod local;
 pub mod logs;
 pub mod migrations;
 pub mod modules;
+pub mod multi_cluster;
 pub mod nodes;
 pub mod ocsp;
 pub mod proxies;
 pub mod redis_acls;
+pub mod retry;
 pub mod roles;
+mod serde_helpers;
 pub mod services;
 pub mod shards;
 pub mod stats;
@@ -360,8 +372,9 @@ pub mod users;
 mod lib_tests;
 
 // Core client and error types
-pub use client::{EnterpriseClient, EnterpriseClientBuilder};
+pub use client::{EnterpriseClient, EnterpriseClientBuilder, RequestOptions, TlsVersion};
 pub use error::{RestError, Result};
+pub use multi_cluster::MultiClusterClient;
 
 // Re-export Tower integration when feature is enabled
 #[cfg(feature = "tower-integration")]
@@ -373,8 +386,13 @@ pub mod testing;
 
 // Database management
 pub use bdb::{
-    BdbHandler, CreateDatabaseRequest, CreateDatabaseRequestBuilder, Database,
-    DatabaseUpgradeRequest, ModuleConfig,
+    AddrType, AuthorizedSubject, AvailabilityStatus, BackupRecord, BdbHandler,
+    ClientCertSubjectValidation, ClientConnection, ClientListQuery, CommandResult,
+    CreateDatabaseRequest, CreateDatabaseRequestBuilder, CreateDatabaseV2Request, Database,
+    DatabaseUpgradeRequest, DatasetImportSource, EndpointAvailability, ExportDestination,
+    ExportRequest, ImportRequest, ModuleConfig, ModuleFeature, MtlsConfig, RecoveryDataFile,
+    RecoveryPlan, RedisValue, ReplicaSource, SearchConfig, SlowlogEntry, UpdateDatabaseRequest,
+    ValidationIssue,
 };
 
 // Database groups
@@ -382,14 +400,22 @@ pub use bdb_groups::{BdbGroup, BdbGroupsHandler};
 
 // Cluster management
 pub use cluster::{
-    BootstrapRequest, ClusterHandler, ClusterInfo, ClusterNode, LicenseInfo, NodeInfo,
+    AuditProtocol, AuditingDbConnsConfig, BootstrapRequest, CapabilityDescription,
+    CertRotationFailure, CertRotationFailureReason, CertRotationResult, CertificateInfo,
+    ClusterAction, ClusterAlertSettings, ClusterAlertThreshold, ClusterCapabilities,
+    ClusterHandler, ClusterHealth, ClusterIdentity, ClusterInfo, ClusterNode, ClusterPolicy,
+    ClusterServiceConfig, ClusterTopology, ClusterVersion, LicenseInfo, MetricsSystem,
+    ModuleCapabilities, ModuleCapability, NodeInfo, RotatedCertificate, ServicesConfiguration,
+    TopologyNode, TopologyShard, WitnessDiskNode, WitnessDiskStatus,
 };
 
 // Node management
-pub use nodes::{Node, NodeActionRequest, NodeHandler, NodeStats};
+pub use nodes::{MaintenanceStatus, Node, NodeActionRequest, NodeHandler, NodeSnapshot, NodeStats};
 
 // User management
-pub use users::{CreateUserRequest, Role, RoleHandler, UpdateUserRequest, User, UserHandler};
+pub use users::{
+    CreateUserRequest, PasswordPolicy, Role, RoleHandler, UpdateUserRequest, User, UserHandler,
+};
 
 // Module management
 pub use modules::{Module, ModuleHandler};
@@ -398,16 +424,21 @@ pub use modules::{Module, ModuleHandler};
 pub use actions::{Action, ActionHandler};
 
 // Logs
-pub use logs::{LogEntry, LogsHandler, LogsQuery};
+pub use logs::{LogEntry, LogsHandler, LogsPage, LogsQuery};
 
 // Active-Active databases
 pub use crdb::{Crdb, CrdbHandler, CrdbInstance, CreateCrdbInstance, CreateCrdbRequest};
 
 // Statistics
-pub use stats::{StatsHandler, StatsInterval, StatsQuery, StatsResponse};
+pub use stats::{
+    ClusterMetricsSnapshot, DatabaseShardStats, ShardStatsEntry, StatsHandler, StatsInterval,
+    StatsQuery, StatsResponse,
+};
 
 // Alerts
-pub use alerts::{Alert, AlertHandler, AlertSettings};
+pub use alerts::{
+    Alert, AlertHandler, AlertId, AlertSettings, AlertSource, AlertStream, AlertThreshold,
+};
 
 // Redis ACLs
 pub use redis_acls::{CreateRedisAclRequest, RedisAcl, RedisAclHandler};
@@ -416,11 +447,12 @@ pub use redis_acls::{CreateRedisAclRequest, RedisAcl, RedisAclHandler};
 pub use shards::{Shard, ShardHandler, ShardStats};
 
 // Proxies
-pub use proxies::{Proxy, ProxyHandler, ProxyStats};
+pub use proxies::{Proxy, ProxyConfigRequest, ProxyHandler, ProxyStats};
 
 // LDAP mappings
 pub use ldap_mappings::{
     CreateLdapMappingRequest, LdapConfig, LdapMapping, LdapMappingHandler, LdapServer,
+    LdapTestResult,
 };
 
 // OCSP
@@ -436,10 +468,10 @@ pub use bootstrap::{
 };
 
 // Cluster Manager settings
-pub use cm_settings::{CmSettings, CmSettingsHandler};
+pub use cm_settings::{CmSettings, CmSettingsHandler, CmSettingsInfo, CmSettingsUpdate};
 
 // CRDB tasks
-pub use crdb_tasks::{CrdbTask, CrdbTasksHandler, CreateCrdbTaskRequest};
+pub use crdb_tasks::{CrdbTask, CrdbTaskStatus, CrdbTasksHandler, CreateCrdbTaskRequest};
 
 // Debug info
 pub use debuginfo::{DebugInfoHandler, DebugInfoRequest, DebugInfoStatus, TimeRange};
@@ -458,16 +490,18 @@ pub use job_scheduler::{
 };
 
 // JSON Schema
-pub use jsonschema::JsonSchemaHandler;
+pub use jsonschema::{JsonSchemaHandler, SchemaError, SchemaResource};
 
 // License
 pub use license::{License, LicenseHandler, LicenseUpdateRequest, LicenseUsage};
 
 // Migrations
-pub use migrations::{CreateMigrationRequest, Migration, MigrationEndpoint, MigrationsHandler};
+pub use migrations::{
+    CreateMigrationRequest, EndpointReachability, Migration, MigrationEndpoint, MigrationsHandler,
+};
 
 // Roles
-pub use roles::{BdbRole, CreateRoleRequest, RoleInfo, RolesHandler};
+pub use roles::{BdbRole, CreateRoleRequest, Permission, RoleInfo, RolesHandler};
 
 // Services
 pub use services::{
@@ -475,9 +509,12 @@ pub use services::{
 };
 
 // Suffixes
-pub use suffixes::{CreateSuffixRequest, Suffix, SuffixesHandler};
+pub use suffixes::{
+    CreateSuffixRequest, NodeSuffixReadiness, Suffix, SuffixStatus, SuffixesHandler,
+};
 
 // Usage report
 pub use usage_report::{
-    DatabaseUsage, NodeUsage, UsageReport, UsageReportConfig, UsageReportHandler, UsageSummary,
+    DatabaseUsage, NodeUsage, UsageCsvRow, UsageReport, UsageReportConfig, UsageReportHandler,
+    UsageSummary,
 };
@@ -2,6 +2,7 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::client::quote_command_arg;
     use crate::{EnterpriseClient, RestError, Result};
     use wiremock::matchers::{basic_auth, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -151,6 +152,37 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_enterprise_client_forbidden_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_json(serde_json::json!({"error": "Insufficient permissions"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("viewer")
+            .password("viewer_pass")
+            .build()
+            .unwrap();
+        let err = client
+            .get::<serde_json::Value>("/v1/cluster")
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(err, RestError::Forbidden { message: ref msg, .. } if msg.contains("Insufficient"))
+        );
+        assert!(err.is_forbidden());
+        assert!(!err.is_unauthorized());
+    }
+
     #[test]
     fn test_rest_error_display() {
         let err = RestError::AuthenticationFailed;
@@ -159,6 +191,7 @@ mod tests {
         let err = RestError::ApiError {
             code: 400,
             message: "Bad request".to_string(),
+            request_id: None,
         };
         assert_eq!(err.to_string(), "API error: Bad request (code: 400)");
 
@@ -503,4 +536,627 @@ mod tests {
             );
         }
     }
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+
+        fn subscriber(&self) -> impl tracing::Subscriber + Send + Sync + 'static {
+            let logs = self.clone();
+            tracing_subscriber::fmt()
+                .with_writer(move || logs.clone())
+                .with_ansi(false)
+                .with_max_level(tracing::Level::WARN)
+                .finish()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_200_response() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/empty"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/bdbs/1/actions/recover"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("  \n"))
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test")
+            .password("test")
+            .build()
+            .unwrap();
+
+        let unit: Result<()> = client.get("/v1/empty").await;
+        assert!(unit.is_ok());
+        let value: serde_json::Value = client.get("/v1/empty").await.unwrap();
+        assert!(value.is_null());
+        let optional = client
+            .get_optional::<crate::ClusterInfo>("/v1/empty")
+            .await
+            .unwrap();
+        assert!(optional.is_none());
+        let posted: Option<serde_json::Value> = client
+            .post("/v1/bdbs/1/actions/recover", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(posted.is_none());
+
+        let err = client
+            .get::<crate::ClusterInfo>("/v1/empty")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, RestError::ParseError(ref msg) if msg.starts_with("Empty response body")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_timeout_does_not_limit_slow_responses() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdbs/1/stats"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"intervals": []}))
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test")
+            .password("test")
+            .connect_timeout(std::time::Duration::from_millis(50))
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/v1/bdbs/1/stats").await;
+        assert!(result.is_ok(), "slow response was cut off: {:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_slow_request_threshold_warns() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"name": "slow"}))
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let logs = CapturedLogs::default();
+        let _guard = tracing::subscriber::set_default(logs.subscriber());
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test")
+            .password("test")
+            .slow_request_threshold(std::time::Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/v1/cluster").await;
+        assert!(result.is_ok());
+
+        let output = logs.contents();
+        assert!(output.contains("WARN"), "missing warning: {}", output);
+        assert!(output.contains("Slow Redis Enterprise API request"));
+        assert!(output.contains("method=GET"));
+        assert!(output.contains("path=\"/v1/cluster\""));
+        assert!(output.contains("elapsed_ms="));
+    }
+
+    #[tokio::test]
+    async fn test_fast_request_does_not_warn() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"name": "fast"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let logs = CapturedLogs::default();
+        let _guard = tracing::subscriber::set_default(logs.subscriber());
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test")
+            .password("test")
+            .slow_request_threshold(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/v1/cluster").await;
+        assert!(result.is_ok());
+        assert!(logs.contents().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_sent_with_every_request() {
+        use wiremock::matchers::header;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .and(header("X-Tenant-Id", "tenant-42"))
+            .and(header("X-Region", "eu-west"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"name": "c1"})),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test_user")
+            .password("test_pass")
+            .default_header("X-Tenant-Id", "tenant-42")
+            .default_header("X-Region", "eu-west")
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let result: Result<serde_json::Value> = client.get("/v1/cluster").await;
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_invalid_default_header_rejected() {
+        let result = EnterpriseClient::builder()
+            .default_header("X Tenant", "tenant-42")
+            .build();
+        assert!(matches!(result, Err(RestError::ConnectionError(_))));
+
+        let result = EnterpriseClient::builder()
+            .default_header("X-Tenant-Id", "bad\nvalue")
+            .build();
+        assert!(matches!(result, Err(RestError::ConnectionError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_generated_request_id_sent_and_attached_to_errors() {
+        use wiremock::matchers::header_exists;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdbs/9"))
+            .and(header_exists("X-Request-Id"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test_user")
+            .password("test_pass")
+            .request_ids(true)
+            .build()
+            .unwrap();
+
+        let error = client
+            .get::<serde_json::Value>("/v1/bdbs/9")
+            .await
+            .unwrap_err();
+        assert!(error.is_not_found());
+        assert!(matches!(error, RestError::NotFound { .. }));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let sent = requests[0].headers["x-request-id"].to_str().unwrap();
+        assert_eq!(error.request_id(), Some(sent));
+        assert_eq!(sent.len(), 36);
+        assert_eq!(&sent[14..15], "4");
+        assert!(error.to_string().contains(sent));
+    }
+
+    #[tokio::test]
+    async fn test_supplied_request_id_overrides_generated() {
+        use crate::RequestOptions;
+        use wiremock::matchers::header;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/v1/bdbs/1"))
+            .and(header("X-Request-Id", "deploy-7f3a"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test_user")
+            .password("test_pass")
+            .request_ids(true)
+            .build()
+            .unwrap()
+            .with_request_options(RequestOptions::builder().request_id("deploy-7f3a").build());
+
+        let error = client.delete("/v1/bdbs/1").await.unwrap_err();
+        assert_eq!(error.request_id(), Some("deploy-7f3a"));
+        assert!(error.is_server_error());
+    }
+
+    #[tokio::test]
+    async fn test_no_request_id_by_default() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdbs/9"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(mock_server.uri())
+            .username("test_user")
+            .password("test_pass")
+            .build()
+            .unwrap();
+
+        let error = client
+            .get::<serde_json::Value>("/v1/bdbs/9")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, RestError::NotFound { .. }));
+        assert_eq!(error.request_id(), None);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key("x-request-id"));
+    }
+
+    #[tokio::test]
+    async fn test_ping_distinguishes_failures() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .and(basic_auth("admin", "password"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"name": "c1"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let client_with = |base_url: String, password: &str| {
+            EnterpriseClient::builder()
+                .base_url(base_url)
+                .username("admin")
+                .password(password)
+                .build()
+                .unwrap()
+        };
+
+        assert!(
+            client_with(mock_server.uri(), "password")
+                .ping()
+                .await
+                .is_ok()
+        );
+
+        let bad_creds = client_with(mock_server.uri(), "wrong").ping().await;
+        assert!(matches!(bad_creds, Err(RestError::AuthenticationFailed)));
+
+        let wrong_path = client_with(format!("{}/not-the-api", mock_server.uri()), "password")
+            .ping()
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(wrong_path, RestError::ConnectionError(ref msg) if msg.contains("/v1/cluster"))
+        );
+
+        let unreachable = client_with("http://127.0.0.1:1".to_string(), "password")
+            .ping()
+            .await
+            .unwrap_err();
+        assert!(matches!(unreachable, RestError::ConnectionError(_)));
+    }
+
+    #[test]
+    fn test_min_tls_version() {
+        use crate::TlsVersion;
+
+        for version in [TlsVersion::Tls1_2, TlsVersion::Tls1_3] {
+            let result = EnterpriseClient::builder()
+                .base_url("https://localhost:9443")
+                .username("admin")
+                .password("password")
+                .min_tls_version(version)
+                .build();
+            assert!(result.is_ok(), "{} should be supported", version);
+        }
+
+        let err = EnterpriseClient::builder()
+            .base_url("https://localhost:9443")
+            .min_tls_version(TlsVersion::Tls1_0)
+            .build()
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, RestError::TlsError(ref msg) if msg.starts_with("TLS 1.0 is not supported"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_sibling_node_keeps_credentials() {
+        use wiremock::matchers::body_json;
+
+        let mock_server = MockServer::start().await;
+        let port = mock_server.address().port();
+
+        // The new master answers on the same port under a different host name
+        Mock::given(method("POST"))
+            .and(path("/v1/bdbs/1/actions/recover"))
+            .respond_with(ResponseTemplate::new(307).insert_header(
+                "Location",
+                format!("http://localhost:{}/v1/bdbs/1/actions/recover", port),
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/bdbs/1/actions/recover"))
+            .and(wiremock::matchers::header(
+                "host",
+                format!("localhost:{}", port).as_str(),
+            ))
+            .and(basic_auth("admin", "password"))
+            .and(body_json(serde_json::json!({"force": true})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"action_uid": "a1"})),
+            )
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = EnterpriseClient::builder()
+            .base_url(format!("http://127.0.0.1:{}", port))
+            .username("admin")
+            .password("password")
+            .build()
+            .unwrap();
+        let body = serde_json::json!({"force": true});
+        let recover = || client.post::<_, serde_json::Value>("/v1/bdbs/1/actions/recover", &body);
+
+        // Unknown host: not followed
+        let err = recover().await.unwrap_err();
+        assert!(
+            matches!(err, RestError::ConnectionError(ref msg) if msg.starts_with("Refusing to follow redirect"))
+        );
+
+        // Explicitly trusted host
+        let trusting = EnterpriseClient::builder()
+            .base_url(format!("http://127.0.0.1:{}", port))
+            .username("admin")
+            .password("password")
+            .redirect_hosts(["localhost"])
+            .build()
+            .unwrap();
+        let response: serde_json::Value = trusting
+            .post(
+                "/v1/bdbs/1/actions/recover",
+                &serde_json::json!({"force": true}),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response["action_uid"], "a1");
+
+        // Host reported as a node address
+        Mock::given(method("GET"))
+            .and(path("/v1/nodes"))
+            .and(basic_auth("admin", "password"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"uid": 1, "addr": "127.0.0.1"},
+                {"uid": 2, "addr": "10.0.0.2", "external_addr": ["localhost"]}
+            ])))
+            .mount(&mock_server)
+            .await;
+        let response = recover().await.unwrap();
+        assert_eq!(response["action_uid"], "a1");
+
+        // A different host on the same port is not a node of this cluster
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(
+                ResponseTemplate::new(307)
+                    .insert_header("Location", format!("http://127.0.0.2:{}/v1/cluster", port)),
+            )
+            .mount(&mock_server)
+            .await;
+        let err = client
+            .get::<serde_json::Value>("/v1/cluster")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, RestError::ConnectionError(ref msg) if msg.starts_with("Refusing to follow redirect"))
+        );
+
+        // Neither is another port
+        let other = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/license"))
+            .respond_with(
+                ResponseTemplate::new(308)
+                    .insert_header("Location", format!("{}/v1/license", other.uri())),
+            )
+            .mount(&mock_server)
+            .await;
+        let err = client
+            .get::<serde_json::Value>("/v1/license")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, RestError::ConnectionError(ref msg) if msg.starts_with("Refusing to follow redirect"))
+        );
+        assert!(other.received_requests().await.unwrap().is_empty());
+
+        // A redirect loop stops at the configured limit
+        Mock::given(method("GET"))
+            .and(path("/v1/bdbs"))
+            .respond_with(
+                ResponseTemplate::new(307)
+                    .insert_header("Location", format!("http://localhost:{}/v1/bdbs", port)),
+            )
+            .mount(&mock_server)
+            .await;
+        let limited = EnterpriseClient::builder()
+            .base_url(format!("http://127.0.0.1:{}", port))
+            .redirect_hosts(["localhost"])
+            .max_redirects(2)
+            .build()
+            .unwrap();
+        let err = limited
+            .get::<serde_json::Value>("/v1/bdbs")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, RestError::ConnectionError(ref msg) if msg.starts_with("Too many redirects"))
+        );
+    }
+
+    fn client_for(base_url: &str) -> EnterpriseClient {
+        EnterpriseClient::builder()
+            .base_url(base_url)
+            .username("admin")
+            .password("secret")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_normalize_url_ipv6_host_and_port() {
+        let client = client_for("https://[fd00::1]:9443");
+        assert_eq!(
+            client.normalize_url("/v1/bdbs"),
+            "https://[fd00::1]:9443/v1/bdbs"
+        );
+    }
+
+    #[test]
+    fn test_normalize_url_ipv6_trailing_slashes() {
+        let client = client_for("https://[fd00::1]:9443//");
+        assert_eq!(client.base_url(), "https://[fd00::1]:9443");
+        assert_eq!(
+            client.normalize_url("v1/bdbs/1"),
+            "https://[fd00::1]:9443/v1/bdbs/1"
+        );
+        assert_eq!(
+            client.normalize_url("//v1/cluster"),
+            "https://[fd00::1]:9443/v1/cluster"
+        );
+    }
+
+    #[test]
+    fn test_normalize_url_ipv6_canonical_form() {
+        let client = client_for("https://[FD00:0:0:0:0:0:0:1]:9443/");
+        assert_eq!(client.base_url(), "https://[fd00::1]:9443");
+    }
+
+    #[test]
+    fn test_normalize_url_ipv6_without_port() {
+        let client = client_for("https://[::1]");
+        assert_eq!(client.normalize_url("/v1/nodes"), "https://[::1]/v1/nodes");
+    }
+
+    #[test]
+    fn test_normalize_url_ipv4_and_hostname() {
+        let client = client_for("https://10.0.0.1:9443/");
+        assert_eq!(
+            client.normalize_url("/v1/bdbs"),
+            "https://10.0.0.1:9443/v1/bdbs"
+        );
+
+        let client = client_for("https://cluster.example.com:9443");
+        assert_eq!(
+            client.normalize_url("/v1/bdbs"),
+            "https://cluster.example.com:9443/v1/bdbs"
+        );
+    }
+
+    #[test]
+    fn test_base_path_prefix() {
+        let client = EnterpriseClient::builder()
+            .base_url("https://gw.example.com/")
+            .base_path("/redis-enterprise/")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.normalize_url("/v1/bdbs"),
+            "https://gw.example.com/redis-enterprise/v1/bdbs"
+        );
+    }
+
+    #[test]
+    fn test_base_path_collapses_slashes() {
+        let client = EnterpriseClient::builder()
+            .base_url("https://[fd00::1]:9443//")
+            .base_path("//gateway//redis-enterprise//")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.normalize_url("//v1/cluster"),
+            "https://[fd00::1]:9443/gateway/redis-enterprise/v1/cluster"
+        );
+    }
+
+    #[test]
+    fn test_unbracketed_ipv6_base_url_rejected() {
+        let result = EnterpriseClient::builder()
+            .base_url("https://fd00::1:9443")
+            .build();
+        let error = result.err().unwrap();
+        assert!(matches!(error, RestError::ValidationError(_)));
+        assert!(error.to_string().contains("brackets"));
+    }
+
+    #[test]
+    fn test_quote_command_arg() {
+        assert_eq!(quote_command_arg("GET"), "GET");
+        assert_eq!(quote_command_arg("hello world"), "\"hello world\"");
+        assert_eq!(quote_command_arg("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote_command_arg(""), "\"\"");
+    }
 }
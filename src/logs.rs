@@ -4,10 +4,11 @@
 //! - Query cluster logs
 //! - Configure log levels
 //! - Export log data
+//! - Page through large result sets with a cursor
 //! - Stream logs in real-time (via polling)
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 use std::pin::Pin;
@@ -46,6 +47,40 @@ pub struct LogsQuery {
     pub offset: Option<u32>,
 }
 
+/// One page of event logs
+#[derive(Debug, Clone)]
+pub struct LogsPage {
+    /// Events in this page
+    pub entries: Vec<LogEntry>,
+    /// Cursor for the next page, to pass as `after` to [`LogsHandler::list_page`]; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Prefix of the cursors [`LogsHandler::list_page`] builds
+const TIME_CURSOR_PREFIX: &str = "time:";
+
+/// Position after the last event of a page, for time-window paging
+///
+/// Encoded as `time:<skip>:<timestamp>`, where `skip` counts the events at
+/// `timestamp` that were already returned and must not be repeated.
+fn time_cursor(entries: &[LogEntry], previous: Option<(u32, &str)>) -> Option<String> {
+    let last = entries.last()?;
+    let mut skip = entries.iter().filter(|e| e.time == last.time).count() as u32;
+    if let Some((previous_skip, previous_time)) = previous
+        && previous_time == last.time
+        && entries.iter().all(|e| e.time == last.time)
+    {
+        skip += previous_skip;
+    }
+    Some(format!("{}{}:{}", TIME_CURSOR_PREFIX, skip, last.time))
+}
+
+/// Decode a cursor built by [`time_cursor`]
+fn parse_time_cursor(cursor: &str) -> Option<(u32, &str)> {
+    let (skip, time) = cursor.strip_prefix(TIME_CURSOR_PREFIX)?.split_once(':')?;
+    Some((skip.parse().ok()?, time))
+}
+
 /// Logs handler for querying event logs
 pub struct LogsHandler {
     client: RestClient,
@@ -67,6 +102,59 @@ impl LogsHandler {
         }
     }
 
+    /// Get one page of event logs
+    ///
+    /// Pass the returned [`LogsPage::next_cursor`] as `after` to fetch the
+    /// following page. `next_cursor` is `None` once a page comes back with fewer
+    /// than `query.limit` events; without a `limit` the whole result is one page.
+    ///
+    /// `/v1/logs` has no server-side cursor, so paging is done client-side by
+    /// time window: the cursor records the last event's timestamp, and the next
+    /// request starts from it with `stime`, skipping the events at that
+    /// timestamp already returned. This needs ascending order, so every page is
+    /// requested with `order=asc` and any other `order` is rejected; `offset`
+    /// only applies to the first page.
+    pub async fn list_page(&self, mut query: LogsQuery, after: Option<&str>) -> Result<LogsPage> {
+        if query.order.as_deref().is_some_and(|order| order != "asc") {
+            return Err(RestError::ValidationError(
+                "list_page only supports ascending order".to_string(),
+            ));
+        }
+        query.order = Some("asc".to_string());
+        let previous = after
+            .map(|cursor| {
+                parse_time_cursor(cursor).ok_or_else(|| {
+                    RestError::ValidationError(format!("invalid logs cursor '{}'", cursor))
+                })
+            })
+            .transpose()?;
+        let skip = match previous {
+            Some((skip, time)) => {
+                query.stime = Some(time.to_string());
+                query.offset = None;
+                query.limit = query.limit.map(|limit| limit + skip);
+                skip
+            }
+            None => 0,
+        };
+        let limit = query.limit;
+
+        let query_str = serde_urlencoded::to_string(&query).unwrap_or_default();
+        let entries: Vec<LogEntry> = self.client.get(&format!("/v1/logs?{}", query_str)).await?;
+
+        let full = limit.is_some_and(|limit| entries.len() >= limit as usize);
+        let entries: Vec<LogEntry> = entries.into_iter().skip(skip as usize).collect();
+        let next_cursor = if full {
+            time_cursor(&entries, previous)
+        } else {
+            None
+        };
+        Ok(LogsPage {
+            entries,
+            next_cursor,
+        })
+    }
+
     /// Stream logs in real-time by polling
     ///
     /// Since Redis Enterprise API doesn't support native streaming, this polls
@@ -5,12 +5,24 @@
 //! - Track migration status
 //! - Manage migration plans
 
+use crate::bdb::DatabaseInfo;
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::time::{sleep, timeout};
 use typed_builder::TypedBuilder;
 
+/// Stream of migration updates returned by [`MigrationsHandler::watch`]
+pub type MigrationWatchStream<'a> = Pin<Box<dyn Stream<Item = Result<Migration>> + Send + 'a>>;
+
+/// Migration statuses after which the migration no longer progresses
+const MIGRATION_TERMINAL_STATES: &[&str] = &["completed", "failed", "cancelled", "error"];
+
 /// Migration task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Migration {
@@ -36,6 +48,13 @@ pub struct Migration {
     pub error: Option<String>,
 }
 
+impl Migration {
+    /// Whether the migration has reached a terminal status (completed, failed, cancelled or error)
+    pub fn is_finished(&self) -> bool {
+        MIGRATION_TERMINAL_STATES.contains(&self.status.as_str())
+    }
+}
+
 /// Migration endpoint configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MigrationEndpoint {
@@ -58,6 +77,39 @@ pub struct MigrationEndpoint {
     pub ssl: Option<bool>,
 }
 
+/// Result of probing a migration endpoint with [`MigrationsHandler::test_endpoint`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointReachability {
+    /// Whether the endpoint accepted a TCP connection (or, for a cluster database, is active)
+    pub reachable: bool,
+    /// Time taken to connect, or to look up the cluster database
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<Duration>,
+    /// Whether the endpoint accepted the credentials; `None` when not checked,
+    /// which is always the case for endpoints probed by host and port
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_ok: Option<bool>,
+    /// Why the endpoint is unreachable or rejected authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl EndpointReachability {
+    /// Whether the endpoint is reachable and did not reject authentication
+    pub fn is_ok(&self) -> bool {
+        self.reachable && self.auth_ok != Some(false)
+    }
+
+    fn unreachable(latency: Option<Duration>, error: String) -> Self {
+        EndpointReachability {
+            reachable: false,
+            latency,
+            auth_ok: None,
+            error: Some(error),
+        }
+    }
+}
+
 /// Create migration request
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct CreateMigrationRequest {
@@ -142,4 +194,130 @@ impl MigrationsHandler {
             .delete(&format!("/v1/migrations/{}", migration_id))
             .await
     }
+
+    /// Check that a migration endpoint is reachable before creating a migration
+    ///
+    /// Endpoints with a `host` and `port` are probed directly from this client
+    /// by opening a TCP connection, which is closed without sending anything;
+    /// credentials are never sent, so `auth_ok` is left unset. Endpoints
+    /// referring to a cluster database by `bdb_uid` are reachable when the
+    /// database is active.
+    ///
+    /// Note that a direct probe tests reachability from the machine running this
+    /// client, which may differ from the cluster's view of the network.
+    pub async fn test_endpoint(
+        &self,
+        endpoint: &MigrationEndpoint,
+    ) -> Result<EndpointReachability> {
+        match (&endpoint.host, endpoint.port, endpoint.bdb_uid) {
+            (Some(host), Some(port), _) => Ok(self.probe_address(host, port).await),
+            (_, _, Some(bdb_uid)) => {
+                let start = Instant::now();
+                let database: Result<DatabaseInfo> =
+                    self.client.get(&format!("/v1/bdbs/{}", bdb_uid)).await;
+                let latency = Some(start.elapsed());
+                match database {
+                    Ok(db) if db.status.as_deref() == Some("active") => Ok(EndpointReachability {
+                        reachable: true,
+                        latency,
+                        auth_ok: None,
+                        error: None,
+                    }),
+                    Ok(db) => Ok(EndpointReachability::unreachable(
+                        latency,
+                        format!(
+                            "Database {} is {}",
+                            bdb_uid,
+                            db.status.as_deref().unwrap_or("in an unknown state")
+                        ),
+                    )),
+                    Err(e) if e.is_not_found() => Ok(EndpointReachability::unreachable(
+                        latency,
+                        format!("Database {} does not exist", bdb_uid),
+                    )),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Err(RestError::ValidationError(
+                "Migration endpoint needs either host and port or bdb_uid".to_string(),
+            )),
+        }
+    }
+
+    /// Open (and close) a TCP connection to an endpoint
+    async fn probe_address(&self, host: &str, port: u16) -> EndpointReachability {
+        let start = Instant::now();
+        match timeout(self.client.timeout(), TcpStream::connect((host, port))).await {
+            Ok(Ok(_)) => EndpointReachability {
+                reachable: true,
+                latency: Some(start.elapsed()),
+                auth_ok: None,
+                error: None,
+            },
+            Ok(Err(e)) => EndpointReachability::unreachable(
+                None,
+                format!("Failed to connect to {}:{}: {}", host, port, e),
+            ),
+            Err(_) => EndpointReachability::unreachable(
+                None,
+                format!("Timed out connecting to {}:{}", host, port),
+            ),
+        }
+    }
+
+    /// Watch a migration's progress
+    ///
+    /// Polls the migration every `poll_interval` and yields it on the first poll
+    /// and whenever its status or progress changes. The stream ends after
+    /// yielding a terminal status (see [`Migration::is_finished`]) or an error.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use redis_enterprise::{EnterpriseClient, MigrationsHandler};
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example(client: EnterpriseClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let migrations = MigrationsHandler::new(client);
+    /// let mut stream = migrations.watch("migration-1", Duration::from_secs(5));
+    ///
+    /// while let Some(migration) = stream.next().await {
+    ///     let migration = migration?;
+    ///     let percent = migration.progress.unwrap_or_default() * 100.0;
+    ///     println!("{}: {:.0}%", migration.status, percent);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch<'a>(
+        &'a self,
+        migration_id: &'a str,
+        poll_interval: Duration,
+    ) -> MigrationWatchStream<'a> {
+        Box::pin(async_stream::stream! {
+            let mut last: Option<(String, Option<f32>)> = None;
+
+            loop {
+                match self.get(migration_id).await {
+                    Ok(migration) => {
+                        let current = (migration.status.clone(), migration.progress);
+                        let finished = migration.is_finished();
+                        if last.as_ref() != Some(&current) {
+                            yield Ok(migration);
+                        }
+                        if finished {
+                            break;
+                        }
+                        last = Some(current);
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+
+                sleep(poll_interval).await;
+            }
+        })
+    }
 }
@@ -109,7 +109,7 @@ impl ModuleHandler {
             .await
         {
             Ok(response) => Ok(response),
-            Err(crate::error::RestError::NotFound) => {
+            Err(crate::error::RestError::NotFound { .. }) => {
                 // v2 endpoint doesn't exist, try v1
                 match self
                     .client
@@ -0,0 +1,151 @@
+//! Running the same operation across many clusters
+//!
+//! ## Overview
+//! - Hold named [`EnterpriseClient`]s for a fleet of clusters
+//! - Fan an operation out to every cluster concurrently
+//! - Collect per-cluster results, so one unreachable cluster doesn't hide the rest
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use redis_enterprise::{EnterpriseClient, MultiClusterClient};
+//!
+//! # async fn example(east: EnterpriseClient, west: EnterpriseClient) {
+//! let fleet = MultiClusterClient::new()
+//!     .with_cluster("us-east", east)
+//!     .with_cluster("us-west", west)
+//!     .concurrency(8);
+//!
+//! let counts = fleet
+//!     .map_concurrent(|_name, client| async move {
+//!         Ok(client.databases().list().await?.len())
+//!     })
+//!     .await;
+//!
+//! for (name, count) in counts {
+//!     match count {
+//!         Ok(count) => println!("{name}: {count} databases"),
+//!         Err(err) => eprintln!("{name}: {err}"),
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::client::EnterpriseClient;
+use crate::error::Result;
+use futures::stream::{self, StreamExt};
+use std::collections::BTreeMap;
+use std::future::Future;
+
+/// Named clients for a fleet of clusters
+///
+/// Clusters are kept in name order, which is also the order of results.
+#[derive(Clone, Default)]
+pub struct MultiClusterClient {
+    clients: BTreeMap<String, EnterpriseClient>,
+    concurrency: Option<usize>,
+}
+
+impl MultiClusterClient {
+    /// An empty fleet with unlimited concurrency
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a cluster, replacing any cluster with the same name
+    #[must_use]
+    pub fn with_cluster(mut self, name: impl Into<String>, client: EnterpriseClient) -> Self {
+        self.insert(name, client);
+        self
+    }
+
+    /// Run at most `limit` per-cluster operations at once (0 is treated as 1)
+    #[must_use]
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = Some(limit.max(1));
+        self
+    }
+
+    /// Add a cluster, returning the client previously registered under `name`
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        client: EnterpriseClient,
+    ) -> Option<EnterpriseClient> {
+        self.clients.insert(name.into(), client)
+    }
+
+    /// Remove a cluster
+    pub fn remove(&mut self, name: &str) -> Option<EnterpriseClient> {
+        self.clients.remove(name)
+    }
+
+    /// The client for a cluster
+    pub fn get(&self, name: &str) -> Option<&EnterpriseClient> {
+        self.clients.get(name)
+    }
+
+    /// Cluster names, in order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// Number of clusters
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Whether no clusters are registered
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Run `operation` against every cluster concurrently and collect each result
+    ///
+    /// Each call gets the cluster name and its own client handle. A failing cluster
+    /// only affects its own entry in the returned map.
+    pub async fn map_concurrent<T, F, Fut>(&self, operation: F) -> BTreeMap<String, Result<T>>
+    where
+        F: Fn(String, EnterpriseClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let limit = self.concurrency.unwrap_or(self.clients.len()).max(1);
+        stream::iter(self.clients.iter())
+            .map(|(name, client)| {
+                let call = operation(name.clone(), client.clone());
+                async move { (name.clone(), call.await) }
+            })
+            .buffer_unordered(limit)
+            .collect()
+            .await
+    }
+
+    /// Run `operation` against every cluster concurrently for its side effects
+    ///
+    /// Completes once every cluster's operation has finished.
+    pub async fn for_each<F, Fut>(&self, operation: F)
+    where
+        F: Fn(String, EnterpriseClient) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let limit = self.concurrency.unwrap_or(self.clients.len()).max(1);
+        stream::iter(self.clients.iter())
+            .map(|(name, client)| operation(name.clone(), client.clone()))
+            .buffer_unordered(limit)
+            .collect::<Vec<()>>()
+            .await;
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, EnterpriseClient)> for MultiClusterClient {
+    fn from_iter<I: IntoIterator<Item = (S, EnterpriseClient)>>(iter: I) -> Self {
+        MultiClusterClient {
+            clients: iter
+                .into_iter()
+                .map(|(name, client)| (name.into(), client))
+                .collect(),
+            concurrency: None,
+        }
+    }
+}
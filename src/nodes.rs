@@ -6,9 +6,14 @@
 //! - Monitor status and metrics
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
+use crate::shards::Shard;
+use futures::try_join;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
 use typed_builder::TypedBuilder;
 
 /// Response from node action operations
@@ -47,6 +52,7 @@ pub struct Node {
     pub external_addr: Option<Vec<String>>,
 
     /// Total memory in bytes
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub total_memory: Option<u64>,
 
     /// Installed OS version (read-only)
@@ -112,6 +118,7 @@ pub struct Node {
     pub bigstore_driver: Option<String>,
 
     /// Storage size of bigstore storage (read-only)
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub bigstore_size: Option<u64>,
 
     /// Public IP address (deprecated)
@@ -121,6 +128,22 @@ pub struct Node {
     pub recovery_path: Option<String>,
 }
 
+/// Node configuration snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    /// Snapshot name, which identifies it on the node
+    #[serde(alias = "id")]
+    pub name: String,
+    /// Node the snapshot was taken on
+    pub node_uid: Option<u32>,
+    /// Time the snapshot was taken (ISO 8601 format)
+    #[serde(alias = "time", alias = "creation_time")]
+    pub timestamp: Option<String>,
+    /// Snapshot size in bytes
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
+    pub size: Option<u64>,
+}
+
 /// Node stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeStats {
@@ -128,13 +151,58 @@ pub struct NodeStats {
     pub cpu_user: Option<f64>,
     pub cpu_system: Option<f64>,
     pub cpu_idle: Option<f64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub free_memory: Option<u64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub network_bytes_in: Option<u64>,
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub network_bytes_out: Option<u64>,
     pub persistent_storage_free: Option<u64>,
     pub ephemeral_storage_free: Option<u64>,
 }
 
+impl NodeStats {
+    /// CPU utilization as a percentage (100 - idle)
+    ///
+    /// The node stats API reports `cpu_idle` as a fraction of CPU time
+    /// (0.0-1.0). Returns `None` when idle time is not reported or falls
+    /// outside that range.
+    pub fn cpu_utilization(&self) -> Option<f64> {
+        let idle = self.cpu_idle.filter(|idle| (0.0..=1.0).contains(idle))?;
+        Some((1.0 - idle) * 100.0)
+    }
+
+    /// Memory utilization as a percentage of `total_memory` bytes
+    ///
+    /// Returns `None` when free memory is not reported or `total_memory` is 0.
+    pub fn memory_utilization(&self, total_memory: u64) -> Option<f64> {
+        let free = self.free_memory?;
+        if total_memory == 0 {
+            return None;
+        }
+        let used = total_memory.saturating_sub(free);
+        Some(used as f64 / total_memory as f64 * 100.0)
+    }
+}
+
+/// Whether a node is in maintenance mode and how many shards it still hosts
+///
+/// Returned by [`NodeHandler::maintenance_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceStatus {
+    /// The node no longer accepts new shards (`accept_servers` is `false`)
+    pub in_maintenance: bool,
+    /// Shards still placed on the node
+    pub shards_remaining: u32,
+}
+
+impl MaintenanceStatus {
+    /// In maintenance mode with every shard moved off, so safe to take down
+    pub fn is_drained(&self) -> bool {
+        self.in_maintenance && self.shards_remaining == 0
+    }
+}
+
 /// Node action request
 #[derive(Debug, Serialize, TypedBuilder)]
 pub struct NodeActionRequest {
@@ -185,6 +253,45 @@ impl NodeHandler {
         self.client.get(&format!("/v1/nodes/{}/stats", uid)).await
     }
 
+    /// Check whether a node is in maintenance mode and how many shards remain on it
+    ///
+    /// A node in maintenance mode stops accepting shards, so this reads the
+    /// node's `accept_servers` flag and counts the cluster's shards placed on it.
+    pub async fn maintenance_status(&self, uid: u32) -> Result<MaintenanceStatus> {
+        let (node, shards) = try_join!(self.get(uid), self.client.get::<Vec<Shard>>("/v1/shards"))?;
+        let node_id = uid.to_string();
+        Ok(MaintenanceStatus {
+            in_maintenance: node.accept_servers == Some(false),
+            shards_remaining: shards
+                .iter()
+                .filter(|shard| shard.node_uid == node_id)
+                .count() as u32,
+        })
+    }
+
+    /// Poll [`maintenance_status`](Self::maintenance_status) until the node is drained
+    ///
+    /// Use after the `maintenance_on` action, before rebooting the node. Returns
+    /// [`RestError::Timeout`] if shards remain after `timeout`.
+    pub async fn wait_until_drained(
+        &self,
+        uid: u32,
+        poll: Duration,
+        timeout: Duration,
+    ) -> Result<MaintenanceStatus> {
+        let mut delays = Backoff::fixed(poll).timeout(timeout);
+        loop {
+            let status = self.maintenance_status(uid).await?;
+            if status.is_drained() {
+                return Ok(status);
+            }
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Get node actions
     pub async fn actions(&self, uid: u32) -> Result<Value> {
         self.client.get(&format!("/v1/nodes/{}/actions", uid)).await
@@ -230,14 +337,14 @@ impl NodeHandler {
     }
 
     /// List snapshots for a node - GET /v1/nodes/{uid}/snapshots
-    pub async fn snapshots(&self, uid: u32) -> Result<Value> {
+    pub async fn snapshots(&self, uid: u32) -> Result<Vec<NodeSnapshot>> {
         self.client
             .get(&format!("/v1/nodes/{}/snapshots", uid))
             .await
     }
 
     /// Create a snapshot - POST /v1/nodes/{uid}/snapshots/{name}
-    pub async fn snapshot_create(&self, uid: u32, name: &str) -> Result<Value> {
+    pub async fn snapshot_create(&self, uid: u32, name: &str) -> Result<NodeSnapshot> {
         self.client
             .post(
                 &format!("/v1/nodes/{}/snapshots/{}", uid, name),
@@ -6,9 +6,12 @@
 //! - Test OCSP connectivity
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
+use crate::retry::Backoff;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
 
 /// OCSP configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +57,26 @@ pub struct OcspStatus {
     pub revocation_reason: Option<String>,
 }
 
+impl OcspStatus {
+    /// True if the certificate was confirmed good/valid
+    pub fn is_good(&self) -> bool {
+        self.status.eq_ignore_ascii_case("good")
+            || self
+                .certificate_status
+                .as_deref()
+                .is_some_and(|status| status.eq_ignore_ascii_case("valid"))
+    }
+
+    /// True if the responder reported the certificate as revoked
+    pub fn is_revoked(&self) -> bool {
+        self.status.eq_ignore_ascii_case("revoked")
+            || self
+                .certificate_status
+                .as_deref()
+                .is_some_and(|status| status.eq_ignore_ascii_case("revoked"))
+    }
+}
+
 /// OCSP test result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OcspTestResult {
@@ -92,6 +115,37 @@ impl OcspHandler {
         self.client.get("/v1/ocsp/status").await
     }
 
+    /// Poll OCSP status until the certificate is reported good
+    ///
+    /// Returns the final status once it is good. Fails immediately with
+    /// [`RestError::TlsError`] if the certificate is revoked, and with
+    /// [`RestError::Timeout`] if no good status is seen within `timeout`.
+    pub async fn wait_for_good(
+        &self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<OcspStatus> {
+        let mut delays = Backoff::fixed(poll_interval).timeout(timeout);
+        loop {
+            let status = self.get_status().await?;
+            if status.is_good() {
+                return Ok(status);
+            }
+            if status.is_revoked() {
+                return Err(RestError::TlsError(format!(
+                    "Certificate revoked at {} (reason: {})",
+                    status.revocation_time.as_deref().unwrap_or("unknown time"),
+                    status.revocation_reason.as_deref().unwrap_or("unspecified")
+                )));
+            }
+
+            match delays.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(RestError::Timeout),
+            }
+        }
+    }
+
     /// Test OCSP connectivity
     pub async fn test(&self) -> Result<OcspTestResult> {
         self.client.get("/v1/ocsp/test").await
@@ -5,10 +5,12 @@
 //! - Create and update configurations
 //! - Monitor status and metrics
 
+use crate::actions::Action;
 use crate::client::RestClient;
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use typed_builder::TypedBuilder;
 
 /// Response for a single metric query
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -195,24 +197,76 @@ impl ProxyHandler {
             .await
     }
 
+    /// Restart a proxy - POST /v1/proxies/{uid}/actions/restart
+    ///
+    /// Returns the action tracking the restart.
+    pub async fn restart(&self, uid: u32) -> Result<Action> {
+        self.client
+            .post(
+                &format!("/v1/proxies/{}/actions/restart", uid),
+                &serde_json::json!({}),
+            )
+            .await
+    }
+
     /// Update proxies (bulk) - PUT /v1/proxies
-    pub async fn update_all(&self, update: ProxyUpdate) -> Result<Vec<Proxy>> {
+    pub async fn update_all(&self, update: ProxyConfigRequest) -> Result<Vec<Proxy>> {
         self.client.put("/v1/proxies", &update).await
     }
 
     /// Update specific proxy - PUT /v1/proxies/{uid}
-    pub async fn update(&self, uid: u32, update: ProxyUpdate) -> Result<Proxy> {
+    pub async fn update(&self, uid: u32, update: ProxyConfigRequest) -> Result<Proxy> {
         self.client
             .put(&format!("/v1/proxies/{}", uid), &update)
             .await
     }
 }
 
-/// Proxy update body
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProxyUpdate {
+/// Proxy configuration update
+///
+/// Only fields that are set are sent, so this can be used for partial updates.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis_enterprise::ProxyConfigRequest;
+///
+/// let request = ProxyConfigRequest::builder()
+///     .threads(8)
+///     .max_connections(20_000)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+pub struct ProxyConfigRequest {
+    /// Number of worker threads
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub threads: Option<u32>,
+    /// Maximum number of client connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
     pub max_connections: Option<u32>,
+    /// Maximum number of worker threads when scaling dynamically
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threads: Option<u32>,
+    #[builder(default, setter(strip_option))]
+    pub max_threads: Option<u32>,
+    /// Whether the proxy adjusts its thread count based on load
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub dynamic_threads_scaling: Option<bool>,
+    /// CPU usage threshold percentage for thread scaling decisions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub threads_usage_threshold: Option<u32>,
+    /// Maximum number of pending connections in the listen queue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub backlog: Option<u32>,
+    /// Logging level (e.g., 'debug', 'info', 'warning', 'error')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub log_level: Option<String>,
 }
+
+/// Alias for backwards compatibility
+pub type ProxyUpdate = ProxyConfigRequest;
@@ -5,8 +5,9 @@
 //! - Manage user permissions
 //! - Query ACL rules
 
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 use typed_builder::TypedBuilder;
 
 /// Redis ACL information
@@ -34,6 +35,202 @@ pub struct CreateRedisAclRequest {
     pub description: Option<String>,
 }
 
+/// ACL categories known to Redis and its common modules
+///
+/// Modules can register further categories, so names outside this list are
+/// only warned about.
+const ACL_CATEGORIES: &[&str] = &[
+    "all",
+    "admin",
+    "bitmap",
+    "blocking",
+    "connection",
+    "dangerous",
+    "fast",
+    "geo",
+    "hash",
+    "hyperloglog",
+    "keyspace",
+    "list",
+    "pubsub",
+    "read",
+    "scripting",
+    "set",
+    "slow",
+    "sortedset",
+    "stream",
+    "string",
+    "transaction",
+    "write",
+    // Module categories
+    "search",
+    "json",
+    "timeseries",
+    "bloom",
+    "cuckoo",
+    "cms",
+    "topk",
+    "tdigest",
+];
+
+/// Rule keywords that take no argument
+const ACL_KEYWORDS: &[&str] = &[
+    "on",
+    "off",
+    "allkeys",
+    "allchannels",
+    "allcommands",
+    "nocommands",
+    "resetkeys",
+    "resetchannels",
+    "resetpass",
+    "nopass",
+    "reset",
+    "clearselectors",
+    "sanitize-payload",
+    "skip-sanitize-payload",
+];
+
+impl CreateRedisAclRequest {
+    /// Check the ACL rule string for obviously malformed rules
+    ///
+    /// This is a best-effort client-side check that catches typos such as
+    /// unbalanced selectors or glob brackets and rules with a missing operand,
+    /// without a round trip to the server. Unknown `@category` names are only
+    /// logged, since modules can register their own. The server remains the
+    /// authority on what it accepts.
+    ///
+    /// Password and hash rules (`>`, `<`, `#`, `!`) are never parsed for
+    /// selectors and are redacted in the error message.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| {
+            Err(RestError::ValidationError(format!(
+                "Invalid ACL rule '{}': {}",
+                redact_acl(&self.acl),
+                reason
+            )))
+        };
+
+        // Selectors are parenthesized groups of rules: a token opening with '('
+        // starts one and a token closing with ')' ends it. They cannot nest.
+        let mut in_selector = false;
+        for token in self.acl.split_whitespace() {
+            if is_secret_rule(token) {
+                if let Err(reason) = validate_acl_rule(token) {
+                    return invalid(reason);
+                }
+                continue;
+            }
+            let mut rule = token;
+            if let Some(rest) = rule.strip_prefix('(') {
+                if in_selector || rest.starts_with('(') {
+                    return invalid("nested selector".to_string());
+                }
+                in_selector = true;
+                rule = rest;
+            }
+            if let Some(rest) = rule.strip_suffix(')') {
+                if in_selector {
+                    in_selector = false;
+                    rule = rest;
+                } else if !matches!(rule.chars().next(), Some('~' | '&' | '%')) {
+                    // Key and channel patterns may end with a literal ')'
+                    return invalid("unbalanced ')'".to_string());
+                }
+            }
+            if rule.is_empty() {
+                continue;
+            }
+            if let Err(reason) = validate_acl_rule(rule) {
+                return invalid(reason);
+            }
+        }
+        if in_selector {
+            return invalid("unbalanced '('".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Whether `rule` sets or removes a password or password hash
+fn is_secret_rule(rule: &str) -> bool {
+    matches!(rule.chars().next(), Some('>' | '<' | '#' | '!'))
+}
+
+/// The ACL string with password and hash values masked
+fn redact_acl(acl: &str) -> String {
+    acl.split_whitespace()
+        .map(|token| {
+            if is_secret_rule(token) && token.len() > 1 {
+                format!("{}***", &token[..1])
+            } else {
+                token.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn validate_acl_rule(rule: &str) -> std::result::Result<(), String> {
+    if ACL_KEYWORDS.contains(&rule.to_ascii_lowercase().as_str()) {
+        return Ok(());
+    }
+
+    let Some(kind) = rule.chars().next() else {
+        return Ok(());
+    };
+    let operand = &rule[kind.len_utf8()..];
+    match kind {
+        '+' | '-' => match operand.strip_prefix('@') {
+            Some("") => Err(format!("'{}' is missing a category name", rule)),
+            Some(category) => {
+                if !ACL_CATEGORIES.contains(&category.to_ascii_lowercase().as_str()) {
+                    warn!("ACL rule '{}' uses an unknown category", rule);
+                }
+                Ok(())
+            }
+            None if operand.is_empty() || operand.starts_with('|') || operand.ends_with('|') => {
+                Err(format!("'{}' is missing a command name", rule))
+            }
+            None => Ok(()),
+        },
+        '~' | '&' => check_pattern(rule, operand),
+        '%' => match operand.split_once('~') {
+            Some((perms, pattern))
+                if !perms.is_empty() && perms.chars().all(|c| matches!(c, 'R' | 'W')) =>
+            {
+                check_pattern(rule, pattern)
+            }
+            _ => Err(format!("'{}' must look like %R~, %W~ or %RW~", rule)),
+        },
+        '>' | '<' | '#' | '!' if !operand.is_empty() => Ok(()),
+        '>' | '<' | '#' | '!' => Err(format!("'{}' is missing a value", rule)),
+        _ => Err(format!("unrecognized rule '{}'", rule)),
+    }
+}
+
+fn check_pattern(rule: &str, pattern: &str) -> std::result::Result<(), String> {
+    if pattern.is_empty() {
+        return Err(format!("'{}' is missing a pattern", rule));
+    }
+    let mut chars = pattern.chars();
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            _ => {}
+        }
+    }
+    if in_class {
+        return Err(format!("unbalanced '[' in pattern '{}'", rule));
+    }
+    Ok(())
+}
+
 define_handler!(
     /// Redis ACL handler for managing ACLs
     pub struct RedisAclHandler;
@@ -43,8 +240,6 @@ impl_crud!(RedisAclHandler {
     list => RedisAcl, "/v1/redis_acls";
     get(u32) => RedisAcl, "/v1/redis_acls/{}";
     delete(u32), "/v1/redis_acls/{}";
-    create(CreateRedisAclRequest) => RedisAcl, "/v1/redis_acls";
-    update(u32, CreateRedisAclRequest) => RedisAcl, "/v1/redis_acls/{}";
 });
 
 /// Alias for backwards compatibility and intuitive plural naming
@@ -52,6 +247,23 @@ pub type RedisAclsHandler = RedisAclHandler;
 
 // Custom methods
 impl RedisAclHandler {
+    /// Create a Redis ACL
+    ///
+    /// The rule string is checked with [`CreateRedisAclRequest::validate`] first,
+    /// so malformed rules fail without a round trip.
+    pub async fn create(&self, request: CreateRedisAclRequest) -> Result<RedisAcl> {
+        request.validate()?;
+        self.client.post("/v1/redis_acls", &request).await
+    }
+
+    /// Update a Redis ACL, validating the rule string first
+    pub async fn update(&self, uid: u32, request: CreateRedisAclRequest) -> Result<RedisAcl> {
+        request.validate()?;
+        self.client
+            .put(&format!("/v1/redis_acls/{}", uid), &request)
+            .await
+    }
+
     /// Validate an ACL payload - POST /v1/redis_acls/validate
     pub async fn validate(&self, body: CreateRedisAclRequest) -> Result<AclValidation> {
         self.client.post("/v1/redis_acls/validate", &body).await
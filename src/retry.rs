@@ -0,0 +1,190 @@
+//! Backoff and retry helpers for polling loops
+//!
+//! ## Overview
+//! - [`Backoff`] yields the delays between attempts: exponential growth, a cap,
+//!   optional jitter, and an optional limit on attempts or total time
+//! - [`retry_async`] and [`retry_async_when`] re-run a fallible async operation
+//!   with those delays, for any error type
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use redis_enterprise::retry::{Backoff, retry_async_when};
+//! use redis_enterprise::{EnterpriseClient, RestError};
+//! use std::time::Duration;
+//!
+//! # async fn example(client: EnterpriseClient) -> Result<(), RestError> {
+//! let backoff = Backoff::new(Duration::from_millis(200))
+//!     .max_delay(Duration::from_secs(5))
+//!     .jitter(0.2)
+//!     .max_retries(6);
+//! let cluster = client.cluster();
+//! let info = retry_async_when(backoff, RestError::is_retryable, || cluster.info()).await?;
+//! println!("Cluster: {}", info.name);
+//! # Ok(())
+//! # }
+//! ```
+
+use rand::Rng;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// Delays between attempts, as an iterator
+///
+/// The `n`th delay is `base * multiplier^n`, capped at `max_delay`, then
+/// reduced by a random fraction of up to `jitter`. The iterator is unbounded
+/// unless [`max_retries`](Self::max_retries) or [`timeout`](Self::timeout) is set.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: f64,
+    max_retries: Option<u32>,
+    deadline: Option<Instant>,
+    attempt: u32,
+}
+
+impl Default for Backoff {
+    /// 500ms doubling up to 30s, with 20% jitter, for at most 5 retries
+    fn default() -> Self {
+        Backoff::new(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(30))
+            .jitter(0.2)
+            .max_retries(5)
+    }
+}
+
+impl Backoff {
+    /// Exponential backoff starting at `base` and doubling, capped at 30 seconds
+    #[must_use]
+    pub fn new(base: Duration) -> Self {
+        Backoff {
+            base,
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.0,
+            max_retries: None,
+            deadline: None,
+            attempt: 0,
+        }
+    }
+
+    /// The same `interval` between every attempt
+    #[must_use]
+    pub fn fixed(interval: Duration) -> Self {
+        Backoff::new(interval).multiplier(1.0).max_delay(interval)
+    }
+
+    /// Cap each delay at `max_delay`
+    #[must_use]
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Grow each delay by `multiplier` (values below 1 are treated as 1)
+    #[must_use]
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Shorten each delay by a random fraction of up to `jitter` (clamped to `0.0..=1.0`)
+    ///
+    /// Spreads out clients that would otherwise retry in lockstep. Jitter never
+    /// lengthens a delay, so `max_delay` still holds.
+    #[must_use]
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Stop after `max_retries` delays
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Stop once the next delay would end more than `timeout` from now
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Delay for the current attempt before jitter
+    fn nominal_delay(&self) -> Duration {
+        let factor = self
+            .multiplier
+            .powi(self.attempt.min(i32::MAX as u32) as i32);
+        let delay = self.base.as_secs_f64() * factor;
+        if !delay.is_finite() || delay >= self.max_delay.as_secs_f64() {
+            self.max_delay
+        } else {
+            Duration::from_secs_f64(delay)
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if self.max_retries.is_some_and(|max| self.attempt >= max) {
+            return None;
+        }
+        let mut delay = self.nominal_delay();
+        if self.jitter > 0.0 {
+            let cut = rand::rng().random_range(0.0..=self.jitter);
+            delay = delay.mul_f64(1.0 - cut);
+        }
+        if let Some(deadline) = self.deadline
+            && Instant::now() + delay > deadline
+        {
+            return None;
+        }
+        self.attempt = self.attempt.saturating_add(1);
+        Some(delay)
+    }
+}
+
+/// Run `operation` until it succeeds or `backoff` runs out, retrying every error
+///
+/// Returns the last error once `backoff` yields no more delays.
+pub async fn retry_async<T, E, F, Fut>(backoff: Backoff, operation: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    retry_async_when(backoff, |_| true, operation).await
+}
+
+/// Run `operation` until it succeeds, `should_retry` rejects an error, or `backoff` runs out
+///
+/// Errors `should_retry` rejects are returned immediately, e.g. with
+/// [`RestError::is_retryable`](crate::RestError::is_retryable) to give up on a
+/// `404` but retry a `503`.
+pub async fn retry_async_when<T, E, F, Fut, P>(
+    mut backoff: Backoff,
+    mut should_retry: P,
+    mut operation: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: FnMut(&E) -> bool,
+{
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if !should_retry(&err) => return Err(err),
+            Err(err) => match backoff.next() {
+                Some(delay) => sleep(delay).await,
+                None => return Err(err),
+            },
+        }
+    }
+}
@@ -24,6 +24,81 @@ pub struct RoleInfo {
     pub cluster_roles: Option<Vec<String>>,
 }
 
+impl RoleInfo {
+    /// Flatten the role's management level, cluster roles and per-database
+    /// bindings into a list of permissions
+    ///
+    /// Empty and `none` values grant nothing and are left out, as are duplicates.
+    pub fn effective_permissions(&self) -> Vec<Permission> {
+        let granted = |value: &&String| !value.is_empty() && value.as_str() != "none";
+        let mut permissions = Vec::new();
+        permissions.extend(
+            self.management
+                .iter()
+                .filter(granted)
+                .map(|level| Permission::Management(level.clone())),
+        );
+        permissions.extend(
+            self.data_access
+                .iter()
+                .filter(granted)
+                .map(|level| Permission::DataAccess(level.clone())),
+        );
+        permissions.extend(
+            self.cluster_roles
+                .iter()
+                .flatten()
+                .filter(granted)
+                .map(|role| Permission::Cluster(role.clone())),
+        );
+        permissions.extend(
+            self.bdb_roles
+                .iter()
+                .flatten()
+                .map(|binding| Permission::Database {
+                    bdb_uid: binding.bdb_uid,
+                    role: binding.role.clone(),
+                    redis_acl_uid: binding.redis_acl_uid,
+                }),
+        );
+
+        let mut effective: Vec<Permission> = Vec::with_capacity(permissions.len());
+        for permission in permissions {
+            if !effective.contains(&permission) {
+                effective.push(permission);
+            }
+        }
+        effective
+    }
+}
+
+/// A single permission granted by a role
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// Cluster management level (e.g., "admin", "db_member", "cluster_viewer")
+    Management(String),
+    /// Data access level applied to all databases
+    DataAccess(String),
+    /// Additional cluster-wide role
+    Cluster(String),
+    /// Role on one database, optionally bound to a Redis ACL
+    Database {
+        bdb_uid: u32,
+        role: String,
+        redis_acl_uid: Option<u32>,
+    },
+}
+
+impl Permission {
+    /// The database this permission is limited to, if any
+    pub fn bdb_uid(&self) -> Option<u32> {
+        match self {
+            Permission::Database { bdb_uid, .. } => Some(*bdb_uid),
+            _ => None,
+        }
+    }
+}
+
 /// Database-specific role permissions
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct BdbRole {
@@ -0,0 +1,85 @@
+//! Lenient deserializers for fields that proxies and older clusters encode inconsistently
+
+use serde::Deserializer;
+use serde::de::{self, Visitor};
+use std::fmt;
+
+/// Deserialize a `u64` sent as a JSON number, a whole float (`8.589934592e9`),
+/// or a numeric string (`"8589934592"`)
+pub(crate) fn flexible_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FlexibleU64Visitor)
+}
+
+/// Like [`flexible_u64`], for optional fields; `null` and missing fields are `None`
+///
+/// Use together with `#[serde(default)]` so a missing field is accepted.
+pub(crate) fn flexible_u64_opt<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionalFlexibleU64Visitor)
+}
+
+#[derive(Clone, Copy)]
+struct FlexibleU64Visitor;
+
+impl<'de> Visitor<'de> for FlexibleU64Visitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative integer, as a number or a string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<u64, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<u64, E> {
+        u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<u64, E> {
+        // 2^64 is exactly representable; anything at or above it does not fit
+        if v.is_finite() && v >= 0.0 && v.fract() == 0.0 && v < 18_446_744_073_709_551_616.0 {
+            Ok(v as u64)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<u64, E> {
+        let v = v.trim();
+        match v.parse::<u64>() {
+            Ok(n) => Ok(n),
+            Err(_) => v
+                .parse::<f64>()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+                .and_then(|f| FlexibleU64Visitor.visit_f64(f)),
+        }
+    }
+}
+
+struct OptionalFlexibleU64Visitor;
+
+impl<'de> Visitor<'de> for OptionalFlexibleU64Visitor {
+    type Value = Option<u64>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("null or a non-negative integer, as a number or a string")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<u64>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<u64>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<u64>, D::Error> {
+        flexible_u64(deserializer).map(Some)
+    }
+}
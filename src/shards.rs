@@ -6,7 +6,7 @@
 //! - Monitor status and metrics
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -29,6 +29,7 @@ pub struct Shard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slots: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "crate::serde_helpers::flexible_u64_opt")]
     pub used_memory: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub backup_progress: Option<f64>,
@@ -120,17 +121,34 @@ impl ShardHandler {
         self.client.post("/v1/shards/actions/migrate", &body).await
     }
 
-    /// Per-shard failover - POST /v1/shards/{uid}/actions/failover
-    pub async fn failover(&self, uid: &str, body: ShardActionRequest) -> Result<Action> {
+    /// Fail over a shard to its replica - POST /v1/shards/{uid}/actions/failover
+    pub async fn failover(&self, uid: &str) -> Result<Action> {
         self.client
-            .post(&format!("/v1/shards/{}/actions/failover", uid), &body)
+            .post(
+                &format!("/v1/shards/{}/actions/failover", uid),
+                &serde_json::json!({}),
+            )
             .await
     }
 
-    /// Per-shard migrate - POST /v1/shards/{uid}/actions/migrate
-    pub async fn migrate(&self, uid: &str, body: ShardActionRequest) -> Result<Action> {
+    /// Migrate a shard to another node - POST /v1/shards/{uid}/actions/migrate
+    ///
+    /// Returns [`RestError::ValidationError`] without issuing the migration if
+    /// the shard already lives on `target_node_uid`.
+    pub async fn migrate(&self, uid: &str, target_node_uid: u32) -> Result<Action> {
+        let shard = self.get(uid).await?;
+        if shard.node_uid == target_node_uid.to_string() {
+            return Err(RestError::ValidationError(format!(
+                "Shard {} is already on node {}",
+                uid, target_node_uid
+            )));
+        }
+
         self.client
-            .post(&format!("/v1/shards/{}/actions/migrate", uid), &body)
+            .post(
+                &format!("/v1/shards/{}/actions/migrate", uid),
+                &serde_json::json!({ "target_node_uid": target_node_uid }),
+            )
             .await
     }
 }
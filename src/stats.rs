@@ -54,9 +54,13 @@
 //! # }
 //! ```
 
+use crate::bdb::BdbHandler;
 use crate::client::RestClient;
 use crate::error::Result;
+use crate::shards::Shard;
+use futures::future::try_join_all;
 use futures::stream::Stream;
+use futures::try_join;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::pin::Pin;
@@ -64,7 +68,7 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 /// Stats query parameters
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StatsQuery {
     /// Time interval for aggregation ("1min", "5min", "1hour", "1day")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,6 +115,70 @@ pub struct LastStatsResponse {
     pub metrics: Value,
 }
 
+/// Cluster-wide metrics from the last stats interval, with the common metrics named
+///
+/// Built from a [`LastStatsResponse`] by [`StatsHandler::cluster_last_typed`].
+/// Metrics the cluster reports as numeric strings are parsed; any metric not
+/// named here stays in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClusterMetricsSnapshot {
+    /// Start time of the stats interval
+    pub stime: Option<String>,
+    /// End time of the stats interval
+    pub etime: Option<String>,
+    /// Interval duration (e.g., "5min", "1hour")
+    pub interval: Option<String>,
+    /// CPU utilization across the cluster, in percent
+    pub cpu_usage: Option<f64>,
+    /// Memory utilization across the cluster, in percent
+    pub memory_usage: Option<f64>,
+    /// Incoming network traffic, in bytes per second (`ingress_bytes` on some versions)
+    pub network_in: Option<f64>,
+    /// Outgoing network traffic, in bytes per second (`egress_bytes` on some versions)
+    pub network_out: Option<f64>,
+    /// Requests per second
+    pub total_req: Option<f64>,
+    /// Every other metric, by name
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+impl From<LastStatsResponse> for ClusterMetricsSnapshot {
+    fn from(response: LastStatsResponse) -> Self {
+        let mut metrics = match response.metrics {
+            Value::Object(metrics) => metrics,
+            _ => serde_json::Map::new(),
+        };
+        let mut take = |names: &[&str]| {
+            names
+                .iter()
+                .filter_map(|name| metrics.remove(*name))
+                .filter_map(|value| match value {
+                    Value::Number(n) => n.as_f64(),
+                    Value::String(s) => s.trim().parse().ok(),
+                    _ => None,
+                })
+                .next()
+        };
+        let cpu_usage = take(&["cpu_usage"]);
+        let memory_usage = take(&["memory_usage"]);
+        let network_in = take(&["network_in", "ingress_bytes"]);
+        let network_out = take(&["network_out", "egress_bytes"]);
+        let total_req = take(&["total_req"]);
+        ClusterMetricsSnapshot {
+            stime: response.stime,
+            etime: response.etime,
+            interval: response.interval,
+            cpu_usage,
+            memory_usage,
+            network_in,
+            network_out,
+            total_req,
+            extra: metrics,
+        }
+    }
+}
+
 /// Aggregated stats response for multiple resources
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AggregatedStatsResponse {
@@ -127,6 +195,57 @@ pub struct ResourceStats {
     pub intervals: Vec<StatsInterval>,
 }
 
+/// A database's stats together with the stats of each of its shards
+///
+/// Returned by [`StatsHandler::database_with_shards`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseShardStats {
+    /// Database UID
+    pub bdb_uid: u32,
+    /// Stats of the database as a whole
+    pub database: StatsResponse,
+    /// Stats of each shard, in the order the database lists its shards
+    pub shards: Vec<ShardStatsEntry>,
+}
+
+/// One shard of a [`DatabaseShardStats`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardStatsEntry {
+    /// The shard, as listed by the database
+    pub shard: Shard,
+    /// Stats of the shard
+    pub stats: StatsResponse,
+}
+
+impl StatsResponse {
+    /// Value of `metric` in the most recent interval that reports it
+    ///
+    /// Numeric strings are parsed, as some versions report metrics that way.
+    pub fn latest(&self, metric: &str) -> Option<f64> {
+        self.intervals
+            .iter()
+            .rev()
+            .find_map(|interval| match interval.metrics.get(metric)? {
+                Value::Number(n) => n.as_f64(),
+                Value::String(s) => s.trim().parse().ok(),
+                _ => None,
+            })
+    }
+}
+
+impl DatabaseShardStats {
+    /// The shard with the highest latest value of `metric`, e.g. `"total_req"`
+    ///
+    /// Shards that don't report the metric are ignored.
+    pub fn hottest_shard(&self, metric: &str) -> Option<&ShardStatsEntry> {
+        self.shards
+            .iter()
+            .filter_map(|entry| Some((entry, entry.stats.latest(metric)?)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entry, _)| entry)
+    }
+}
+
 /// Stats handler for retrieving metrics
 pub struct StatsHandler {
     client: RestClient,
@@ -154,6 +273,11 @@ impl StatsHandler {
         self.client.get("/v1/cluster/stats/last").await
     }
 
+    /// Get cluster stats for last interval with the common metrics as named fields
+    pub async fn cluster_last_typed(&self) -> Result<ClusterMetricsSnapshot> {
+        self.cluster_last().await.map(ClusterMetricsSnapshot::from)
+    }
+
     // raw variant removed: use cluster_last()
 
     /// Get node stats
@@ -290,6 +414,50 @@ impl StatsHandler {
 
     // raw variant removed: use shards()
 
+    /// Get database stats broken down by shard
+    ///
+    /// Lists the database's shards, then fetches the database's stats and every
+    /// shard's stats concurrently with the same `query`.
+    pub async fn database_with_shards(
+        &self,
+        uid: u32,
+        query: Option<StatsQuery>,
+    ) -> Result<DatabaseShardStats> {
+        let shards: Vec<Shard> =
+            serde_json::from_value(BdbHandler::new(self.client.clone()).shards(uid).await?)?;
+        let query_str = query
+            .as_ref()
+            .map(|q| serde_urlencoded::to_string(q).unwrap_or_default());
+        let with_query = |path: String| match &query_str {
+            Some(q) => format!("{}?{}", path, q),
+            None => path,
+        };
+
+        let database_path = with_query(format!("/v1/bdbs/{}/stats", uid));
+        let shard_paths: Vec<String> = shards
+            .iter()
+            .map(|shard| with_query(format!("/v1/shards/{}/stats", shard.uid)))
+            .collect();
+        let (database, shard_stats) = try_join!(
+            self.client.get::<StatsResponse>(&database_path),
+            try_join_all(
+                shard_paths
+                    .iter()
+                    .map(|path| self.client.get::<StatsResponse>(path))
+            ),
+        )?;
+
+        Ok(DatabaseShardStats {
+            bdb_uid: uid,
+            database,
+            shards: shards
+                .into_iter()
+                .zip(shard_stats)
+                .map(|(shard, stats)| ShardStatsEntry { shard, stats })
+                .collect(),
+        })
+    }
+
     /// Stream cluster stats in real-time by polling
     ///
     /// # Arguments
@@ -5,8 +5,11 @@
 //! - Manage suffix rules
 //! - Query suffix usage
 
+use crate::bdb::DatabaseInfo;
 use crate::client::RestClient;
 use crate::error::Result;
+use crate::nodes::Node;
+use futures::try_join;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
@@ -45,6 +48,45 @@ pub struct CreateSuffixRequest {
     pub use_external_addr: Option<bool>,
 }
 
+/// Propagation status of a DNS suffix, from [`SuffixesHandler::verify`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuffixStatus {
+    /// Suffix name
+    pub name: String,
+    /// The DNS suffix string
+    pub dns_suffix: Option<String>,
+    /// Whether the suffix is part of the cluster's suffix configuration
+    pub cluster_registered: bool,
+    /// Readiness of each node to serve endpoints under the suffix
+    pub nodes: Vec<NodeSuffixReadiness>,
+}
+
+impl SuffixStatus {
+    /// True if the cluster has the suffix and every node can serve it
+    pub fn is_ready(&self) -> bool {
+        self.cluster_registered && self.nodes.iter().all(|node| node.ready)
+    }
+
+    /// Nodes that cannot serve the suffix yet
+    pub fn pending_nodes(&self) -> Vec<&NodeSuffixReadiness> {
+        self.nodes.iter().filter(|node| !node.ready).collect()
+    }
+}
+
+/// Whether a single node can serve endpoints under a suffix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSuffixReadiness {
+    /// Node UID
+    pub node_uid: u32,
+    /// Address the suffix's DNS records point at for this node
+    pub address: Option<String>,
+    /// True if the node is active and has the address the suffix uses
+    pub ready: bool,
+    /// Why the node is not ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 /// Suffixes handler
 pub struct SuffixesHandler {
     client: RestClient,
@@ -82,8 +124,89 @@ impl SuffixesHandler {
         self.client.delete(&format!("/v1/suffix/{}", name)).await
     }
 
+    /// List all DNS suffixes with the UIDs of the databases using each one
+    ///
+    /// Cross-references every database's `dns_suffixes`, which may name a
+    /// suffix or give its DNS string. Check for an empty UID list before
+    /// deleting a suffix, so live database endpoints keep resolving.
+    pub async fn list_with_usage(&self) -> Result<Vec<(Suffix, Vec<u32>)>> {
+        let (suffixes, databases) = try_join!(
+            self.list(),
+            self.client.get::<Vec<DatabaseInfo>>("/v1/bdbs")
+        )?;
+        Ok(suffixes
+            .into_iter()
+            .map(|suffix| {
+                let uids = databases
+                    .iter()
+                    .filter(|db| {
+                        db.dns_suffixes.iter().flatten().any(|used| {
+                            *used == suffix.name || suffix.dns_suffix.as_ref() == Some(used)
+                        })
+                    })
+                    .map(|db| db.uid)
+                    .collect();
+                (suffix, uids)
+            })
+            .collect())
+    }
+
     /// Get cluster DNS suffixes configuration
     pub async fn cluster_suffixes(&self) -> Result<Vec<Suffix>> {
         self.client.get("/v1/cluster/suffixes").await
     }
+
+    /// Check whether a suffix has propagated to the cluster's frontends
+    ///
+    /// Confirms the suffix is part of the cluster's suffix configuration and
+    /// that each node is active with the address type (internal or external)
+    /// the suffix publishes.
+    pub async fn verify(&self, name: &str) -> Result<SuffixStatus> {
+        let suffix = self.get(name).await?;
+        let cluster_registered = self
+            .cluster_suffixes()
+            .await?
+            .iter()
+            .any(|registered| registered.name == suffix.name);
+        let nodes: Vec<Node> = self.client.get("/v1/nodes").await?;
+
+        let external = suffix.use_external_addr.unwrap_or(false);
+        let nodes = nodes
+            .into_iter()
+            .map(|node| {
+                let address = if external {
+                    node.external_addr
+                        .as_ref()
+                        .and_then(|addrs| addrs.first())
+                        .cloned()
+                } else {
+                    node.addr.clone()
+                };
+                let reason = if node.status != "active" {
+                    Some(format!("node status is {}", node.status))
+                } else if address.is_none() {
+                    Some(if external {
+                        "node has no external address".to_string()
+                    } else {
+                        "node has no internal address".to_string()
+                    })
+                } else {
+                    None
+                };
+                NodeSuffixReadiness {
+                    node_uid: node.uid,
+                    address,
+                    ready: reason.is_none(),
+                    reason,
+                }
+            })
+            .collect();
+
+        Ok(SuffixStatus {
+            name: suffix.name,
+            dns_suffix: suffix.dns_suffix,
+            cluster_registered,
+            nodes,
+        })
+    }
 }
@@ -498,6 +498,250 @@ impl AlertFixture {
     }
 }
 
+/// Builder for Active-Active (CRDB) fixtures
+///
+/// Defaults to an active database with two participating instances.
+///
+/// # Example
+///
+/// ```
+/// use redis_enterprise::testing::fixtures::CrdbFixture;
+///
+/// let crdb = CrdbFixture::new("crdb-guid-1", "global-cache")
+///     .clusters(vec!["us-east.example.com", "eu-west.example.com", "ap-south.example.com"])
+///     .encryption(true)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CrdbFixture {
+    guid: String,
+    name: String,
+    status: String,
+    memory_size: u64,
+    clusters: Vec<String>,
+    instance_status: String,
+    encryption: bool,
+    data_persistence: String,
+    eviction_policy: Option<String>,
+}
+
+impl CrdbFixture {
+    /// Create a new CRDB fixture with required fields
+    pub fn new(guid: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            guid: guid.into(),
+            name: name.into(),
+            status: "active".to_string(),
+            memory_size: 1024 * 1024 * 1024, // 1GB default
+            clusters: vec![
+                "cluster1.example.com".to_string(),
+                "cluster2.example.com".to_string(),
+            ],
+            instance_status: "active".to_string(),
+            encryption: false,
+            data_persistence: "disabled".to_string(),
+            eviction_policy: None,
+        }
+    }
+
+    /// Set the CRDB status
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Set memory size in bytes
+    pub fn memory_size(mut self, size: u64) -> Self {
+        self.memory_size = size;
+        self
+    }
+
+    /// Set the participating clusters, one instance per cluster
+    pub fn clusters(mut self, clusters: Vec<impl Into<String>>) -> Self {
+        self.clusters = clusters.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the status reported by every instance
+    pub fn instance_status(mut self, status: impl Into<String>) -> Self {
+        self.instance_status = status.into();
+        self
+    }
+
+    /// Enable or disable communication encryption
+    pub fn encryption(mut self, enabled: bool) -> Self {
+        self.encryption = enabled;
+        self
+    }
+
+    /// Set persistence mode
+    pub fn data_persistence(mut self, mode: impl Into<String>) -> Self {
+        self.data_persistence = mode.into();
+        self
+    }
+
+    /// Set eviction policy
+    pub fn eviction_policy(mut self, policy: impl Into<String>) -> Self {
+        self.eviction_policy = Some(policy.into());
+        self
+    }
+
+    /// Build the JSON fixture
+    pub fn build(self) -> Value {
+        let instances: Vec<Value> = self
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(i, cluster)| {
+                json!({
+                    "id": i + 1,
+                    "cluster": cluster,
+                    "cluster_name": cluster.split('.').next().unwrap_or(cluster),
+                    "status": self.instance_status,
+                    "endpoints": [format!("redis-12000.{}:12000", cluster)]
+                })
+            })
+            .collect();
+
+        let mut obj = json!({
+            "guid": self.guid,
+            "name": self.name,
+            "status": self.status,
+            "memory_size": self.memory_size,
+            "instances": instances,
+            "encryption": self.encryption,
+            "data_persistence": self.data_persistence
+        });
+
+        if let Some(eviction_policy) = self.eviction_policy {
+            obj["eviction_policy"] = json!(eviction_policy);
+        }
+
+        obj
+    }
+}
+
+/// Builder for migration fixtures
+///
+/// Defaults to a syncing migration from an external Redis endpoint into database 1.
+///
+/// # Example
+///
+/// ```
+/// use redis_enterprise::testing::fixtures::MigrationFixture;
+///
+/// let migration = MigrationFixture::new("mig-1")
+///     .status("in-sync")
+///     .progress(1.0)
+///     .source("legacy.example.com", 6380)
+///     .target_bdb(3)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MigrationFixture {
+    migration_id: String,
+    status: String,
+    progress: Option<f32>,
+    source_host: String,
+    source_port: u16,
+    target_bdb_uid: u32,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    error: Option<String>,
+}
+
+impl MigrationFixture {
+    /// Create a new migration fixture
+    pub fn new(migration_id: impl Into<String>) -> Self {
+        Self {
+            migration_id: migration_id.into(),
+            status: "syncing".to_string(),
+            progress: Some(0.0),
+            source_host: "source.example.com".to_string(),
+            source_port: 6379,
+            target_bdb_uid: 1,
+            start_time: Some("2024-01-01T00:00:00Z".to_string()),
+            end_time: None,
+            error: None,
+        }
+    }
+
+    /// Set the sync status (e.g., "syncing", "in-sync", "out-of-sync")
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    /// Set progress as a fraction (0.0-1.0)
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Set the source endpoint host and port
+    pub fn source(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.source_host = host.into();
+        self.source_port = port;
+        self
+    }
+
+    /// Set the target database UID
+    pub fn target_bdb(mut self, uid: u32) -> Self {
+        self.target_bdb_uid = uid;
+        self
+    }
+
+    /// Set the start time (ISO 8601 format)
+    pub fn start_time(mut self, time: impl Into<String>) -> Self {
+        self.start_time = Some(time.into());
+        self
+    }
+
+    /// Set the end time (ISO 8601 format)
+    pub fn end_time(mut self, time: impl Into<String>) -> Self {
+        self.end_time = Some(time.into());
+        self
+    }
+
+    /// Set an error message
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Build the JSON fixture
+    pub fn build(self) -> Value {
+        let mut obj = json!({
+            "migration_id": self.migration_id,
+            "source": {
+                "endpoint_type": "redis",
+                "host": self.source_host,
+                "port": self.source_port
+            },
+            "target": {
+                "endpoint_type": "cluster",
+                "bdb_uid": self.target_bdb_uid
+            },
+            "status": self.status
+        });
+
+        if let Some(progress) = self.progress {
+            obj["progress"] = json!(progress);
+        }
+        if let Some(start_time) = self.start_time {
+            obj["start_time"] = json!(start_time);
+        }
+        if let Some(end_time) = self.end_time {
+            obj["end_time"] = json!(end_time);
+        }
+        if let Some(error) = self.error {
+            obj["error"] = json!(error);
+        }
+
+        obj
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -580,4 +824,60 @@ mod tests {
         assert_eq!(alert["description"], "Memory usage critical");
         assert_eq!(alert["threshold"], "90");
     }
+
+    #[test]
+    fn test_crdb_fixture_defaults() {
+        let crdb = CrdbFixture::new("guid-1", "global").build();
+        assert_eq!(crdb["guid"], "guid-1");
+        assert_eq!(crdb["status"], "active");
+        assert_eq!(crdb["instances"].as_array().unwrap().len(), 2);
+        assert_eq!(crdb["instances"][0]["id"], 1);
+        assert_eq!(crdb["instances"][1]["cluster"], "cluster2.example.com");
+
+        let parsed: crate::Crdb = serde_json::from_value(crdb).unwrap();
+        assert_eq!(parsed.instances.len(), 2);
+        assert_eq!(
+            parsed.instances[0].cluster_name.as_deref(),
+            Some("cluster1")
+        );
+    }
+
+    #[test]
+    fn test_crdb_fixture_customized() {
+        let crdb = CrdbFixture::new("guid-2", "global")
+            .status("pending")
+            .clusters(vec!["a.example.com", "b.example.com", "c.example.com"])
+            .instance_status("syncing")
+            .encryption(true)
+            .eviction_policy("allkeys-lru")
+            .build();
+
+        assert_eq!(crdb["status"], "pending");
+        assert_eq!(crdb["instances"].as_array().unwrap().len(), 3);
+        assert_eq!(crdb["instances"][2]["status"], "syncing");
+        assert_eq!(crdb["encryption"], true);
+        assert_eq!(crdb["eviction_policy"], "allkeys-lru");
+    }
+
+    #[test]
+    fn test_migration_fixture() {
+        let migration = MigrationFixture::new("mig-1")
+            .status("in-sync")
+            .progress(1.0)
+            .source("legacy.example.com", 6380)
+            .target_bdb(3)
+            .end_time("2024-01-01T01:00:00Z")
+            .build();
+
+        assert_eq!(migration["source"]["host"], "legacy.example.com");
+        assert_eq!(migration["target"]["bdb_uid"], 3);
+
+        let parsed: crate::Migration = serde_json::from_value(migration).unwrap();
+        assert_eq!(parsed.migration_id, "mig-1");
+        assert_eq!(parsed.status, "in-sync");
+        assert_eq!(parsed.progress, Some(1.0));
+        assert_eq!(parsed.source.port, Some(6380));
+        assert_eq!(parsed.end_time.as_deref(), Some("2024-01-01T01:00:00Z"));
+        assert!(parsed.error.is_none());
+    }
 }
@@ -57,7 +57,29 @@
 //!     let client = server.client();
 //!     let result = client.databases().get(999).await;
 //!
-//!     assert!(matches!(result, Err(RestError::NotFound)));
+//!     assert!(matches!(result, Err(RestError::NotFound { .. })));
+//! }
+//! ```
+//!
+//! # Stateful Databases
+//!
+//! For create-then-get flows, enable the in-memory database store:
+//!
+//! ```ignore
+//! use redis_enterprise::testing::MockEnterpriseServer;
+//! use redis_enterprise::CreateDatabaseRequest;
+//!
+//! #[tokio::test]
+//! async fn test_database_lifecycle() {
+//!     let server = MockEnterpriseServer::start().await.with_stateful_databases().await;
+//!     let client = server.client();
+//!
+//!     let request = CreateDatabaseRequest::builder().name("cache").build();
+//!     let db = client.databases().create(request).await.unwrap();
+//!     assert_eq!(client.databases().get(db.uid).await.unwrap().name, "cache");
+//!
+//!     client.databases().delete(db.uid).await.unwrap();
+//!     assert!(client.databases().get(db.uid).await.is_err());
 //! }
 //! ```
 //!
@@ -95,8 +117,8 @@ pub mod server;
 
 // Re-export main types for convenience
 pub use fixtures::{
-    ActionFixture, AlertFixture, ClusterFixture, DatabaseFixture, LicenseFixture, NodeFixture,
-    UserFixture,
+    ActionFixture, AlertFixture, ClusterFixture, CrdbFixture, DatabaseFixture, LicenseFixture,
+    MigrationFixture, NodeFixture, UserFixture,
 };
 pub use server::MockEnterpriseServer;
 
@@ -15,7 +15,25 @@
 //! let not_found = responses::not_found("Database not found");
 //! let unauthorized = responses::unauthorized();
 //! let conflict = responses::conflict("Resource already exists");
+//!
+//! // Malformed responses
+//! let truncated = responses::malformed_json();
+//! let proxy_page = responses::html_error_page();
 //! ```
+//!
+//! # Malformed Responses
+//!
+//! [`malformed_json`] and [`html_error_page`] simulate bodies a well-behaved
+//! cluster would never send, so consumers can verify their handling of bad
+//! payloads:
+//!
+//! - [`malformed_json`] returns a 200 with a truncated JSON body. Typed calls
+//!   fail with [`RestError::ParseError`](crate::RestError::ParseError), which
+//!   carries the field path reported by `serde_path_to_error`.
+//! - [`html_error_page`] returns a 500 with an HTML body, like a misconfigured
+//!   load balancer or reverse proxy in front of the cluster. Calls fail with
+//!   [`RestError::ServerError`](crate::RestError::ServerError) containing the
+//!   raw HTML rather than a JSON error message.
 
 use serde_json::{Value, json};
 use std::time::Duration;
@@ -53,6 +71,15 @@ pub fn unauthorized() -> ResponseTemplate {
     }))
 }
 
+/// Create a 403 Forbidden response
+pub fn forbidden(message: impl Into<String>) -> ResponseTemplate {
+    let message = message.into();
+    ResponseTemplate::new(403).set_body_json(json!({
+        "error": message,
+        "code": 403
+    }))
+}
+
 /// Create a 404 Not Found response
 pub fn not_found(message: impl Into<String>) -> ResponseTemplate {
     let message = message.into();
@@ -111,6 +138,24 @@ pub fn error(code: u16, message: impl Into<String>) -> ResponseTemplate {
     }))
 }
 
+/// Create a 200 OK response whose JSON body is truncated mid-object
+pub fn malformed_json() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(
+        r#"{"uid": 1, "name": "truncated-db", "memory_size": 10737"#,
+        "application/json",
+    )
+}
+
+/// Create a 500 response with an HTML error page, as returned by a misconfigured proxy
+pub fn html_error_page() -> ResponseTemplate {
+    ResponseTemplate::new(500).set_body_raw(
+        "<html>\n<head><title>500 Internal Server Error</title></head>\n\
+         <body>\n<center><h1>500 Internal Server Error</h1></center>\n\
+         <hr><center>nginx</center>\n</body>\n</html>\n",
+        "text/html",
+    )
+}
+
 /// Create a response with a delay (for testing timeouts)
 pub fn delayed(response: ResponseTemplate, delay: Duration) -> ResponseTemplate {
     response.set_delay(delay)
@@ -129,9 +174,16 @@ mod tests {
     fn test_error_responses() {
         let _not_found = not_found("Resource not found");
         let _unauthorized = unauthorized();
+        let _forbidden = forbidden("Insufficient permissions");
         let _conflict = conflict("Already exists");
         let _rate_limited = rate_limited(Some(Duration::from_secs(60)));
         let _server_error = server_error("Internal error");
         let _cluster_busy = cluster_busy();
     }
+
+    #[test]
+    fn test_malformed_responses() {
+        let _malformed = malformed_json();
+        let _html = html_error_page();
+    }
 }
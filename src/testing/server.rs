@@ -28,12 +28,16 @@
 
 use crate::EnterpriseClient;
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use wiremock::matchers::{method, path, path_regex};
-use wiremock::{Mock, MockServer, ResponseTemplate};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
 
 /// A wrapper around wiremock's MockServer configured for Redis Enterprise API testing
 pub struct MockEnterpriseServer {
     server: MockServer,
+    databases: Arc<Mutex<BTreeMap<u32, Value>>>,
 }
 
 impl MockEnterpriseServer {
@@ -41,9 +45,48 @@ impl MockEnterpriseServer {
     pub async fn start() -> Self {
         Self {
             server: MockServer::start().await,
+            databases: Arc::new(Mutex::new(BTreeMap::new())),
         }
     }
 
+    /// Enable stateful database CRUD backed by an in-memory store
+    ///
+    /// Once enabled, `POST /v1/bdbs` assigns a uid and stores the database,
+    /// `GET /v1/bdbs` and `GET /v1/bdbs/{uid}` return stored databases,
+    /// `PUT /v1/bdbs/{uid}` merges the request body into the stored database,
+    /// and `DELETE /v1/bdbs/{uid}` removes it. Unknown uids return 404.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let server = MockEnterpriseServer::start().await.with_stateful_databases().await;
+    /// let client = server.client();
+    ///
+    /// let request = CreateDatabaseRequest::builder().name("cache").build();
+    /// let created = client.databases().create(request).await.unwrap();
+    /// let fetched = client.databases().get(created.uid).await.unwrap();
+    /// assert_eq!(fetched.name, "cache");
+    /// ```
+    pub async fn with_stateful_databases(self) -> Self {
+        Mock::given(path_regex(r"^/v1/bdbs(/\d+)?$"))
+            .respond_with(StatefulDatabases {
+                databases: self.databases.clone(),
+            })
+            .mount(&self.server)
+            .await;
+        self
+    }
+
+    /// Get a snapshot of the databases held by the stateful store
+    pub fn stored_databases(&self) -> Vec<Value> {
+        self.databases
+            .lock()
+            .expect("database store poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+
     /// Get the base URI of the mock server
     pub fn uri(&self) -> String {
         self.server.uri()
@@ -283,6 +326,142 @@ impl MockEnterpriseServer {
             .mount(&self.server)
             .await;
     }
+
+    /// Mount a flaky endpoint that fails `fail_count` times before succeeding
+    ///
+    /// The first `fail_count` requests matching `http_method` and `path_str` receive
+    /// an error response with `error_status` (built with [`responses::error`]);
+    /// every request after that receives a 200 response with `success_body`.
+    /// Useful for exercising retry, backoff, and circuit-breaker logic.
+    ///
+    /// [`responses::error`]: super::responses::error
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let server = MockEnterpriseServer::start().await;
+    /// server
+    ///     .mock_path_flaky("GET", "/v1/cluster", 2, 503, ClusterFixture::new("c").build())
+    ///     .await;
+    ///
+    /// let client = server.client();
+    /// assert!(client.cluster().info().await.is_err());
+    /// assert!(client.cluster().info().await.is_err());
+    /// assert!(client.cluster().info().await.is_ok());
+    /// ```
+    pub async fn mock_path_flaky(
+        &self,
+        http_method: &str,
+        path_str: &str,
+        fail_count: usize,
+        error_status: u16,
+        success_body: Value,
+    ) {
+        Mock::given(method(http_method))
+            .and(path(path_str))
+            .respond_with(FlakyResponder {
+                calls: AtomicUsize::new(0),
+                fail_count,
+                error_status,
+                success_body,
+            })
+            .mount(&self.server)
+            .await;
+    }
+}
+
+/// Responder backing [`MockEnterpriseServer::mock_path_flaky`]
+struct FlakyResponder {
+    calls: AtomicUsize,
+    fail_count: usize,
+    error_status: u16,
+    success_body: Value,
+}
+
+impl Respond for FlakyResponder {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if call < self.fail_count {
+            super::responses::error(
+                self.error_status,
+                format!("Injected failure {} of {}", call + 1, self.fail_count),
+            )
+        } else {
+            super::responses::success(self.success_body.clone())
+        }
+    }
+}
+
+/// Responder backing [`MockEnterpriseServer::with_stateful_databases`]
+struct StatefulDatabases {
+    databases: Arc<Mutex<BTreeMap<u32, Value>>>,
+}
+
+impl Respond for StatefulDatabases {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let uid = request
+            .url
+            .path()
+            .strip_prefix("/v1/bdbs/")
+            .and_then(|uid| uid.parse::<u32>().ok());
+        let mut databases = self.databases.lock().expect("database store poisoned");
+
+        match (request.method.as_str(), uid) {
+            ("GET", None) => {
+                let list: Vec<Value> = databases.values().cloned().collect();
+                super::responses::success(list)
+            }
+            ("POST", None) => {
+                let body: Value = match request.body_json() {
+                    Ok(body) => body,
+                    Err(e) => return super::responses::bad_request(e.to_string()),
+                };
+                let uid = body["uid"]
+                    .as_u64()
+                    .map(|uid| uid as u32)
+                    .unwrap_or_else(|| databases.keys().next_back().map_or(1, |max| max + 1));
+                if databases.contains_key(&uid) {
+                    return super::responses::conflict(format!("Database {} already exists", uid));
+                }
+                let name = body["name"].as_str().unwrap_or("db").to_string();
+                let mut database = super::fixtures::DatabaseFixture::new(uid, name).build();
+                merge(&mut database, body);
+                database["uid"] = uid.into();
+                databases.insert(uid, database.clone());
+                super::responses::created(database)
+            }
+            ("GET", Some(uid)) => match databases.get(&uid) {
+                Some(database) => super::responses::success(database.clone()),
+                None => super::responses::not_found(format!("Database {} not found", uid)),
+            },
+            ("PUT", Some(uid)) => {
+                let body: Value = match request.body_json() {
+                    Ok(body) => body,
+                    Err(e) => return super::responses::bad_request(e.to_string()),
+                };
+                match databases.get_mut(&uid) {
+                    Some(database) => {
+                        merge(database, body);
+                        database["uid"] = uid.into();
+                        super::responses::success(database.clone())
+                    }
+                    None => super::responses::not_found(format!("Database {} not found", uid)),
+                }
+            }
+            ("DELETE", Some(uid)) => match databases.remove(&uid) {
+                Some(_) => super::responses::no_content(),
+                None => super::responses::not_found(format!("Database {} not found", uid)),
+            },
+            _ => super::responses::error(405, "Method not allowed"),
+        }
+    }
+}
+
+/// Shallow-merge the fields of `patch` into `target` when both are objects
+fn merge(target: &mut Value, patch: Value) {
+    if let (Some(target), Value::Object(patch)) = (target.as_object_mut(), patch) {
+        target.extend(patch);
+    }
 }
 
 #[cfg(test)]
@@ -432,4 +611,135 @@ mod tests {
         assert_eq!(alerts.len(), 1);
         assert_eq!(alerts[0].name, "bdb_high_latency");
     }
+
+    #[tokio::test]
+    async fn test_stateful_databases_lifecycle() {
+        use crate::CreateDatabaseRequest;
+
+        let server = MockEnterpriseServer::start()
+            .await
+            .with_stateful_databases()
+            .await;
+        let client = server.client();
+
+        assert!(client.databases().list().await.unwrap().is_empty());
+
+        let first = client
+            .databases()
+            .create(
+                CreateDatabaseRequest::builder()
+                    .name("cache")
+                    .memory_size(512 * 1024 * 1024)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let second = client
+            .databases()
+            .create(CreateDatabaseRequest::builder().name("sessions").build())
+            .await
+            .unwrap();
+        assert_eq!(first.uid, 1);
+        assert_eq!(second.uid, 2);
+
+        let fetched = client.databases().get(first.uid).await.unwrap();
+        assert_eq!(fetched.name, "cache");
+        assert_eq!(fetched.memory_size, Some(512 * 1024 * 1024));
+        assert_eq!(fetched.status.as_deref(), Some("active"));
+
+        let updated = client
+            .databases()
+            .update(first.uid, serde_json::json!({"memory_size": 1024}))
+            .await
+            .unwrap();
+        assert_eq!(updated.memory_size, Some(1024));
+        assert_eq!(updated.name, "cache");
+
+        client.databases().delete(first.uid).await.unwrap();
+        let result = client.databases().get(first.uid).await;
+        assert!(matches!(result, Err(crate::RestError::NotFound { .. })));
+
+        let remaining = client.databases().list().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].name, "sessions");
+        assert_eq!(server.stored_databases().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stateful_databases_delete_missing() {
+        let server = MockEnterpriseServer::start()
+            .await
+            .with_stateful_databases()
+            .await;
+        let client = server.client();
+
+        let result = client.databases().delete(42).await;
+        assert!(matches!(
+            result,
+            Err(crate::RestError::ApiError { code: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_path_flaky() {
+        let server = MockEnterpriseServer::start().await;
+        server
+            .mock_path_flaky(
+                "GET",
+                "/v1/cluster",
+                2,
+                503,
+                ClusterFixture::new("flaky-cluster").build(),
+            )
+            .await;
+
+        let client = server.client();
+        for _ in 0..2 {
+            let result = client.cluster().info().await;
+            assert!(matches!(result, Err(crate::RestError::ClusterBusy { .. })));
+        }
+        let info = client.cluster().info().await.unwrap();
+        assert_eq!(info.name, "flaky-cluster");
+        let info = client.cluster().info().await.unwrap();
+        assert_eq!(info.name, "flaky-cluster");
+
+        let requests = server.inner().received_requests().await.unwrap();
+        assert_eq!(requests.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_response() {
+        let server = MockEnterpriseServer::start().await;
+        server
+            .mock_path(
+                "GET",
+                "/v1/bdbs/1",
+                crate::testing::responses::malformed_json(),
+            )
+            .await;
+
+        let client = server.client();
+        let result = client.databases().get(1).await;
+        assert!(matches!(result, Err(crate::RestError::ParseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_html_error_page_response() {
+        let server = MockEnterpriseServer::start().await;
+        server
+            .mock_path(
+                "GET",
+                "/v1/cluster",
+                crate::testing::responses::html_error_page(),
+            )
+            .await;
+
+        let client = server.client();
+        match client.cluster().info().await {
+            Err(crate::RestError::ServerError { message: body, .. }) => {
+                assert!(body.contains("<html>"))
+            }
+            other => panic!("Expected ServerError with HTML body, got {:?}", other),
+        }
+    }
 }
@@ -6,9 +6,10 @@
 //! - Export usage data
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::error::{RestError, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tokio::io::AsyncWrite;
 
 /// Usage report
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +35,166 @@ pub struct UsageReport {
     pub summary: Option<UsageSummary>,
 }
 
+impl UsageReport {
+    /// Flatten the per-database usage into rows, one per database
+    pub fn to_csv_rows(&self) -> Vec<UsageCsvRow> {
+        self.databases
+            .iter()
+            .flatten()
+            .map(|db| UsageCsvRow {
+                report_id: Some(self.report_id.clone()),
+                timestamp: Some(self.timestamp.clone()),
+                bdb_uid: Some(db.bdb_uid),
+                database_name: db.name.clone(),
+                memory_used_avg: Some(db.memory_used_avg),
+                memory_used_peak: Some(db.memory_used_peak),
+                ops_per_sec_avg: Some(db.ops_per_sec_avg),
+                bandwidth_avg: Some(db.bandwidth_avg),
+                shard_count: db.shard_count,
+            })
+            .collect()
+    }
+}
+
+/// One database row of a usage report, as in the CSV export
+///
+/// Columns other than the database name are optional since the CSV export
+/// does not always include them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageCsvRow {
+    /// Usage report the row belongs to
+    pub report_id: Option<String>,
+    /// Timestamp when the report was generated
+    pub timestamp: Option<String>,
+    /// Database unique identifier
+    pub bdb_uid: Option<u32>,
+    /// Name of the database
+    pub database_name: String,
+    /// Average memory usage during the reporting period (bytes)
+    pub memory_used_avg: Option<u64>,
+    /// Peak memory usage during the reporting period (bytes)
+    pub memory_used_peak: Option<u64>,
+    /// Average operations per second
+    pub ops_per_sec_avg: Option<f64>,
+    /// Average bandwidth usage (bytes per second)
+    pub bandwidth_avg: Option<u64>,
+    /// Number of shards in the database
+    pub shard_count: Option<u32>,
+}
+
+impl UsageCsvRow {
+    /// Parse a usage report CSV export into rows
+    ///
+    /// Columns are matched by header name in any order, and unknown columns are
+    /// ignored. A `database_name` (or `name`) column is required.
+    pub fn parse_csv(csv: &str) -> Result<Vec<UsageCsvRow>> {
+        let mut records = parse_csv_records(csv)?.into_iter();
+        let Some(header) = records.next() else {
+            return Ok(Vec::new());
+        };
+        let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.trim()));
+        let name_column = column(&["database_name", "name"]).ok_or_else(|| {
+            RestError::ParseError("Usage CSV has no database_name column".to_string())
+        })?;
+        let columns = [
+            column(&["report_id"]),
+            column(&["timestamp"]),
+            column(&["bdb_uid"]),
+            column(&["memory_used_avg"]),
+            column(&["memory_used_peak"]),
+            column(&["ops_per_sec_avg"]),
+            column(&["bandwidth_avg"]),
+            column(&["shard_count"]),
+        ];
+
+        records
+            .enumerate()
+            .filter(|(_, record)| !record.iter().all(|field| field.is_empty()))
+            .map(|(index, record)| {
+                let line = index + 2;
+                let cell = |column: Option<usize>| {
+                    column
+                        .and_then(|c| record.get(c))
+                        .map(|v| v.trim())
+                        .filter(|v| !v.is_empty())
+                };
+                fn number<T: std::str::FromStr>(
+                    value: Option<&str>,
+                    line: usize,
+                ) -> Result<Option<T>> {
+                    value
+                        .map(|v| {
+                            v.parse::<T>().map_err(|_| {
+                                RestError::ParseError(format!(
+                                    "Invalid number '{}' on usage CSV line {}",
+                                    v, line
+                                ))
+                            })
+                        })
+                        .transpose()
+                }
+                let [
+                    report_id,
+                    timestamp,
+                    bdb_uid,
+                    mem_avg,
+                    mem_peak,
+                    ops,
+                    bandwidth,
+                    shards,
+                ] = columns;
+                Ok(UsageCsvRow {
+                    report_id: cell(report_id).map(str::to_string),
+                    timestamp: cell(timestamp).map(str::to_string),
+                    bdb_uid: number(cell(bdb_uid), line)?,
+                    database_name: cell(Some(name_column)).unwrap_or_default().to_string(),
+                    memory_used_avg: number(cell(mem_avg), line)?,
+                    memory_used_peak: number(cell(mem_peak), line)?,
+                    ops_per_sec_avg: number(cell(ops), line)?,
+                    bandwidth_avg: number(cell(bandwidth), line)?,
+                    shard_count: number(cell(shards), line)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Split CSV text into records, handling quoted fields and `""` escapes
+fn parse_csv_records(csv: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(RestError::ParseError(
+            "Unterminated quoted field in usage CSV".to_string(),
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
 /// Database usage information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseUsage {
@@ -42,12 +203,15 @@ pub struct DatabaseUsage {
     /// Name of the database
     pub name: String,
     /// Average memory usage during the reporting period (bytes)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub memory_used_avg: u64,
     /// Peak memory usage during the reporting period (bytes)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub memory_used_peak: u64,
     /// Average operations per second
     pub ops_per_sec_avg: f64,
     /// Average bandwidth usage (bytes per second)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub bandwidth_avg: u64,
     /// Number of shards in the database
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,10 +226,13 @@ pub struct NodeUsage {
     /// Average CPU usage as a percentage (0.0-1.0)
     pub cpu_usage_avg: f32,
     /// Average memory usage during the reporting period (bytes)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub memory_usage_avg: u64,
     /// Persistent storage usage (bytes)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub persistent_storage_usage: u64,
     /// Ephemeral storage usage (bytes)
+    #[serde(deserialize_with = "crate::serde_helpers::flexible_u64")]
     pub ephemeral_storage_usage: u64,
 }
 
@@ -86,6 +253,13 @@ pub struct UsageSummary {
     pub shard_count: u32,
 }
 
+impl UsageSummary {
+    /// Estimate the cost of the reporting period from total memory at `rate_per_gb`
+    pub fn cost_estimate(&self, rate_per_gb: f64) -> f64 {
+        self.total_memory_gb * rate_per_gb
+    }
+}
+
 /// Usage report configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageReportConfig {
@@ -120,6 +294,14 @@ impl UsageReportHandler {
         self.client.get("/v1/usage_report/latest").await
     }
 
+    /// Get the latest usage report together with its parsed CSV export
+    pub async fn latest_parsed(&self) -> Result<(UsageReport, Vec<UsageCsvRow>)> {
+        let report = self.latest().await?;
+        let csv = self.download_csv(&report.report_id).await?;
+        let rows = UsageCsvRow::parse_csv(&csv)?;
+        Ok((report, rows))
+    }
+
     /// List all usage reports
     pub async fn list(&self) -> Result<Vec<UsageReport>> {
         self.client.get("/v1/usage_report").await
@@ -150,9 +332,22 @@ impl UsageReportHandler {
     }
 
     /// Download usage report as CSV
+    ///
+    /// Buffers the whole report; use [`download_csv_to`](Self::download_csv_to)
+    /// for large reports.
     pub async fn download_csv(&self, report_id: &str) -> Result<String> {
         self.client
             .get_text(&format!("/v1/usage_report/{}/csv", report_id))
             .await
     }
+
+    /// Stream a usage report's CSV to `writer`, returning the number of bytes written
+    pub async fn download_csv_to<W>(&self, report_id: &str, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.client
+            .get_to_writer(&format!("/v1/usage_report/{}/csv", report_id), writer)
+            .await
+    }
 }
@@ -10,7 +10,9 @@
 //! ```
 
 use crate::client::RestClient;
-use crate::error::Result;
+use crate::cluster::{ClusterHandler, ClusterInfo};
+use crate::error::{RestError, Result};
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use typed_builder::TypedBuilder;
@@ -145,6 +147,71 @@ pub struct UpdateUserRequest {
     pub auth_method: Option<String>,
 }
 
+/// Password rules enforced by the cluster, from `password_min_length` and
+/// `password_complexity` in [`ClusterInfo`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Minimum password length
+    pub min_length: Option<u32>,
+    /// Require upper and lower case letters, a digit and a special character,
+    /// no more than three repeated characters, and no user name in the password
+    pub complexity: bool,
+}
+
+impl PasswordPolicy {
+    /// Read the password policy from the cluster configuration
+    pub fn from_cluster(info: &ClusterInfo) -> Self {
+        Self {
+            min_length: info.password_min_length,
+            complexity: info.password_complexity.unwrap_or(false),
+        }
+    }
+
+    /// Check `password` for the user `email`, naming the first rule it breaks
+    pub fn validate(&self, password: &str, email: &str) -> Result<()> {
+        let fail = |rule: &str| Err(RestError::ValidationError(format!("password {}", rule)));
+
+        let length = password.chars().count();
+        if let Some(min_length) = self.min_length
+            && length < min_length as usize
+        {
+            return fail(&format!(
+                "must be at least {} characters long (got {})",
+                min_length, length
+            ));
+        }
+        if !self.complexity {
+            return Ok(());
+        }
+
+        if !password.chars().any(|c| c.is_uppercase()) {
+            return fail("must contain an uppercase letter");
+        }
+        if !password.chars().any(|c| c.is_lowercase()) {
+            return fail("must contain a lowercase letter");
+        }
+        if !password.chars().any(|c| c.is_ascii_digit()) {
+            return fail("must contain a digit");
+        }
+        if password.chars().all(char::is_alphanumeric) {
+            return fail("must contain a special character");
+        }
+        let chars: Vec<char> = password.chars().collect();
+        if chars.windows(4).any(|w| w.iter().all(|&c| c == w[0])) {
+            return fail("must not repeat a character more than three times in a row");
+        }
+        let user = email.split('@').next().unwrap_or_default().to_lowercase();
+        if !user.is_empty() {
+            let lower = password.to_lowercase();
+            let reversed: String = user.chars().rev().collect();
+            if lower.contains(&user) || lower.contains(&reversed) {
+                return fail("must not contain the user name");
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Role information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
@@ -182,6 +249,32 @@ impl UserHandler {
         self.client.post("/v1/users", &request).await
     }
 
+    /// Create several users, at most `concurrency` at a time
+    ///
+    /// Returns one result per request, in the same order as `requests`. A failed
+    /// creation does not stop the others.
+    pub async fn create_many(
+        &self,
+        requests: Vec<CreateUserRequest>,
+        concurrency: usize,
+    ) -> Vec<Result<User>> {
+        stream::iter(requests)
+            .map(|request| self.create(request))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Create a user after checking its password against the cluster password policy
+    ///
+    /// A password that breaks the policy is rejected with a `ValidationError`
+    /// naming the rule, without sending the create request.
+    pub async fn create_checked(&self, request: CreateUserRequest) -> Result<User> {
+        let info = ClusterHandler::new(self.client.clone()).info().await?;
+        PasswordPolicy::from_cluster(&info).validate(&request.password, &request.email)?;
+        self.create(request).await
+    }
+
     /// Update user
     pub async fn update(&self, uid: u32, request: UpdateUserRequest) -> Result<User> {
         self.client
@@ -289,4 +382,27 @@ impl RoleHandler {
     pub async fn get(&self, uid: u32) -> Result<Role> {
         self.client.get(&format!("/v1/roles/{}", uid)).await
     }
+
+    /// Add role `role_uid` to each of `user_uids`, keeping their other roles
+    ///
+    /// Returns one result per user, in the same order as `user_uids`.
+    pub async fn assign_many(&self, role_uid: u32, user_uids: &[u32]) -> Vec<Result<User>> {
+        let users = UserHandler::new(self.client.clone());
+        let users = &users;
+        futures::future::join_all(user_uids.iter().map(|&uid| async move {
+            let user = users.get(uid).await?;
+            let mut role_uids = user.role_uids.clone().unwrap_or_default();
+            if role_uids.contains(&role_uid) {
+                return Ok(user);
+            }
+            role_uids.push(role_uid);
+            users
+                .update(
+                    uid,
+                    UpdateUserRequest::builder().role_uids(role_uids).build(),
+                )
+                .await
+        }))
+        .await
+    }
 }
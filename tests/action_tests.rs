@@ -240,6 +240,59 @@ async fn test_action_cancel() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_action_list_running_and_cancel_all() {
+    let mock_server = MockServer::start().await;
+
+    let mut queued = test_action();
+    queued["action_uid"] = json!("action-queued");
+    queued["status"] = json!("queued");
+    Mock::given(method("GET"))
+        .and(path("/v1/actions"))
+        .respond_with(success_response(json!([
+            test_action(),
+            completed_action(),
+            failed_action(),
+            queued
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/actions/action-123-abc"))
+        .respond_with(no_content_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/actions/action-queued"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error_code": "action_not_cancellable"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = ActionHandler::new(client);
+
+    let running = handler.list_running().await.unwrap();
+    let uids: Vec<&str> = running.iter().map(|a| a.action_uid.as_str()).collect();
+    assert_eq!(uids, vec!["action-123-abc", "action-queued"]);
+    assert!(running.iter().all(|a| a.is_running() && !a.is_terminal()));
+
+    let outcomes = handler.cancel_all_running().await.unwrap();
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].0.action_uid, "action-123-abc");
+    assert!(outcomes[0].1.is_ok());
+    assert_eq!(outcomes[1].0.action_uid, "action-queued");
+    assert!(outcomes[1].1.is_err());
+}
+
 #[tokio::test]
 async fn test_action_cancel_nonexistent() {
     let mock_server = MockServer::start().await;
@@ -291,3 +344,72 @@ async fn test_action_get_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_post_action_tracked_with_retry_then_wait_for() {
+    use redis_enterprise::retry::Backoff;
+    use std::time::Duration;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/actions/optimize_shards_placement"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/actions/optimize_shards_placement"))
+        .respond_with(success_response(json!({"action_uid": "action-123-abc"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/actions/action-123-abc"))
+        .respond_with(success_response(test_action()))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    let mut done = test_action();
+    done["status"] = json!("completed");
+    done["progress"] = json!(100.0);
+    Mock::given(method("GET"))
+        .and(path("/v1/actions/action-123-abc"))
+        .respond_with(success_response(done))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/proxies/1/actions/reload"))
+        .respond_with(no_content_response())
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let uid = client
+        .post_action_tracked_with_retry(
+            "/v1/bdbs/1/actions/optimize_shards_placement",
+            &json!({}),
+            Backoff::fixed(Duration::from_millis(10)).max_retries(3),
+        )
+        .await
+        .unwrap()
+        .expect("action_uid in response");
+    assert_eq!(uid, "action-123-abc");
+
+    let action = ActionHandler::new(client.clone())
+        .wait_for(&uid, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(action.status, "completed");
+
+    let untracked = client
+        .post_action_tracked("/v1/proxies/1/actions/reload", &json!({}))
+        .await
+        .unwrap();
+    assert_eq!(untracked, None);
+}
@@ -1,6 +1,9 @@
 //! Alerts endpoint tests for Redis Enterprise
 
-use redis_enterprise::{AlertHandler, AlertSettings, EnterpriseClient};
+use futures::StreamExt;
+use redis_enterprise::{
+    AlertHandler, AlertSettings, AlertSource, AlertThreshold, EnterpriseClient,
+};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -305,7 +308,7 @@ async fn test_alerts_update_settings() {
 
     let settings = AlertSettings {
         enabled: true,
-        threshold: Some(json!({"value": 85, "unit": "percent"})),
+        threshold: Some(AlertThreshold::Percentage(85.0)),
         email_recipients: Some(vec!["admin@example.com".to_string()]),
         webhook_url: Some("https://webhook.example.com/alerts".to_string()),
     };
@@ -336,7 +339,11 @@ async fn test_alerts_update_settings() {
     assert!(result.is_ok());
     let updated_settings = result.unwrap();
     assert!(updated_settings.enabled);
-    assert!(updated_settings.threshold.is_some());
+    assert_eq!(
+        updated_settings.threshold,
+        Some(AlertThreshold::Percentage(85.0))
+    );
+    assert_eq!(updated_settings.threshold_value(), Some(85.0));
     assert!(updated_settings.email_recipients.is_some());
     assert!(updated_settings.webhook_url.is_some());
 }
@@ -347,7 +354,9 @@ async fn test_alerts_update_settings_invalid() {
 
     let settings = AlertSettings {
         enabled: true,
-        threshold: Some(json!({"value": "invalid", "unit": "percent"})),
+        threshold: Some(AlertThreshold::Raw(
+            json!({"value": "invalid", "unit": "percent"}),
+        )),
         email_recipients: None,
         webhook_url: None,
     };
@@ -421,6 +430,98 @@ async fn test_alerts_clear_nonexistent() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_alerts_acknowledge_many() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/alerts/alert-123"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(no_content_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/alerts/alert-456"))
+        .respond_with(no_content_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/alerts/missing"))
+        .respond_with(error_response(404, "Alert not found"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = AlertHandler::new(client);
+    let uids = vec![
+        "alert-123".to_string(),
+        "missing".to_string(),
+        "alert-456".to_string(),
+    ];
+    let results = handler.acknowledge_many(&uids).await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, "alert-123");
+    assert!(results[0].1.is_ok());
+    assert_eq!(results[1].0, "missing");
+    assert!(results[1].1.is_err());
+    assert_eq!(results[2].0, "alert-456");
+    assert!(results[2].1.is_ok());
+}
+
+#[tokio::test]
+async fn test_alerts_acknowledge_all_for_database() {
+    let mock_server = MockServer::start().await;
+
+    let mut second = test_database_alert();
+    second["uid"] = json!("alert-457");
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/alerts"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!([test_database_alert(), second])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/alerts/alert-456"))
+        .respond_with(no_content_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/alerts/alert-457"))
+        .respond_with(no_content_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = AlertHandler::new(client);
+    let results = handler.acknowledge_all_for_database(1).await.unwrap();
+
+    let uids: Vec<&str> = results.iter().map(|(uid, _)| uid.as_str()).collect();
+    assert_eq!(uids, vec!["alert-456", "alert-457"]);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+}
+
 #[tokio::test]
 async fn test_alerts_clear_all() {
     let mock_server = MockServer::start().await;
@@ -516,3 +617,186 @@ async fn test_alerts_get_settings_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_alert_threshold_shapes() {
+    let parse =
+        |value: serde_json::Value| -> AlertThreshold { serde_json::from_value(value).unwrap() };
+
+    assert_eq!(parse(json!(80)), AlertThreshold::Value(80.0));
+    assert_eq!(parse(json!(2.5)), AlertThreshold::Value(2.5));
+    assert_eq!(
+        parse(json!({"value": 75, "unit": "percent"})),
+        AlertThreshold::Percentage(75.0)
+    );
+    assert_eq!(parse(json!(true)), AlertThreshold::Enabled(true));
+    assert_eq!(
+        parse(json!({"value": 5, "unit": "minutes"})),
+        AlertThreshold::Raw(json!({"value": 5, "unit": "minutes"}))
+    );
+    assert_eq!(
+        parse(json!("unlimited")),
+        AlertThreshold::Raw(json!("unlimited"))
+    );
+
+    // Shapes that would not serialize back unchanged are kept verbatim
+    for value in [json!("80"), json!("90%"), json!(80.0)] {
+        let threshold = parse(value.clone());
+        assert_eq!(threshold, AlertThreshold::Raw(value.clone()));
+        assert_eq!(serde_json::to_value(threshold).unwrap(), value);
+    }
+
+    // Serialization keeps the API's shapes
+    assert_eq!(
+        serde_json::to_value(AlertThreshold::Percentage(75.0)).unwrap(),
+        json!({"value": 75, "unit": "percent"})
+    );
+    assert_eq!(
+        serde_json::to_value(AlertThreshold::Value(2.5)).unwrap(),
+        json!(2.5)
+    );
+}
+
+#[test]
+fn test_alert_settings_threshold_value() {
+    let settings: AlertSettings = serde_json::from_value(json!({
+        "enabled": true,
+        "threshold": "80"
+    }))
+    .unwrap();
+    assert_eq!(settings.threshold_value(), Some(80.0));
+
+    let settings: AlertSettings = serde_json::from_value(json!({
+        "enabled": true,
+        "threshold": "90%"
+    }))
+    .unwrap();
+    assert_eq!(settings.threshold_value(), Some(90.0));
+
+    let settings: AlertSettings = serde_json::from_value(json!({
+        "enabled": true,
+        "threshold": false
+    }))
+    .unwrap();
+    assert_eq!(settings.threshold_value(), None);
+
+    let settings: AlertSettings = serde_json::from_value(json!({"enabled": false})).unwrap();
+    assert_eq!(settings.threshold_value(), None);
+}
+
+#[tokio::test]
+async fn test_alerts_all_sources_stream() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/alerts"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "cluster_license_about_to_expire": {
+                "enabled": true,
+                "state": true,
+                "severity": "WARNING",
+                "change_time": "2024-01-01T00:00:00Z"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes/alerts"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "1": {
+                "node_free_flash": {"enabled": true, "state": false},
+                "node_memory": {"enabled": true, "state": true, "severity": "CRITICAL"}
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/alerts"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!([test_alert()])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = AlertHandler::new(client);
+    let mut alerts: Vec<_> = handler
+        .all_sources_stream()
+        .map(|alert| alert.unwrap())
+        .collect()
+        .await;
+    alerts.sort_by(|a, b| a.uid.cmp(&b.uid));
+
+    assert_eq!(alerts.len(), 4);
+    let cluster = alerts
+        .iter()
+        .find(|a| a.source == Some(AlertSource::Cluster))
+        .unwrap();
+    assert_eq!(cluster.name, "cluster_license_about_to_expire");
+    assert_eq!(cluster.entity_type.as_deref(), Some("cluster"));
+    assert!(cluster.is_active());
+
+    let node_memory = alerts.iter().find(|a| a.uid == "1:node_memory").unwrap();
+    assert_eq!(node_memory.source, Some(AlertSource::Node));
+    assert_eq!(node_memory.entity_uid.as_deref(), Some("1"));
+    assert!(node_memory.is_critical());
+    let free_flash = alerts
+        .iter()
+        .find(|a| a.uid == "1:node_free_flash")
+        .unwrap();
+    assert!(!free_flash.is_active());
+
+    let database = alerts
+        .iter()
+        .find(|a| a.source == Some(AlertSource::Database))
+        .unwrap();
+    assert_eq!(database.uid, "alert-123");
+}
+
+#[tokio::test]
+async fn test_alerts_all_sources_stream_partial_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/alerts"))
+        .respond_with(success_response(json!({})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes/alerts"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/alerts"))
+        .respond_with(success_response(json!({
+            "3": {"bdb_size": {"enabled": true, "state": true, "severity": "WARNING"}}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = AlertHandler::new(client);
+    let results: Vec<_> = handler.all_sources_stream().collect().await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+    let alert = results.into_iter().find_map(|r| r.ok()).unwrap();
+    assert_eq!(alert.uid, "3:bdb_size");
+    assert_eq!(alert.entity_type.as_deref(), Some("bdb"));
+}
@@ -4,6 +4,7 @@
 //! - `crud`: Basic CRUD operations (list, get, create, delete)
 //! - `actions`: Database actions (export, import, backup, restore, upgrade)
 //! - `monitoring`: Monitoring endpoints (shards, alerts, peer stats)
+//! - `config`: Typed configuration (mTLS)
 
 #[path = "bdb/common.rs"]
 mod common;
@@ -11,6 +12,9 @@ mod common;
 #[path = "bdb/actions.rs"]
 mod actions;
 
+#[path = "bdb/config.rs"]
+mod config;
+
 #[path = "bdb/crud.rs"]
 mod crud;
 
@@ -0,0 +1,276 @@
+//! Configuration tests for database (BDB) operations
+//!
+//! Tests for typed database settings such as mTLS client authentication.
+
+use crate::common::{success_response, test_client, test_database};
+use redis_enterprise::bdb::{
+    AuthorizedSubject, ClientCertSubjectValidation, ModuleFeature, MtlsConfig,
+};
+use serde_json::json;
+use wiremock::matchers::{basic_auth, body_json, method, path};
+use wiremock::{Mock, MockServer};
+
+#[tokio::test]
+async fn test_database_mtls_config_read() {
+    let mock_server = MockServer::start().await;
+
+    let mut db = test_database();
+    db["client_cert_subject_validation_type"] = json!("full_subject");
+    db["authorized_subjects"] = json!([
+        {"CN": "app.example.com", "O": "Example", "OU": ["ops", "dev"], "C": "US"}
+    ]);
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(db))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let config = client.databases().mtls_config(1).await.unwrap();
+
+    assert_eq!(
+        config.validation_type,
+        ClientCertSubjectValidation::FullSubject
+    );
+    assert_eq!(config.authorized_subjects.len(), 1);
+    let subject = &config.authorized_subjects[0];
+    assert_eq!(subject.common_name.as_deref(), Some("app.example.com"));
+    assert_eq!(subject.organization.as_deref(), Some("Example"));
+    assert_eq!(
+        subject.organizational_units,
+        Some(vec!["ops".to_string(), "dev".to_string()])
+    );
+    assert_eq!(subject.country.as_deref(), Some("US"));
+    assert!(subject.locality.is_none());
+}
+
+#[tokio::test]
+async fn test_database_mtls_config_read_defaults() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_database()))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let config = client.databases().mtls_config(1).await.unwrap();
+
+    assert_eq!(config, MtlsConfig::default());
+}
+
+#[tokio::test]
+async fn test_database_configure_mtls() {
+    let mock_server = MockServer::start().await;
+
+    let mut db = test_database();
+    db["client_cert_subject_validation_type"] = json!("san_cn");
+    db["authorized_subjects"] = json!([{"CN": "app.example.com"}]);
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({
+            "client_cert_subject_validation_type": "san_cn",
+            "authorized_subjects": [{"CN": "app.example.com"}]
+        })))
+        .respond_with(success_response(db))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let config = MtlsConfig::builder()
+        .validation_type(ClientCertSubjectValidation::SanCn)
+        .authorized_subjects(vec![
+            AuthorizedSubject::builder()
+                .common_name("app.example.com")
+                .build(),
+        ])
+        .build();
+    let db = client.databases().configure_mtls(1, config).await.unwrap();
+
+    assert_eq!(db.uid, 1);
+    assert_eq!(
+        db.client_cert_subject_validation_type.as_deref(),
+        Some("san_cn")
+    );
+}
+
+#[tokio::test]
+async fn test_database_set_disabled_commands() {
+    let mock_server = MockServer::start().await;
+
+    let mut db = test_database();
+    db["disabled_commands"] = json!("FLUSHALL,FLUSHDB,KEYS");
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(
+            json!({"disabled_commands": "FLUSHALL,FLUSHDB,KEYS"}),
+        ))
+        .respond_with(success_response(db))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let db = client
+        .databases()
+        .set_disabled_commands(1, vec!["FLUSHALL".into(), "FLUSHDB".into(), "KEYS".into()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        db.disabled_commands_list(),
+        vec!["FLUSHALL", "FLUSHDB", "KEYS"]
+    );
+}
+
+#[tokio::test]
+async fn test_database_disabled_commands_parsing() {
+    let mock_server = MockServer::start().await;
+
+    let mut db = test_database();
+    db["disabled_commands"] = json!("FLUSHALL, CONFIG  DEBUG,");
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(db))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/2"))
+        .respond_with(success_response(json!({"uid": 2, "name": "plain"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let db = client.databases().get(1).await.unwrap();
+    assert_eq!(
+        db.disabled_commands_list(),
+        vec!["FLUSHALL", "CONFIG", "DEBUG"]
+    );
+
+    let db = client.databases().get(2).await.unwrap();
+    assert!(db.disabled_commands_list().is_empty());
+}
+
+#[tokio::test]
+async fn test_database_module_features() {
+    let mock_server = MockServer::start().await;
+
+    let mut db = test_database();
+    db["search"] = json!(true);
+    db["timeseries"] = json!({"enabled": false, "retention_policy": 3600});
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(db))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/2"))
+        .respond_with(success_response(json!({
+            "uid": 2,
+            "name": "plain",
+            "search": {"query_timeout_ms": 500},
+            "timeseries": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let db = client.databases().get(1).await.unwrap();
+    assert_eq!(db.search_feature(), Some(ModuleFeature::Enabled));
+    let timeseries = db.timeseries_feature().unwrap();
+    assert!(!timeseries.is_enabled());
+    assert_eq!(timeseries.config().unwrap().extra["retention_policy"], 3600);
+    assert_eq!(
+        db.timeseries,
+        Some(json!({"enabled": false, "retention_policy": 3600}))
+    );
+
+    let db = client.databases().get(2).await.unwrap();
+    let search = db.search_feature().unwrap();
+    assert!(search.is_enabled());
+    assert!(matches!(search, ModuleFeature::Configured(_)));
+    assert_eq!(db.timeseries_feature(), Some(ModuleFeature::Disabled));
+    assert_eq!(
+        serde_json::to_value(&search).unwrap(),
+        json!({"query_timeout_ms": 500})
+    );
+
+    assert_eq!(ModuleFeature::from_value(&json!("on")), None);
+}
+
+#[test]
+fn test_database_connection_string() {
+    use redis_enterprise::bdb::{AddrType, DatabaseInfo};
+
+    let mut db = test_database();
+    db["endpoints"] = json!([
+        {"uid": "1:1", "addr": ["10.0.0.1"], "port": 12000, "addr_type": "internal",
+         "dns_name": "redis-12000.internal.example.com"},
+        {"uid": "1:2", "addr": ["203.0.113.5"], "port": 12000, "addr_type": "external"},
+        {"uid": "1:3", "addr": ["203.0.113.6"], "port": 12001, "addr_type": "external"}
+    ]);
+    let info: DatabaseInfo = serde_json::from_value(db.clone()).unwrap();
+
+    assert_eq!(
+        info.connection_string(AddrType::Internal).as_deref(),
+        Some("redis://redis-12000.internal.example.com:12000")
+    );
+    // First matching endpoint is the master endpoint
+    assert_eq!(
+        info.connection_string(AddrType::External).as_deref(),
+        Some("redis://203.0.113.5:12000")
+    );
+
+    // TLS switches the scheme
+    db["ssl"] = json!(true);
+    let info: DatabaseInfo = serde_json::from_value(db.clone()).unwrap();
+    assert_eq!(
+        info.connection_string(AddrType::External).as_deref(),
+        Some("rediss://203.0.113.5:12000")
+    );
+
+    // Falls back to another address type, falling back to the database port
+    db["endpoints"] = json!([{"addr": ["fd00::1"], "addr_type": "internal"}]);
+    db["ssl"] = json!(false);
+    let info: DatabaseInfo = serde_json::from_value(db.clone()).unwrap();
+    assert_eq!(
+        info.connection_string(AddrType::External).as_deref(),
+        Some("redis://[fd00::1]:12000")
+    );
+
+    // No endpoints yet
+    let info: DatabaseInfo = serde_json::from_value(test_database()).unwrap();
+    assert!(info.connection_string(AddrType::Internal).is_none());
+}
+
+#[test]
+fn test_database_memory_fields_accept_strings_and_floats() {
+    use redis_enterprise::bdb::DatabaseInfo;
+
+    let mut db = test_database();
+    db["memory_size"] = json!("8589934592");
+    db["memory_used"] = json!(1073741824.0);
+    db["bigstore_ram_size"] = json!(null);
+    let info: DatabaseInfo = serde_json::from_value(db.clone()).unwrap();
+    assert_eq!(info.memory_size, Some(8_589_934_592));
+    assert_eq!(info.memory_used, Some(1_073_741_824));
+    assert_eq!(info.bigstore_ram_size, None);
+
+    db["memory_size"] = json!(8589934592u64);
+    db.as_object_mut().unwrap().remove("memory_used");
+    let info: DatabaseInfo = serde_json::from_value(db.clone()).unwrap();
+    assert_eq!(info.memory_size, Some(8_589_934_592));
+    assert_eq!(info.memory_used, None);
+
+    for invalid in [json!("lots"), json!(-1), json!(1.5)] {
+        db["memory_size"] = invalid;
+        assert!(serde_json::from_value::<DatabaseInfo>(db.clone()).is_err());
+    }
+}
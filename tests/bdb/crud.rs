@@ -3,10 +3,13 @@
 use crate::common::{
     created_response, no_content_response, success_response, test_client, test_database,
 };
-use redis_enterprise::bdb::CreateDatabaseRequest;
+use redis_enterprise::bdb::{
+    CreateDatabaseRequest, CreateDatabaseV2Request, RecoveryDataFile, RecoveryPlan,
+};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
-use wiremock::{Mock, MockServer};
+use std::time::Duration;
+use wiremock::matchers::{basic_auth, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
 async fn test_database_list() {
@@ -82,6 +85,256 @@ async fn test_database_create() {
     assert_eq!(db.name, "test-db");
 }
 
+#[tokio::test]
+async fn test_database_create_checked() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(test_database()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder()
+        .name("test-db")
+        .memory_size(1073741824)
+        .build();
+    let db = client.databases().create_checked(request).await.unwrap();
+
+    assert_eq!(db.uid, 1);
+}
+
+#[tokio::test]
+async fn test_database_validate_create() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/license"))
+        .respond_with(success_response(json!({
+            "expired": false,
+            "shards_limit": 10,
+            "ram_shards_limit": 10,
+            "ram_shards_in_use": 8
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/stats/last"))
+        .respond_with(success_response(json!({
+            "interval": "1sec",
+            "provisional_memory": "3221225472"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(test_database()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+
+    let fits = CreateDatabaseRequest::builder()
+        .name("cache")
+        .memory_size(1024 * 1024 * 1024)
+        .replication(true)
+        .eviction_policy("volatile-lru")
+        .persistence("aof")
+        .build();
+    assert!(
+        client
+            .databases()
+            .validate_create(&fits)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let too_big = CreateDatabaseRequest::builder()
+        .name("cache")
+        .memory_size(2 * 1024 * 1024 * 1024)
+        .replication(true)
+        .shards_count(2)
+        .eviction_policy("allkeys-lru-ish")
+        .build();
+    let issues = client.databases().validate_create(&too_big).await.unwrap();
+    let fields: Vec<_> = issues.iter().map(|issue| issue.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        vec!["eviction_policy", "shards_count", "memory_size"]
+    );
+    assert_eq!(
+        issues[1].to_string(),
+        "shards_count: needs 4 shards but the license allows 2 more (8 of 10 in use)"
+    );
+
+    // Evicting any key would drop data the database persists
+    let lossy = CreateDatabaseRequest::builder()
+        .name("")
+        .memory_size(1024)
+        .eviction_policy("allkeys-lru")
+        .persistence("snapshot")
+        .build();
+    let issues = client.databases().validate_create(&lossy).await.unwrap();
+    let fields: Vec<_> = issues.iter().map(|issue| issue.field.as_str()).collect();
+    assert_eq!(fields, vec!["name", "eviction_policy"]);
+    assert_eq!(issues[0].message, "database name must not be empty");
+}
+
+#[tokio::test]
+async fn test_database_validate_create_pairs_shard_limits() {
+    let mock_server = MockServer::start().await;
+
+    // No RAM shard limit: the overall limit applies to all shards in use
+    Mock::given(method("GET"))
+        .and(path("/v1/license"))
+        .respond_with(success_response(json!({
+            "expired": false,
+            "shards_limit": 10,
+            "ram_shards_in_use": 6,
+            "flash_shards_in_use": 3
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/stats/last"))
+        .respond_with(success_response(json!({"interval": "1sec"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder()
+        .name("cache")
+        .memory_size(1024)
+        .shards_count(2)
+        .build();
+    let issues = client.databases().validate_create(&request).await.unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].to_string(),
+        "shards_count: needs 2 shards but the license allows 1 more (9 of 10 in use)"
+    );
+}
+
+#[tokio::test]
+async fn test_database_create_checked_requires_memory_size() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(test_database()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder().name("test-db").build();
+    let err = client
+        .databases()
+        .create_checked(request)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, redis_enterprise::RestError::ValidationError(ref msg) if msg.contains("memory_size"))
+    );
+
+    let zero = CreateDatabaseRequest::builder()
+        .name("test-db")
+        .memory_size(0)
+        .build();
+    assert!(zero.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_database_create_v2_with_recovery_plan() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/bdbs"))
+        .and(body_json(json!({
+            "bdb": {"name": "test-db", "memory_size": 1073741824u64, "shards_count": 2},
+            "recovery_plan": {
+                "data_files": [
+                    {"filename": "redis-1.rdb", "node_uid": "1", "shard_slots": "0-8191"},
+                    {"filename": "redis-2.rdb", "node_uid": "2", "shard_slots": "8192-16383"}
+                ]
+            }
+        })))
+        .respond_with(created_response(test_database()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let data_files = vec![
+        RecoveryDataFile::builder()
+            .filename("redis-1.rdb")
+            .node_uid("1")
+            .shard_slots("0-8191")
+            .build(),
+        RecoveryDataFile::builder()
+            .filename("redis-2.rdb")
+            .node_uid("2")
+            .shard_slots("8192-16383")
+            .build(),
+    ];
+    let request = CreateDatabaseV2Request::builder()
+        .bdb(
+            CreateDatabaseRequest::builder()
+                .name("test-db")
+                .memory_size(1073741824)
+                .shards_count(2)
+                .build(),
+        )
+        .recovery_plan(RecoveryPlan { data_files })
+        .build();
+    let db = client.databases().create_v2_with(request).await.unwrap();
+    assert_eq!(db.uid, 1);
+
+    let empty_plan = CreateDatabaseV2Request::builder()
+        .bdb(
+            CreateDatabaseRequest::builder()
+                .name("test-db")
+                .memory_size(1073741824)
+                .build(),
+        )
+        .recovery_plan(RecoveryPlan::default())
+        .build();
+    let err = client
+        .databases()
+        .create_v2_with(empty_plan)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        redis_enterprise::RestError::ValidationError(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_database_update_conflict() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(409).set_body_string("database is busy"))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let err = client
+        .databases()
+        .update(1, json!({"memory_size": 2147483648u64}))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, redis_enterprise::RestError::Conflict { .. }));
+}
+
 #[tokio::test]
 async fn test_database_delete() {
     let mock_server = MockServer::start().await;
@@ -98,3 +351,160 @@ async fn test_database_delete() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_database_create_and_wait() {
+    let mock_server = MockServer::start().await;
+
+    let mut pending = test_database();
+    pending["status"] = json!("pending");
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(pending.clone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(pending))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(test_database()))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder()
+        .name("test-db")
+        .memory_size(1073741824)
+        .build();
+    let db = client
+        .databases()
+        .create_and_wait(request, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(db.uid, 1);
+    assert_eq!(db.status.as_deref(), Some("active"));
+}
+
+#[tokio::test]
+async fn test_database_create_and_wait_failure_state() {
+    let mock_server = MockServer::start().await;
+
+    let mut pending = test_database();
+    pending["status"] = json!("pending");
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(pending))
+        .mount(&mock_server)
+        .await;
+
+    let mut failed = test_database();
+    failed["status"] = json!("creation-failed");
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(failed))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder().name("test-db").build();
+    let error = client
+        .databases()
+        .create_and_wait(request, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("creation-failed"));
+}
+
+#[tokio::test]
+async fn test_database_create_and_wait_timeout() {
+    let mock_server = MockServer::start().await;
+
+    let mut pending = test_database();
+    pending["status"] = json!("pending");
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(created_response(pending.clone()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(pending))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let request = CreateDatabaseRequest::builder().name("test-db").build();
+    let result = client
+        .databases()
+        .create_and_wait(
+            request,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        )
+        .await;
+
+    assert!(result.unwrap_err().is_timeout());
+}
+
+#[test]
+fn test_database_info_to_update_request_and_diff() {
+    use redis_enterprise::bdb::DatabaseInfo;
+
+    let mut body = test_database();
+    body["endpoints"] = json!([{"uid": "1:1", "port": 12000}]);
+    body["eviction_policy"] = json!("volatile-lru");
+    body["topology_epoch"] = json!(7);
+    body["throughput_ingress"] = json!(1250.5);
+    body["bigstore"] = json!(false);
+    body["module_list"] = json!([{"module_name": "search", "module_args": ""}]);
+    body["shard_list"] = json!([1, 2]);
+    body["last_changed_time"] = json!("2024-01-01T00:00:00Z");
+    body["redis_version"] = json!("7.2");
+    body["backup_status"] = json!("succeeded");
+    let info: DatabaseInfo = serde_json::from_value(body).unwrap();
+
+    let mut request = info.to_update_request();
+    for read_only in [
+        "uid",
+        "status",
+        "type",
+        "version",
+        "endpoints",
+        "topology_epoch",
+        "throughput_ingress",
+        "bigstore",
+        "module_list",
+        "shard_list",
+        "last_changed_time",
+        "redis_version",
+        "backup_status",
+    ] {
+        assert!(request.get(read_only).is_none(), "{read_only} is read-only");
+    }
+    assert_eq!(request.get("name"), Some(&json!("test-db")));
+    assert_eq!(request.get("eviction_policy"), Some(&json!("volatile-lru")));
+    assert!(request.fields.values().all(|value| !value.is_null()));
+    request.set("memory_size", 2u64 << 30);
+    let sent: serde_json::Value = request.into();
+    assert_eq!(sent["memory_size"], 2u64 << 30);
+
+    let mut edited = info.clone();
+    edited.memory_size = Some(2 << 30);
+    edited.eviction_policy = None;
+    edited.status = Some("pending".to_string());
+    assert_eq!(
+        info.diff(&edited),
+        json!({"memory_size": 2u64 << 30, "eviction_policy": null})
+    );
+    assert_eq!(info.diff(&info), json!({}));
+}
@@ -1,11 +1,11 @@
 //! Monitoring tests for database (BDB) operations
 //!
-//! Tests for shards, alerts, peer stats, syncer state, and password management.
+//! Tests for shards, alerts, peer stats, syncer state, password management, and memory usage.
 
 use crate::common::{no_content_response, success_response, test_client};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
-use wiremock::{Mock, MockServer};
+use wiremock::matchers::{basic_auth, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
 async fn test_database_get_shards() {
@@ -111,3 +111,214 @@ async fn test_passwords_delete_and_reset_status() {
     let reset = client.databases().backup_reset_status(1).await.unwrap();
     assert_eq!(reset["status"], "reset");
 }
+
+#[tokio::test]
+async fn test_database_over_memory_threshold() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "name": "half", "memory_size": 1000, "memory_used": 500},
+            {"uid": 2, "name": "hot", "memory_size": 1000, "memory_used": 950},
+            {"uid": 3, "name": "warm", "memory_size": 1000, "memory_used": 850},
+            {"uid": 4, "name": "no-usage", "memory_size": 1000},
+            {"uid": 5, "name": "no-limit", "memory_size": 0, "memory_used": 100},
+            {"uid": 6, "name": "at-limit", "memory_size": 1000, "memory_used": 800}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let over = client.databases().over_memory_threshold(0.8).await.unwrap();
+
+    let names: Vec<&str> = over.iter().map(|(db, _)| db.name.as_str()).collect();
+    assert_eq!(names, vec!["hot", "warm"]);
+    assert!((over[0].1 - 0.95).abs() < f64::EPSILON);
+    assert!((over[1].1 - 0.85).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_database_availability_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/availability"))
+        .respond_with(success_response(json!({})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/2/availability"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "error_code": "no_quorum",
+            "description": "Master shard is down"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/local/bdbs/2/endpoint/availability"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "error_code": "no_endpoint",
+            "endpoints": [
+                {"uid": "2:1", "available": true},
+                {"uid": "2:2", "error_code": "proxy_down", "description": "Proxy is down"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/3/availability"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let handler = client.databases();
+
+    let up = handler.availability_status(1).await.unwrap();
+    assert!(up.available);
+    assert_eq!(up.reason, None);
+    assert!(up.endpoints.is_empty());
+
+    let down = handler.availability_status(2).await.unwrap();
+    assert!(!down.available);
+    assert_eq!(down.error_code.as_deref(), Some("no_quorum"));
+    assert_eq!(down.reason.as_deref(), Some("Master shard is down"));
+
+    let endpoint = handler.endpoint_availability_status(2).await.unwrap();
+    assert!(!endpoint.available);
+    assert_eq!(endpoint.reason.as_deref(), Some("no_endpoint"));
+    assert_eq!(endpoint.endpoints.len(), 2);
+    assert!(endpoint.endpoints[0].available);
+    assert!(!endpoint.endpoints[1].available);
+    assert_eq!(
+        endpoint.endpoints[1].reason.as_deref(),
+        Some("Proxy is down")
+    );
+
+    assert!(
+        handler
+            .availability_status(3)
+            .await
+            .unwrap_err()
+            .is_not_found()
+    );
+}
+
+fn slowlog_reply() -> serde_json::Value {
+    json!({
+        "response": [
+            [14, 1709287200, 25000, ["HGETALL", "user:42"], "10.0.0.5:51234", "api"],
+            [13, 1709287100, 12000, ["KEYS", "*"], "10.0.0.6:40000", ""]
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_database_slowlog() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(
+            json!({"name": "c1", "slowlog_in_sanitized_support": false}),
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/command"))
+        .and(body_json(json!({"command": "SLOWLOG GET 2"})))
+        .respond_with(success_response(slowlog_reply()))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let entries = client.databases().slowlog(1, 2).await.unwrap();
+
+    assert_eq!(entries.len(), 2);
+    let newest = &entries[0];
+    assert_eq!(newest.id, 14);
+    assert_eq!(newest.timestamp.to_rfc3339(), "2024-03-01T10:00:00+00:00");
+    assert_eq!(newest.duration_us, 25000);
+    assert_eq!(newest.command, vec!["HGETALL", "user:42"]);
+    assert_eq!(newest.client.as_deref(), Some("10.0.0.5:51234"));
+    assert_eq!(newest.client_name.as_deref(), Some("api"));
+    assert!(!newest.sanitized);
+    assert_eq!(entries[1].client_name, None);
+}
+
+#[tokio::test]
+async fn test_database_slowlog_sanitized() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(
+            json!({"name": "c1", "slowlog_in_sanitized_support": true}),
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/command"))
+        .respond_with(success_response(slowlog_reply()))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let entries = client.databases().slowlog(1, 2).await.unwrap();
+
+    assert!(entries[0].sanitized);
+    assert_eq!(entries[0].command_name(), Some("HGETALL"));
+    assert_eq!(entries[0].command, vec!["HGETALL", "?"]);
+}
+
+#[tokio::test]
+async fn test_database_client_list() {
+    use redis_enterprise::bdb::ClientListQuery;
+
+    let mock_server = MockServer::start().await;
+
+    let list = "id=3 addr=10.0.0.5:51234 laddr=10.0.0.1:12000 fd=8 name=api age=120 idle=0 flags=N db=0 cmd=client|list user=default\n\
+                id=4 addr=10.0.0.6:40000 laddr=10.0.0.1:12000 fd=9 name= age=3600 idle=3590 flags=N db=1 cmd=get user=app\n";
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/command"))
+        .and(body_json(json!({"command": "CLIENT LIST"})))
+        .respond_with(success_response(json!({"response": list})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/command"))
+        .and(body_json(json!({"command": "CLIENT LIST TYPE normal"})))
+        .respond_with(success_response(json!({"response": list})))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server);
+    let connections = client.databases().client_list(1).await.unwrap();
+
+    assert_eq!(connections.len(), 2);
+    assert_eq!(connections[0].id, Some(3));
+    assert_eq!(connections[0].addr.as_deref(), Some("10.0.0.5:51234"));
+    assert_eq!(connections[0].name.as_deref(), Some("api"));
+    assert_eq!(connections[0].cmd.as_deref(), Some("client|list"));
+    assert_eq!(connections[0].extra["laddr"], "10.0.0.1:12000");
+    assert_eq!(connections[1].name, None);
+    assert_eq!(connections[1].idle, Some(3590));
+    assert_eq!(connections[1].db, Some(1));
+
+    let page = client
+        .databases()
+        .client_list_with(
+            1,
+            ClientListQuery::builder()
+                .client_type("normal")
+                .offset(1)
+                .limit(5)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].user.as_deref(), Some("app"));
+}
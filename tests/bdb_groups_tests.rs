@@ -5,7 +5,7 @@ mod tests {
         BdbGroupsHandler, CreateBdbGroupRequest, UpdateBdbGroupRequest,
     };
     use serde_json::json;
-    use wiremock::matchers::{basic_auth, method, path};
+    use wiremock::matchers::{basic_auth, body_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup_mock_client(mock_server: &MockServer) -> BdbGroupsHandler {
@@ -184,4 +184,133 @@ mod tests {
         let result = handler.create(request).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_add_database_to_group() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("PUT"))
+            .and(path("/v1/bdbs/3"))
+            .and(basic_auth("test_user", "test_pass"))
+            .and(body_json(json!({"group_uid": 1})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 3,
+                "name": "db3",
+                "group_uid": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdb_groups/1"))
+            .and(basic_auth("test_user", "test_pass"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 1,
+                "name": "group1",
+                "memory_size": 1073741824,
+                "members": ["2", "3"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let group = handler.add_database(1, 3).await.unwrap();
+        assert_eq!(group.uid, 1);
+        assert_eq!(group.members, Some(vec!["2".to_string(), "3".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_add_database_already_in_another_group() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("PUT"))
+            .and(path("/v1/bdbs/3"))
+            .and(basic_auth("test_user", "test_pass"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+                "error_code": "bdb_in_group",
+                "description": "Database is already a member of group 2"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = handler.add_database(1, 3).await.unwrap_err();
+        assert!(err.is_conflict());
+        let message = err.to_string();
+        assert!(message.contains("database 3 cannot be added to group 1"));
+        assert!(message.contains("already a member of group 2"));
+    }
+
+    #[tokio::test]
+    async fn test_remove_database_from_group() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdb_groups/1"))
+            .and(basic_auth("test_user", "test_pass"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 1,
+                "name": "group1",
+                "members": ["2", "3"]
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/v1/bdbs/3"))
+            .and(basic_auth("test_user", "test_pass"))
+            .and(body_json(json!({"group_uid": null})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 3,
+                "name": "db3"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdb_groups/1"))
+            .and(basic_auth("test_user", "test_pass"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 1,
+                "name": "group1",
+                "members": ["2"]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let group = handler.remove_database(1, 3).await.unwrap();
+        assert_eq!(group.members, Some(vec!["2".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_remove_database_not_in_group() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/bdb_groups/1"))
+            .and(basic_auth("test_user", "test_pass"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": 1,
+                "name": "group1",
+                "members": ["2"]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/v1/bdbs/9"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let result = handler.remove_database(1, 9).await;
+        assert!(result.unwrap_err().is_not_found());
+    }
 }
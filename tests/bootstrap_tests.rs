@@ -5,6 +5,7 @@ use redis_enterprise::{
     NodeBootstrap, NodePaths,
 };
 use serde_json::json;
+use std::time::Duration;
 use wiremock::matchers::{basic_auth, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -330,3 +331,105 @@ async fn test_bootstrap_create_minimal_config() {
     assert_eq!(status.status, "in_progress");
     assert_eq!(status.progress, Some(0.0));
 }
+
+#[tokio::test]
+async fn test_bootstrap_wait_until_done() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(success_response(bootstrap_status_response(
+            "in_progress",
+            Some(50.0),
+            Some("Configuring node"),
+        )))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(success_response(bootstrap_status_response(
+            "completed",
+            Some(100.0),
+            None,
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = BootstrapHandler::new(client);
+
+    let status = handler
+        .wait_until_done(Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert!(status.is_completed());
+    assert_eq!(status.progress, Some(100.0));
+}
+
+#[tokio::test]
+async fn test_bootstrap_wait_until_done_surfaces_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(success_response(bootstrap_status_response(
+            "error",
+            Some(30.0),
+            Some("Persistent path is not writable"),
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = BootstrapHandler::new(client);
+
+    let error = handler
+        .wait_until_done(Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Persistent path is not writable")
+    );
+}
+
+#[tokio::test]
+async fn test_bootstrap_wait_until_done_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(success_response(bootstrap_status_response(
+            "in_progress",
+            Some(10.0),
+            None,
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = BootstrapHandler::new(client);
+
+    let result = handler
+        .wait_until_done(Duration::from_millis(10), Duration::from_millis(50))
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+}
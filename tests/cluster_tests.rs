@@ -3,9 +3,15 @@
 
 mod common;
 
-use redis_enterprise::{ClusterHandler, ClusterInfo, EnterpriseClient};
+use redis_enterprise::alerts::AlertThreshold;
+use redis_enterprise::{
+    AuditProtocol, AuditingDbConnsConfig, ClusterAlertSettings, ClusterAlertThreshold,
+    ClusterHandler, ClusterInfo, ClusterPolicy, ClusterServiceConfig, ClusterVersion,
+    EnterpriseClient, MetricsSystem, ModuleCapabilities, ServicesConfiguration, WitnessDiskStatus,
+};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
+use std::time::Duration;
+use wiremock::matchers::{basic_auth, body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // Test helper functions
@@ -51,6 +57,63 @@ async fn test_cluster_actions_and_auditing() {
     assert_eq!(updated["enabled"], true);
 }
 
+#[tokio::test]
+async fn test_cluster_auditing_db_conns_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/auditing/db_conns"))
+        .respond_with(success_response(json!({
+            "audit_address": "audit.example.com",
+            "audit_port": 2001,
+            "audit_protocol": "TCP",
+            "audit_reconnect_interval": 1,
+            "audit_reconnect_max_attempts": 0
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster/auditing/db_conns"))
+        .and(body_json(json!({
+            "audit_address": "/var/run/audit.sock",
+            "audit_protocol": "local"
+        })))
+        .respond_with(success_response(json!({
+            "audit_address": "/var/run/audit.sock",
+            "audit_protocol": "local",
+            "audit_reconnect_interval": 1,
+            "audit_reconnect_max_attempts": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = ClusterHandler::new(client);
+
+    let config = handler.auditing_db_conns_typed().await.unwrap();
+    assert_eq!(config.audit_address.as_deref(), Some("audit.example.com"));
+    assert_eq!(config.audit_port, Some(2001));
+    assert_eq!(config.audit_protocol, Some(AuditProtocol::Tcp));
+
+    let updated = handler
+        .update_auditing_db_conns(
+            AuditingDbConnsConfig::builder()
+                .audit_address("/var/run/audit.sock")
+                .audit_protocol(AuditProtocol::Local)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.audit_protocol, Some(AuditProtocol::Local));
+    assert_eq!(updated.audit_port, None);
+}
+
 #[tokio::test]
 async fn test_cluster_certs_policy_and_witness() {
     let mock_server = MockServer::start().await;
@@ -231,6 +294,231 @@ async fn test_cluster_remove_node() {
     assert_eq!(result.unwrap()["message"], "Node 2 removed");
 }
 
+#[tokio::test]
+async fn test_cluster_join_node_and_wait() {
+    let mock_server = MockServer::start().await;
+    let joining_host = format!("localhost:{}", mock_server.address().port());
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bootstrap/join"))
+        .respond_with(success_response(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The new node is not listed at first, then shows up while provisioning
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(
+            json!([{"uid": 1, "address": "10.0.0.1", "status": "active"}]),
+        ))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "address": "10.0.0.1", "status": "active"},
+            {"uid": 2, "address": "localhost", "status": "provisioning"}
+        ])))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "address": "10.0.0.1", "status": "active"},
+            {"uid": 2, "address": "localhost", "status": "active"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    // The joining node reports its own bootstrap progress; the master's
+    // bootstrap status is unrelated
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .and(header("host", joining_host.as_str()))
+        .respond_with(success_response(json!({"status": "in_progress"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(success_response(
+            json!({"status": "error", "message": "stale"}),
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let node = client
+        .cluster()
+        .join_node_and_wait(
+            "localhost",
+            "admin",
+            "password",
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(node.uid, 2);
+    assert_eq!(node.status, "active");
+}
+
+#[tokio::test]
+async fn test_cluster_join_node_and_wait_join_failed() {
+    let mock_server = MockServer::start().await;
+    let joining_host = format!("localhost:{}", mock_server.address().port());
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bootstrap/join"))
+        .respond_with(success_response(json!({})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(
+            json!([{"uid": 1, "address": "10.0.0.1", "status": "active"}]),
+        ))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .and(header("host", joining_host.as_str()))
+        .respond_with(success_response(json!({
+            "status": "error",
+            "message": "Node localhost is unreachable"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let error = client
+        .cluster()
+        .join_node_and_wait(
+            "localhost",
+            "admin",
+            "password",
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("unreachable"));
+}
+
+#[tokio::test]
+async fn test_cluster_remove_node_and_wait() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/nodes/3"))
+        .respond_with(success_response(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Node 3 is still listed for the first two polls, then disappears
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "address": "10.0.0.1", "status": "active"},
+            {"uid": 3, "address": "10.0.0.3", "status": "active"}
+        ])))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(
+            json!([{"uid": 1, "address": "10.0.0.1", "status": "active"}]),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(success_response(json!([
+            {"uid": "1", "bdb_uid": 1, "node_uid": "1", "role": "master", "status": "active"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    client
+        .cluster()
+        .remove_node_and_wait(3, Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_cluster_remove_node_and_wait_times_out() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/nodes/3"))
+        .respond_with(success_response(json!({})))
+        .mount(&mock_server)
+        .await;
+
+    // Node is gone but one of its shards has not been re-placed yet
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(
+            json!([{"uid": 1, "address": "10.0.0.1", "status": "active"}]),
+        ))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(success_response(json!([
+            {"uid": "2", "bdb_uid": 1, "node_uid": "3", "role": "replica", "status": "active"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let result = client
+        .cluster()
+        .remove_node_and_wait(3, Duration::from_millis(10), Duration::from_millis(50))
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+}
+
 #[tokio::test]
 async fn test_cluster_reset() {
     let mock_server = MockServer::start().await;
@@ -280,3 +568,868 @@ async fn test_cluster_recover() {
     let result = handler.recover().await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_cluster_identity() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "uid": 7,
+            "name": "prod.example.com",
+            "version": "7.4.2-54",
+            "created": "2024-01-15T10:00:00Z",
+            "cm_server_version": 3,
+            "nodes": [1, 2, 3],
+            "rack_aware": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let identity = client.cluster().identity().await.unwrap();
+    assert_eq!(identity.uid, Some(7));
+    assert_eq!(identity.name, "prod.example.com");
+    assert_eq!(identity.created.as_deref(), Some("2024-01-15T10:00:00Z"));
+    assert_eq!(identity.cm_server_version, Some(3));
+
+    let version = identity.version.unwrap();
+    assert_eq!(version.to_string(), "7.4.2-54");
+    assert!(version > ClusterVersion::new(7, 4, 2));
+    assert!(version < ClusterVersion::new(7, 8, 0));
+}
+
+#[test]
+fn test_cluster_version_parsing() {
+    let v: ClusterVersion = "7.2.0-92".parse().unwrap();
+    assert_eq!((v.major, v.minor, v.patch, v.build), (7, 2, 0, Some(92)));
+
+    let v: ClusterVersion = "6.4".parse().unwrap();
+    assert_eq!(v, ClusterVersion::new(6, 4, 0));
+
+    assert!("".parse::<ClusterVersion>().is_err());
+    assert!("7.x.1".parse::<ClusterVersion>().is_err());
+    assert!("7.2.0-beta".parse::<ClusterVersion>().is_err());
+    assert!("1.2.3.4".parse::<ClusterVersion>().is_err());
+
+    let mut versions: Vec<ClusterVersion> = ["7.4.2-54", "6.2.18", "7.4.2", "7.2.0-92"]
+        .iter()
+        .map(|v| v.parse().unwrap())
+        .collect();
+    versions.sort();
+    let sorted: Vec<String> = versions.iter().map(ToString::to_string).collect();
+    assert_eq!(sorted, vec!["6.2.18", "7.2.0-92", "7.4.2", "7.4.2-54"]);
+}
+
+#[tokio::test]
+async fn test_cluster_ssh_public_key() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "name": "test-cluster",
+            "cluster_ssh_public_key": "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC7 cluster@node1\n"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let key = client.cluster().ssh_public_key().await.unwrap();
+    assert_eq!(
+        key,
+        "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC7 cluster@node1"
+    );
+}
+
+#[tokio::test]
+async fn test_cluster_ssh_public_key_missing() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(json!({"name": "test-cluster"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let result = client.cluster().ssh_public_key().await;
+    assert!(result.unwrap_err().is_not_found());
+}
+
+#[test]
+fn test_metrics_system_serialization() {
+    assert_eq!(serde_json::to_value(MetricsSystem::V1).unwrap(), json!(1));
+    assert_eq!(serde_json::to_value(MetricsSystem::V2).unwrap(), json!(2));
+    assert_eq!(
+        serde_json::to_value(MetricsSystem::Other(0)).unwrap(),
+        json!(0)
+    );
+
+    for system in [
+        MetricsSystem::V1,
+        MetricsSystem::V2,
+        MetricsSystem::Other(7),
+    ] {
+        let value = serde_json::to_value(system).unwrap();
+        assert_eq!(
+            serde_json::from_value::<MetricsSystem>(value).unwrap(),
+            system
+        );
+    }
+
+    // Known codes always decode to their named variant
+    assert!(matches!(MetricsSystem::from(1), MetricsSystem::V1));
+    assert_eq!(MetricsSystem::Other(1), MetricsSystem::V1);
+    assert_ne!(MetricsSystem::Other(0), MetricsSystem::V1);
+}
+
+#[tokio::test]
+async fn test_cluster_set_metrics_system() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({"metrics_system": 2})))
+        .respond_with(success_response(json!({
+            "name": "test-cluster",
+            "metrics_system": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let info = client
+        .cluster()
+        .set_metrics_system(MetricsSystem::V2)
+        .await
+        .unwrap();
+    assert_eq!(info.metrics_system, Some(2));
+    assert_eq!(info.metrics_system(), Some(MetricsSystem::V2));
+}
+
+#[tokio::test]
+async fn test_cluster_alert_settings_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(json!({
+            "name": "test-cluster",
+            "alert_settings": {
+                "node_cpu_utilization": {"enabled": true, "threshold": "80"},
+                "node_memory": {"enabled": false, "threshold": "20%"},
+                "node_future_alert": {"enabled": true, "threshold": "5"}
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster"))
+        .and(body_json(json!({
+            "alert_settings": {
+                "node_cpu_utilization": {"enabled": true, "threshold": 90}
+            }
+        })))
+        .respond_with(success_response(json!({
+            "name": "test-cluster",
+            "alert_settings": {
+                "node_cpu_utilization": {"enabled": true, "threshold": "90"}
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let cluster = client.cluster();
+
+    let settings = cluster.alert_settings().await.unwrap();
+    let cpu = settings.node_cpu_utilization.unwrap();
+    assert!(cpu.enabled);
+    assert_eq!(cpu.threshold, Some(AlertThreshold::Raw(json!("80"))));
+    assert_eq!(
+        settings.node_memory.unwrap().threshold,
+        Some(AlertThreshold::Raw(json!("20%")))
+    );
+    assert!(settings.extra.contains_key("node_future_alert"));
+
+    // Unchanged alerts read back from the cluster are not sent again
+    let mut update: ClusterAlertSettings = cluster.alert_settings().await.unwrap();
+    update.node_cpu_utilization = Some(
+        ClusterAlertThreshold::builder()
+            .enabled(true)
+            .threshold(AlertThreshold::Value(90.0))
+            .build(),
+    );
+    let updated = cluster.update_alert_settings(update).await.unwrap();
+    assert_eq!(
+        updated.node_cpu_utilization.unwrap().threshold,
+        Some(AlertThreshold::Raw(json!("90")))
+    );
+}
+
+#[tokio::test]
+async fn test_cluster_actions_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/actions"))
+        .respond_with(success_response(json!({
+            "actions": [
+                {
+                    "action_uid": "a-1",
+                    "name": "optimize_shards_placement",
+                    "status": "running",
+                    "progress": 40.0,
+                    "start_time": "2024-03-01T10:00:00Z"
+                },
+                "recover"
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/actions/optimize_shards_placement"))
+        .respond_with(success_response(json!({
+            "action_uid": "a-1",
+            "name": "optimize_shards_placement",
+            "status": "completed",
+            "progress": 100.0,
+            "start_time": "2024-03-01T10:00:00Z",
+            "end_time": "2024-03-01T10:05:00Z",
+            "node_uid": "2"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let cluster = client.cluster();
+
+    let actions = cluster.actions_typed().await.unwrap();
+    assert_eq!(actions.len(), 2);
+    assert!(actions[0].is_running());
+    assert_eq!(actions[0].progress, Some(40.0));
+    assert_eq!(actions[1].name, "recover");
+    assert!(actions[1].status.is_none());
+    assert!(!actions[1].is_running());
+
+    let detail = cluster
+        .action_detail_typed("optimize_shards_placement")
+        .await
+        .unwrap();
+    assert!(detail.is_terminal());
+    assert_eq!(detail.end_time.as_deref(), Some("2024-03-01T10:05:00Z"));
+    assert_eq!(detail.extra["node_uid"], "2");
+}
+
+#[tokio::test]
+async fn test_cluster_enter_and_exit_maintenance() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster"))
+        .and(body_json(json!({"block_cluster_changes": true})))
+        .respond_with(success_response(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(json!({
+            "name": "test-cluster",
+            "block_cluster_changes": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let info = client.cluster().enter_maintenance().await.unwrap();
+    assert_eq!(info.block_cluster_changes, Some(true));
+
+    // The cluster still reports changes as blocked, so exiting is not confirmed
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster"))
+        .and(body_json(json!({"block_cluster_changes": false})))
+        .respond_with(success_response(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let err = client.cluster().exit_maintenance().await.unwrap_err();
+    assert!(err.to_string().contains("did not exit maintenance mode"));
+}
+
+#[tokio::test]
+async fn test_cluster_topology_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/topology"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "nodes": [
+                {"uid": 1, "addr": "10.0.0.1", "rack_id": "zone-a", "status": "active"},
+                {"uid": 2, "addr": "10.0.0.2", "rack_id": "zone-b", "status": "active"}
+            ],
+            "shards": [
+                {"uid": "1", "bdb_uid": 1, "node_uid": "1", "role": "master", "assigned_slots": "0-16383"},
+                {"uid": "2", "bdb_uid": 1, "node_uid": "2", "role": "slave", "assigned_slots": "0-16383"},
+                {"uid": "3", "bdb_uid": 2, "node_uid": "1", "role": "master"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let topology = client.cluster().topology_typed().await.unwrap();
+    assert_eq!(topology.nodes.len(), 2);
+    assert_eq!(topology.node(2).unwrap().rack_id.as_deref(), Some("zone-b"));
+    assert!(topology.node(9).is_none());
+
+    let on_node_1: Vec<&str> = topology
+        .shards_on_node(1)
+        .iter()
+        .map(|shard| shard.uid.as_str())
+        .collect();
+    assert_eq!(on_node_1, vec!["1", "3"]);
+    assert_eq!(topology.shards_for_database(1).len(), 2);
+    assert!(topology.shards_on_node(3).is_empty());
+}
+
+#[tokio::test]
+async fn test_cluster_info_etag_caching() {
+    use wiremock::matchers::header;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(
+            success_response(json!({"name": "cached-cluster"})).insert_header("ETag", "\"v1\""),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .caching(true)
+        .build()
+        .unwrap();
+
+    let handler = ClusterHandler::new(client);
+    for _ in 0..3 {
+        let info = handler.info().await.unwrap();
+        assert_eq!(info.name, "cached-cluster");
+    }
+}
+
+#[tokio::test]
+async fn test_etag_cache_evicts_least_recently_used() {
+    let mock_server = MockServer::start().await;
+
+    // Once evicted, /v1/cluster must be fetched without revalidation
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .and(header("If-None-Match", "\"c1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(
+            success_response(json!({"name": "cached-cluster"})).insert_header("ETag", "\"c1\""),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/policy"))
+        .respond_with(success_response(json!({})).insert_header("ETag", "\"p1\""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .caching(true)
+        .cache_capacity(1)
+        .build()
+        .unwrap();
+
+    let _: serde_json::Value = client.get("/v1/cluster").await.unwrap();
+    let _: serde_json::Value = client.get("/v1/cluster/policy").await.unwrap();
+    let info: serde_json::Value = client.get("/v1/cluster").await.unwrap();
+    assert_eq!(info["name"], "cached-cluster");
+}
+
+#[tokio::test]
+async fn test_cluster_info_without_caching_sends_no_etag() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(
+            success_response(json!({"name": "fresh-cluster"})).insert_header("ETag", "\"v1\""),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ClusterHandler::new(client);
+    handler.info().await.unwrap();
+    handler.info().await.unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(
+        requests
+            .iter()
+            .all(|r| !r.headers.contains_key("if-none-match"))
+    );
+}
+
+#[tokio::test]
+async fn test_cluster_health_report() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(json!({"name": "prod.example.com"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "address": "10.0.0.1", "status": "active"},
+            {"uid": 2, "address": "10.0.0.2", "status": "down"},
+            {"uid": 3, "address": "10.0.0.3", "status": "active"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/alerts"))
+        .respond_with(success_response(json!([
+            {"uid": "a1", "name": "node_failed", "severity": "CRITICAL", "state": "active"},
+            {"uid": "a2", "name": "node_memory", "severity": "WARNING", "state": "active"},
+            {"uid": "a3", "name": "cluster_certs", "severity": "CRITICAL", "state": "resolved"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/license"))
+        .respond_with(success_response(json!({
+            "type": "commercial",
+            "expired": false,
+            "expiration_date": "2027-01-01T00:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let health = client.cluster().health_report().await.unwrap();
+    assert_eq!(health.cluster_name, "prod.example.com");
+    assert_eq!(health.node_count, 3);
+    assert_eq!(health.degraded_nodes.len(), 1);
+    assert_eq!(health.degraded_nodes[0].uid, 2);
+    assert_eq!(health.active_critical_alerts.len(), 1);
+    assert_eq!(health.active_critical_alerts[0].uid, "a1");
+    assert!(health.license_ok);
+    assert!(!health.is_healthy());
+}
+
+#[tokio::test]
+async fn test_cluster_policy_typed_and_partial_update() {
+    let mock_server = MockServer::start().await;
+
+    let current = json!({
+        "default_shards_placement": "dense",
+        "default_sharded_proxy_policy": "all-master-shards",
+        "default_non_sharded_proxy_policy": "single",
+        "rack_aware": false,
+        "persistent_node_removal": true,
+        "default_redis_version": "7.2"
+    });
+    let updated = json!({
+        "default_shards_placement": "sparse",
+        "default_sharded_proxy_policy": "all-master-shards",
+        "default_non_sharded_proxy_policy": "single",
+        "rack_aware": true,
+        "persistent_node_removal": true,
+        "default_redis_version": "7.2"
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/policy"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(current))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster/policy"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({
+            "default_shards_placement": "sparse",
+            "rack_aware": true
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/policy"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(updated))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = ClusterHandler::new(client);
+
+    let before = handler.policy_typed().await.unwrap();
+    assert_eq!(before.default_shards_placement.as_deref(), Some("dense"));
+    assert_eq!(before.rack_aware, Some(false));
+    assert_eq!(before.extra["default_redis_version"], "7.2");
+
+    let change = ClusterPolicy::builder()
+        .default_shards_placement("sparse")
+        .rack_aware(true)
+        .build();
+    let after = handler.update_policy(change).await.unwrap();
+    assert_ne!(before, after);
+    assert_eq!(after.default_shards_placement.as_deref(), Some("sparse"));
+    assert_eq!(after.rack_aware, Some(true));
+    assert_eq!(after.extra, before.extra);
+}
+
+#[tokio::test]
+async fn test_cluster_services_configuration_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/services_configuration"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "cm_server": {"operating_mode": "enabled"},
+            "mdns_server": {"operating_mode": "disabled"},
+            "stats_archiver": {"operating_mode": "enabled", "retention_days": 7},
+            "entraid_agent_mgr": {"operating_mode": "disabled"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/cluster/services_configuration"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({
+            "mdns_server": {"operating_mode": "enabled"}
+        })))
+        .respond_with(success_response(json!({
+            "cm_server": {"operating_mode": "enabled"},
+            "mdns_server": {"operating_mode": "enabled"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = ClusterHandler::new(client);
+
+    let services = handler.services_configuration_typed().await.unwrap();
+    assert!(services.cm_server.as_ref().unwrap().is_enabled());
+    assert!(!services.mdns_server.as_ref().unwrap().is_enabled());
+    assert_eq!(
+        services.stats_archiver.as_ref().unwrap().extra["retention_days"],
+        7
+    );
+    assert!(services.pdns_server.is_none());
+    assert_eq!(
+        services.extra["entraid_agent_mgr"]["operating_mode"],
+        "disabled"
+    );
+
+    let change = ServicesConfiguration::builder()
+        .mdns_server(ClusterServiceConfig::enabled())
+        .build();
+    let updated = handler.update_services_configuration(change).await.unwrap();
+    assert!(updated.mdns_server.unwrap().is_enabled());
+}
+
+#[test]
+fn test_cluster_service_config_enabled_flag() {
+    let flag: ClusterServiceConfig = serde_json::from_value(json!({"enabled": true})).unwrap();
+    assert!(flag.is_enabled());
+    assert!(!ClusterServiceConfig::default().is_enabled());
+    assert!(!ClusterServiceConfig::disabled().is_enabled());
+}
+
+#[tokio::test]
+async fn test_cluster_witness_disk_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/witness_disk"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "configured": true,
+            "healthy": true,
+            "path": "/mnt/witness",
+            "nodes": [
+                {"node_uid": 1, "reachable": true},
+                {"node_uid": 2, "reachable": false, "error": "stale file handle"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let status = ClusterHandler::new(client)
+        .witness_disk_status()
+        .await
+        .unwrap();
+    assert!(status.configured);
+    assert_eq!(status.path.as_deref(), Some("/mnt/witness"));
+    assert_eq!(status.unreachable_nodes(), vec![2]);
+    assert_eq!(status.nodes[1].error.as_deref(), Some("stale file handle"));
+    assert!(!status.is_quorum_protected());
+}
+
+#[test]
+fn test_witness_disk_quorum_protection() {
+    let protected: WitnessDiskStatus = serde_json::from_value(json!({
+        "configured": true,
+        "healthy": true,
+        "nodes": [{"node_uid": 1, "reachable": true}]
+    }))
+    .unwrap();
+    assert!(protected.is_quorum_protected());
+
+    let unconfigured: WitnessDiskStatus = serde_json::from_value(json!({})).unwrap();
+    assert!(!unconfigured.is_quorum_protected());
+
+    let unhealthy: WitnessDiskStatus =
+        serde_json::from_value(json!({"configured": true, "healthy": false})).unwrap();
+    assert!(!unhealthy.is_quorum_protected());
+}
+
+#[tokio::test]
+async fn test_cluster_module_capabilities_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/module-capabilities"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "all_capabilities": [
+                {"name": "types", "desc": "module has its own types"},
+                {"name": "crdb", "desc": "module can be used in Active-Active databases"}
+            ],
+            "modules": [
+                {
+                    "name": "ReJSON",
+                    "display_name": "RedisJSON",
+                    "semantic_version": "2.6.6",
+                    "capabilities": ["types", "crdb"]
+                },
+                {
+                    "module_name": "search",
+                    "semantic_version": "2.8.4",
+                    "versions": ["2.6.12"],
+                    "capabilities": ["types"]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let caps = ClusterHandler::new(client)
+        .module_capabilities_typed()
+        .await
+        .unwrap();
+    assert_eq!(caps.all_capabilities.len(), 2);
+    assert_eq!(caps.modules.len(), 2);
+    assert_eq!(caps.module("redisjson").unwrap().module_name, "ReJSON");
+    assert!(caps.module("timeseries").is_none());
+}
+
+#[test]
+fn test_module_capabilities_supports() {
+    let caps: ModuleCapabilities = serde_json::from_value(json!({
+        "modules": [
+            {"module_name": "ReJSON", "display_name": "RedisJSON", "semantic_version": "2.6.6"},
+            {"module_name": "search", "semantic_version": "2.8.4", "versions": ["1.6.16"]},
+            {"module_name": "custom", "semantic_version": "nightly"}
+        ]
+    }))
+    .unwrap();
+
+    assert!(caps.supports("ReJSON", "2.6.6"));
+    assert!(caps.supports("rejson", "2.6"));
+    assert!(caps.supports("RedisJSON", "2.4.1"));
+    assert!(!caps.supports("ReJSON", "2.8"));
+    assert!(!caps.supports("ReJSON", "3.0"));
+    assert!(caps.supports("search", "1.6"));
+    assert!(caps.supports("search", "2.8.0"));
+    assert!(!caps.supports("timeseries", "1.0"));
+    assert!(caps.supports("custom", "nightly"));
+    assert!(!caps.supports("custom", "1.0"));
+}
+
+#[tokio::test]
+async fn test_client_capabilities_are_cached() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(json!({
+            "name": "cluster1.local",
+            "version": "7.4.2-54",
+            "crdt_supported_featureset_version": 8,
+            "crdt_supported_protocol_versions": ["1", "2"],
+            "password_min_length": 12,
+            "password_complexity": true
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/module-capabilities"))
+        .respond_with(success_response(json!({
+            "modules": [{"module_name": "search", "capabilities": ["types"]}]
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let caps = client.capabilities().await.unwrap();
+    assert_eq!(caps.version, Some("7.4.2-54".parse().unwrap()));
+    assert!(caps.version_at_least(ClusterVersion::new(7, 2, 0)));
+    assert_eq!(caps.crdt_featureset_version, Some(8));
+    assert_eq!(caps.crdt_protocol_versions, vec!["1", "2"]);
+    assert_eq!(caps.password_policy.min_length, Some(12));
+    assert!(caps.password_policy.complexity);
+    assert!(caps.supports_module("Search"));
+    assert!(!caps.supports_module("timeseries"));
+
+    // Clones share the cache; only a refresh fetches again
+    let again = client.clone().capabilities().await.unwrap();
+    assert!(std::sync::Arc::ptr_eq(&caps, &again));
+    let refreshed = client.refresh_capabilities().await.unwrap();
+    assert!(!std::sync::Arc::ptr_eq(&caps, &refreshed));
+}
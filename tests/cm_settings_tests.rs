@@ -1,6 +1,6 @@
 //! Cluster Manager settings tests for Redis Enterprise
 
-use redis_enterprise::{CmSettings, CmSettingsHandler, EnterpriseClient};
+use redis_enterprise::{CmSettings, CmSettingsHandler, CmSettingsUpdate, EnterpriseClient};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -418,3 +418,89 @@ async fn test_cm_settings_workflow() {
     assert_eq!(updated_settings.auto_recovery, Some(true));
     assert_eq!(updated_settings.max_simultaneous_backups, Some(3));
 }
+
+#[tokio::test]
+async fn test_cm_settings_typed_partial_update() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/cm_settings"))
+        .and(body_json(json!({
+            "cm_session_timeout": 900,
+            "login_message": "Authorized use only",
+            "banner": "PRODUCTION"
+        })))
+        .respond_with(success_response(json!({
+            "cm_port": 8443,
+            "cm_session_timeout": 900,
+            "login_message": "Authorized use only",
+            "banner": "PRODUCTION",
+            "timezone": "UTC",
+            "ui_theme": "dark"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let update = CmSettingsUpdate::builder()
+        .cm_session_timeout(900)
+        .login_message("Authorized use only")
+        .banner("PRODUCTION")
+        .build();
+    let settings = CmSettingsHandler::new(client).update(update).await.unwrap();
+
+    assert_eq!(settings.cm_session_timeout, Some(900));
+    assert_eq!(settings.cm_port, Some(8443));
+    assert_eq!(
+        settings.login_message.as_deref(),
+        Some("Authorized use only")
+    );
+    assert_eq!(settings.banner.as_deref(), Some("PRODUCTION"));
+    assert_eq!(settings.timezone.as_deref(), Some("UTC"));
+    assert_eq!(settings.extra["ui_theme"], "dark");
+}
+
+#[tokio::test]
+async fn test_cm_settings_info_roundtrip() {
+    let mock_server = MockServer::start().await;
+    let stored = json!({
+        "cm_port": 8443,
+        "login_message": "Authorized use only",
+        "timezone": "UTC",
+        "ui_theme": "dark"
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cm_settings"))
+        .respond_with(success_response(stored.clone()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/cm_settings"))
+        .and(body_json(stored.clone()))
+        .respond_with(success_response(stored.clone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = CmSettingsHandler::new(client);
+
+    let info = handler.get_info().await.unwrap();
+    assert_eq!(info.extra["ui_theme"], "dark");
+    assert_eq!(serde_json::to_value(&info).unwrap(), stored);
+
+    let updated = handler.update(info).await.unwrap();
+    assert_eq!(updated.timezone.as_deref(), Some("UTC"));
+}
@@ -1,7 +1,8 @@
 //! CRDB tasks endpoint tests for Redis Enterprise
 
-use redis_enterprise::{CrdbTasksHandler, CreateCrdbTaskRequest, EnterpriseClient};
+use redis_enterprise::{CrdbTaskStatus, CrdbTasksHandler, CreateCrdbTaskRequest, EnterpriseClient};
 use serde_json::json;
+use std::time::Duration;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -508,3 +509,118 @@ async fn test_crdb_tasks_list_by_crdb_nonexistent() {
 
     assert!(result.is_err());
 }
+
+fn test_client(mock_server: &MockServer) -> EnterpriseClient {
+    EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_crdb_task_status_parsing() {
+    assert_eq!(CrdbTaskStatus::from("queued"), CrdbTaskStatus::Queued);
+    assert_eq!(CrdbTaskStatus::from("started"), CrdbTaskStatus::Running);
+    assert_eq!(CrdbTaskStatus::from("Finished"), CrdbTaskStatus::Completed);
+    assert_eq!(CrdbTaskStatus::from("canceled"), CrdbTaskStatus::Cancelled);
+    assert_eq!(
+        CrdbTaskStatus::from("paused"),
+        CrdbTaskStatus::Unknown("paused".to_string())
+    );
+    assert!(CrdbTaskStatus::Failed.is_terminal());
+    assert!(!CrdbTaskStatus::Running.is_terminal());
+    assert!(!CrdbTaskStatus::Unknown("paused".to_string()).is_terminal());
+    assert_eq!(CrdbTaskStatus::Cancelled.to_string(), "cancelled");
+}
+
+#[tokio::test]
+async fn test_crdb_tasks_wait_completes() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/crdb_tasks/task-123"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_crdb_task()))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let mut completed = test_crdb_task();
+    completed["status"] = json!("completed");
+    completed["progress"] = json!(100.0);
+    Mock::given(method("GET"))
+        .and(path("/v1/crdb_tasks/task-123"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(completed))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let handler = CrdbTasksHandler::new(test_client(&mock_server));
+    let task = handler
+        .wait(
+            "task-123",
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(task.task_status(), CrdbTaskStatus::Completed);
+    assert_eq!(task.progress, Some(100.0));
+}
+
+#[tokio::test]
+async fn test_crdb_tasks_wait_returns_failed_task() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/crdb_tasks/task-999"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_failed_task()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let handler = CrdbTasksHandler::new(test_client(&mock_server));
+    let task = handler
+        .wait(
+            "task-999",
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(task.task_status(), CrdbTaskStatus::Failed);
+    assert_eq!(
+        task.error.as_deref(),
+        Some("Connection timeout during restore")
+    );
+}
+
+#[tokio::test]
+async fn test_crdb_tasks_wait_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/crdb_tasks/task-123"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_crdb_task()))
+        .mount(&mock_server)
+        .await;
+
+    let handler = CrdbTasksHandler::new(test_client(&mock_server));
+    let result = handler
+        .wait(
+            "task-123",
+            Duration::from_millis(20),
+            Duration::from_millis(50),
+        )
+        .await;
+
+    assert!(result.unwrap_err().is_timeout());
+}
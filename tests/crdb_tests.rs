@@ -582,3 +582,74 @@ async fn test_crdb_tasks_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_crdb_instance_validation() {
+    let with_url = CreateCrdbInstance::builder()
+        .cluster("cluster1.example.com")
+        .cluster_url("https://cluster1.example.com:9443")
+        .build();
+    assert!(with_url.validate().is_ok());
+
+    let host_port = CreateCrdbInstance::builder()
+        .cluster("cluster2.example.com:9443")
+        .build();
+    assert!(host_port.validate().is_ok());
+
+    let bare_name = CreateCrdbInstance::builder()
+        .cluster("cluster3.example.com")
+        .build();
+    let err = bare_name.validate().unwrap_err();
+    assert!(err.is_bad_request());
+    assert!(err.to_string().contains("host:port"));
+
+    let bad_url = CreateCrdbInstance::builder()
+        .cluster("cluster4.example.com")
+        .cluster_url("cluster4.example.com:9443")
+        .build();
+    assert!(
+        bad_url
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("invalid cluster_url")
+    );
+}
+
+#[tokio::test]
+async fn test_crdb_create_checked_rejects_invalid_instance() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/crdbs"))
+        .respond_with(created_response(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let request = CreateCrdbRequest::builder()
+        .name("global-cache")
+        .memory_size(1073741824)
+        .instances(vec![
+            CreateCrdbInstance::builder()
+                .cluster("us-east.example.com:9443")
+                .build(),
+            CreateCrdbInstance::builder()
+                .cluster("eu-west.example.com")
+                .build(),
+        ])
+        .build();
+    let err = CrdbHandler::new(client)
+        .create_checked(request)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("eu-west.example.com"));
+}
@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use redis_enterprise::EnterpriseClient;
     use redis_enterprise::debuginfo::{DebugInfoHandler, DebugInfoRequest, TimeRange};
+    use redis_enterprise::{EnterpriseClient, RestError};
     use serde_json::json;
-    use wiremock::matchers::{basic_auth, method, path};
+    use std::time::Duration;
+    use wiremock::matchers::{basic_auth, body_partial_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup_mock_client(mock_server: &MockServer) -> DebugInfoHandler {
@@ -462,4 +463,181 @@ mod tests {
         let data = handler.node_bdb_binary(4).await.unwrap();
         assert_eq!(data, tar_gz_data);
     }
+
+    #[test]
+    fn test_time_range_split() {
+        let range = TimeRange {
+            start: "2024-01-01T00:00:00Z".to_string(),
+            end: "2024-01-01T02:30:00Z".to_string(),
+        };
+        let windows = range.split(Duration::from_secs(3600)).unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].start, "2024-01-01T00:00:00+00:00");
+        assert_eq!(windows[1].start, "2024-01-01T01:00:00+00:00");
+        assert_eq!(windows[2].end, "2024-01-01T02:30:00+00:00");
+
+        assert!(range.split(Duration::ZERO).is_err());
+        let reversed = TimeRange {
+            start: range.end.clone(),
+            end: range.start.clone(),
+        };
+        assert!(reversed.split(Duration::from_secs(60)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_collect_windowed() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/debuginfo"))
+            .and(body_partial_json(
+                json!({"time_range": {"start": "2024-01-01T01:00:00+00:00"}}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "task_id": "window-2",
+                "status": "completed"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/debuginfo/window-2/download"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"second".to_vec()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // The first window was downloaded by an earlier, interrupted run
+        let out_dir =
+            std::env::temp_dir().join(format!("redis-enterprise-debuginfo-{}", std::process::id()));
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let first = out_dir.join("debuginfo-20240101T000000Z-20240101T010000Z.tar.gz");
+        std::fs::write(&first, b"first").unwrap();
+        // ...which was cut off while streaming the second
+        let partial = out_dir.join("debuginfo-20240101T010000Z-20240101T020000Z.tar.gz.partial");
+        std::fs::write(&partial, b"sec").unwrap();
+
+        let range = TimeRange {
+            start: "2024-01-01T00:00:00Z".to_string(),
+            end: "2024-01-01T02:00:00Z".to_string(),
+        };
+        let paths = handler
+            .collect_windowed(
+                &range,
+                Duration::from_secs(3600),
+                &out_dir,
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], first);
+        assert_eq!(std::fs::read(&paths[1]).unwrap(), b"second");
+        assert!(!partial.exists());
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_collect_windowed_cancelled_task() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/debuginfo"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "task_id": "window-1",
+                "status": "cancelled"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let out_dir = std::env::temp_dir().join(format!(
+            "redis-enterprise-debuginfo-cancelled-{}",
+            std::process::id()
+        ));
+        let range = TimeRange {
+            start: "2024-01-01T00:00:00Z".to_string(),
+            end: "2024-01-01T01:00:00Z".to_string(),
+        };
+        let result = handler
+            .collect_windowed(
+                &range,
+                Duration::from_secs(3600),
+                &out_dir,
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await;
+
+        assert!(
+            matches!(result, Err(RestError::ServerError { message: ref msg, .. }) if msg.contains("cancelled"))
+        );
+        assert!(std::fs::read_dir(&out_dir).unwrap().next().is_none());
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_collect_windowed_timeout_and_io_errors() {
+        let mock_server = MockServer::start().await;
+        let handler = setup_mock_client(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/debuginfo"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "task_id": "window-1",
+                "status": "running"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/debuginfo/window-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "task_id": "window-1",
+                "status": "running"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let range = TimeRange {
+            start: "2024-01-01T00:00:00Z".to_string(),
+            end: "2024-01-01T01:00:00Z".to_string(),
+        };
+        let out_dir = std::env::temp_dir().join(format!(
+            "redis-enterprise-debuginfo-timeout-{}",
+            std::process::id()
+        ));
+        let result = handler
+            .collect_windowed(
+                &range,
+                Duration::from_secs(3600),
+                &out_dir,
+                Duration::from_millis(10),
+                Duration::from_millis(50),
+            )
+            .await;
+        assert!(matches!(result, Err(RestError::Timeout)));
+        std::fs::remove_dir_all(&out_dir).unwrap();
+
+        // A regular file where the output directory should be
+        let blocker = std::env::temp_dir().join(format!(
+            "redis-enterprise-debuginfo-blocker-{}",
+            std::process::id()
+        ));
+        std::fs::write(&blocker, b"").unwrap();
+        let result = handler
+            .collect_windowed(
+                &range,
+                Duration::from_secs(3600),
+                blocker.join("out"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+            )
+            .await;
+        assert!(matches!(result, Err(RestError::IoError(_))));
+        std::fs::remove_file(&blocker).unwrap();
+    }
 }
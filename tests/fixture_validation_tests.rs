@@ -9,7 +9,7 @@
 //!   They should be Option<Value> to handle empty objects {} from API
 
 use redis_enterprise::{ClusterInfo, Database, License, Module, Node, User};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 #[test]
 fn test_cluster_info_from_fixture() {
@@ -34,6 +34,61 @@ fn test_single_database_from_fixture() {
         serde_json::from_str(fixture).expect("Failed to deserialize single database");
 }
 
+#[test]
+fn test_database_info_serde_roundtrip() {
+    let fixture = include_str!("fixtures/bdb_single.json");
+    let original: Database =
+        serde_json::from_str(fixture).expect("Failed to deserialize single database");
+
+    let serialized = serde_json::to_string(&original).expect("Failed to serialize database");
+    let roundtripped: Database =
+        serde_json::from_str(&serialized).expect("Failed to deserialize serialized database");
+    assert_eq!(original, roundtripped);
+
+    // Serializing again gives the same JSON, so persisted copies are stable
+    let reserialized = serde_json::to_string(&roundtripped).unwrap();
+    assert_eq!(serialized, reserialized);
+}
+
+#[test]
+fn test_database_info_extra_never_shadows_typed_fields() {
+    let fixture = include_str!("fixtures/bdb_single.json");
+    let mut database: Database =
+        serde_json::from_str(fixture).expect("Failed to deserialize single database");
+    let uid = database.uid;
+    let uid_keys = serde_json::to_string(&database)
+        .unwrap()
+        .matches("\"uid\":")
+        .count();
+    database.extra.insert("uid".to_string(), json!(uid + 1000));
+    database
+        .extra
+        .insert("future_setting".to_string(), json!("on"));
+
+    let serialized = serde_json::to_string(&database).expect("Failed to serialize database");
+    assert_eq!(serialized.matches("\"uid\":").count(), uid_keys);
+
+    let value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(value["uid"], uid);
+    assert_eq!(value["future_setting"], "on");
+
+    let roundtripped: Database = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(roundtripped.uid, uid);
+    assert!(!roundtripped.extra.contains_key("uid"));
+    assert_eq!(roundtripped.extra["future_setting"], "on");
+}
+
+#[test]
+fn test_database_list_serde_roundtrip() {
+    let fixture = include_str!("fixtures/bdbs_list.json");
+    let original: Vec<Database> =
+        serde_json::from_str(fixture).expect("Failed to deserialize database list");
+    let serialized = serde_json::to_value(&original).expect("Failed to serialize databases");
+    let roundtripped: Vec<Database> =
+        serde_json::from_value(serialized).expect("Failed to deserialize serialized databases");
+    assert_eq!(original, roundtripped);
+}
+
 #[test]
 fn test_nodes_list_from_fixture() {
     let fixture = include_str!("fixtures/nodes_list.json");
@@ -278,6 +278,7 @@ async fn test_job_scheduler_create() {
             "report_type": "usage",
             "email_recipients": ["admin@company.com"]
         })),
+        validate: false,
     };
 
     Mock::given(method("POST"))
@@ -329,6 +330,7 @@ async fn test_job_scheduler_create_minimal() {
         schedule: "0 0 * * *".to_string(),
         enabled: None,
         params: None,
+        validate: false,
     };
 
     Mock::given(method("POST"))
@@ -374,6 +376,7 @@ async fn test_job_scheduler_create_invalid() {
         schedule: "invalid cron".to_string(),
         enabled: Some(true),
         params: None,
+        validate: false,
     };
 
     Mock::given(method("POST"))
@@ -411,6 +414,7 @@ async fn test_job_scheduler_update() {
             "backup_location": "/backups/updated",
             "retention_days": 14
         })),
+        validate: false,
     };
 
     Mock::given(method("PUT"))
@@ -463,6 +467,7 @@ async fn test_job_scheduler_update_nonexistent() {
         schedule: "0 0 * * *".to_string(),
         enabled: None,
         params: None,
+        validate: false,
     };
 
     Mock::given(method("PUT"))
@@ -691,3 +696,144 @@ async fn test_job_scheduler_history_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_validate_cron_accepts_common_expressions() {
+    use redis_enterprise::job_scheduler::validate_cron;
+
+    for expr in [
+        "0 2 * * *",
+        "*/15 * * * *",
+        "0 9-17 * * MON-FRI",
+        "30 4 1,15 * *",
+        "0 0 1 JAN *",
+        "5/10 0 * * 7",
+        "@hourly",
+        "@daily",
+    ] {
+        assert!(validate_cron(expr).is_ok(), "{} should be valid", expr);
+    }
+}
+
+#[test]
+fn test_validate_cron_reports_position() {
+    use redis_enterprise::job_scheduler::validate_cron;
+
+    let error = validate_cron("0 25 * * *").unwrap_err();
+    assert!(matches!(
+        error,
+        redis_enterprise::RestError::ValidationError(_)
+    ));
+    assert!(error.to_string().contains("position 2"), "{}", error);
+
+    let error = validate_cron("0 0 * * 1-FOO").unwrap_err();
+    assert!(error.to_string().contains("position 10"), "{}", error);
+
+    let error = validate_cron("*/0 * * * *").unwrap_err();
+    assert!(error.to_string().contains("position 2"), "{}", error);
+
+    let error = validate_cron("0 0 * *").unwrap_err();
+    assert!(error.to_string().contains("expected 5 fields"), "{}", error);
+
+    assert!(validate_cron("@reboot").is_err());
+}
+
+#[tokio::test]
+async fn test_job_scheduler_create_validates_schedule() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/job_scheduler"))
+        .respond_with(created_response(test_scheduled_job()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = JobSchedulerHandler::new(client);
+
+    let request = CreateScheduledJobRequest::builder()
+        .name("Nightly Backup")
+        .job_type("backup")
+        .schedule("0 2 * *")
+        .validate(true)
+        .build();
+    let error = handler.create(request).await.unwrap_err();
+    assert!(matches!(
+        error,
+        redis_enterprise::RestError::ValidationError(_)
+    ));
+}
+
+fn scheduled_job(schedule: &str) -> redis_enterprise::ScheduledJob {
+    let mut job = test_scheduled_job();
+    job["schedule"] = json!(schedule);
+    serde_json::from_value(job).unwrap()
+}
+
+fn utc(s: &str) -> chrono::DateTime<chrono::Utc> {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_scheduled_job_upcoming_runs_hourly() {
+    let job = scheduled_job("0 * * * *");
+    let runs = job.upcoming_runs(3, utc("2024-03-10T01:30:00Z"));
+    assert_eq!(
+        runs,
+        vec![
+            utc("2024-03-10T02:00:00Z"),
+            utc("2024-03-10T03:00:00Z"),
+            utc("2024-03-10T04:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn test_scheduled_job_upcoming_runs_weekdays_and_month_rollover() {
+    // 2024-05-31 is a Friday
+    let job = scheduled_job("30 9 * * MON-FRI");
+    let runs = job.upcoming_runs(2, utc("2024-05-31T09:30:00Z"));
+    assert_eq!(
+        runs,
+        vec![utc("2024-06-03T09:30:00Z"), utc("2024-06-04T09:30:00Z")]
+    );
+
+    let job = scheduled_job("@yearly");
+    let runs = job.upcoming_runs(1, utc("2024-05-31T00:00:00Z"));
+    assert_eq!(runs, vec![utc("2025-01-01T00:00:00Z")]);
+}
+
+#[test]
+fn test_scheduled_job_upcoming_runs_day_fields_combine_with_or() {
+    // The 1st of the month or any Sunday; 2024-06-01 is a Saturday
+    let job = scheduled_job("0 0 1 * SUN");
+    let runs = job.upcoming_runs(3, utc("2024-05-31T12:00:00Z"));
+    assert_eq!(
+        runs,
+        vec![
+            utc("2024-06-01T00:00:00Z"),
+            utc("2024-06-02T00:00:00Z"),
+            utc("2024-06-09T00:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn test_scheduled_job_upcoming_runs_invalid_or_impossible() {
+    assert!(
+        scheduled_job("not a cron")
+            .upcoming_runs(3, utc("2024-01-01T00:00:00Z"))
+            .is_empty()
+    );
+    assert!(
+        scheduled_job("0 0 30 2 *")
+            .upcoming_runs(1, utc("2024-01-01T00:00:00Z"))
+            .is_empty()
+    );
+}
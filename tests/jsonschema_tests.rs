@@ -1,5 +1,7 @@
 //! JSON Schema endpoint tests for Redis Enterprise
 
+#[cfg(feature = "schema-validation")]
+use redis_enterprise::{CreateDatabaseRequest, SchemaResource};
 use redis_enterprise::{EnterpriseClient, JsonSchemaHandler};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
@@ -534,3 +536,82 @@ async fn test_jsonschema_validate_empty_object() {
     assert!(!validation["valid"].as_bool().unwrap());
     assert_eq!(validation["errors"].as_array().unwrap().len(), 2);
 }
+
+#[cfg(feature = "schema-validation")]
+#[tokio::test]
+async fn test_jsonschema_validate_local() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/jsonschema/bdb"))
+        .respond_with(success_response(json!({
+            "type": "object",
+            "definitions": {
+                "policy": {"type": "string", "enum": ["noeviction", "allkeys-lru"]}
+            },
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "memory_size": {"type": "integer", "minimum": 1},
+                "port": {"type": "integer", "minimum": 1, "maximum": 65535},
+                "eviction_policy": {"$ref": "#/definitions/policy"},
+                "module_list": {
+                    "type": "array",
+                    "items": {"type": "object", "required": ["module_name"]}
+                }
+            },
+            "required": ["name", "memory_size"],
+            "additionalProperties": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = JsonSchemaHandler::new(client);
+
+    let valid = CreateDatabaseRequest::builder()
+        .name("cache")
+        .memory_size(1024)
+        .eviction_policy("allkeys-lru")
+        .build();
+    assert_eq!(
+        handler
+            .validate_local(SchemaResource::Bdb, &valid)
+            .await
+            .unwrap(),
+        Ok(())
+    );
+
+    // The schema is fetched once and reused
+    let errors = handler
+        .validate_local(
+            "bdb",
+            &json!({
+                "name": "cache",
+                "port": 70000,
+                "eviction_policy": "volatile-ttl",
+                "module_list": [{"module_args": ""}],
+                "shards": 2
+            }),
+        )
+        .await
+        .unwrap()
+        .unwrap_err();
+    let mut paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+    paths.sort_unstable();
+    assert_eq!(
+        paths,
+        vec!["", "", "/eviction_policy", "/module_list/0", "/port"]
+    );
+    assert!(
+        errors
+            .iter()
+            .any(|error| error.message.contains("memory_size"))
+    );
+    assert!(errors.iter().any(|error| error.message.contains("shards")));
+}
@@ -477,3 +477,71 @@ async fn test_ldap_update_config_invalid() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_ldap_mapping_test_user() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/cluster/ldap/test"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({"username": "jdoe", "password": "secret"})))
+        .respond_with(success_response(json!({
+            "success": true,
+            "dn": "uid=jdoe,ou=people,dc=example,dc=com",
+            "groups": ["cn=dbas,ou=groups,dc=example,dc=com"],
+            "roles": ["db_admin"],
+            "role_uids": [3]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = LdapMappingHandler::new(client);
+    let result = handler.test("jdoe", "secret").await.unwrap();
+
+    assert!(result.success);
+    assert_eq!(
+        result.dn.as_deref(),
+        Some("uid=jdoe,ou=people,dc=example,dc=com")
+    );
+    assert_eq!(result.groups, vec!["cn=dbas,ou=groups,dc=example,dc=com"]);
+    assert_eq!(result.roles, vec!["db_admin"]);
+    assert_eq!(result.role_uids, vec![3]);
+    assert!(result.error.is_none());
+}
+
+#[tokio::test]
+async fn test_ldap_mapping_test_user_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/cluster/ldap/test"))
+        .respond_with(success_response(json!({
+            "success": false,
+            "error": "Invalid credentials"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = LdapMappingHandler::new(client);
+    let result = handler.test("jdoe", "wrong").await.unwrap();
+
+    assert!(!result.success);
+    assert!(result.groups.is_empty());
+    assert!(result.roles.is_empty());
+    assert_eq!(result.error.as_deref(), Some("Invalid credentials"));
+}
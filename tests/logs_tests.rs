@@ -1,6 +1,6 @@
 //! Logs endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, LogsHandler, LogsQuery};
+use redis_enterprise::{EnterpriseClient, LogsHandler, LogsQuery, RestError};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -326,7 +326,9 @@ async fn test_list_logs_error_response() {
 
     assert!(result.is_err());
     let error = result.unwrap_err();
-    assert!(error.to_string().contains("403"));
+    assert!(error.is_forbidden());
+    assert!(!error.is_unauthorized());
+    assert!(error.to_string().contains("Insufficient permissions"));
 }
 
 #[tokio::test]
@@ -352,3 +354,99 @@ async fn test_list_logs_authentication_error() {
 
     assert!(result.is_err());
 }
+
+fn log_at(time: &str, event_type: &str) -> serde_json::Value {
+    json!({"time": time, "type": event_type})
+}
+
+#[tokio::test]
+async fn test_list_logs_page_time_window_fallback() {
+    let mock_server = MockServer::start().await;
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password123")
+        .build()
+        .expect("Failed to create test client");
+
+    // Later pages are requested from the last timestamp with stime
+    Mock::given(method("GET"))
+        .and(path("/v1/logs"))
+        .and(query_param("stime", "2023-01-01T12:00:02Z"))
+        .and(query_param("limit", "3"))
+        .respond_with(success_response(json!([
+            log_at("2023-01-01T12:00:02Z", "b"),
+            log_at("2023-01-01T12:00:03Z", "c"),
+            log_at("2023-01-01T12:00:03Z", "d"),
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/logs"))
+        .and(query_param("stime", "2023-01-01T12:00:03Z"))
+        .and(query_param("limit", "4"))
+        .respond_with(success_response(json!([
+            log_at("2023-01-01T12:00:03Z", "c"),
+            log_at("2023-01-01T12:00:03Z", "d"),
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/logs"))
+        .and(query_param("limit", "2"))
+        .and(query_param("order", "asc"))
+        .respond_with(success_response(json!([
+            log_at("2023-01-01T12:00:01Z", "a"),
+            log_at("2023-01-01T12:00:02Z", "b"),
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let handler = LogsHandler::new(client);
+    let mut cursor = None;
+    let mut seen = Vec::new();
+    loop {
+        let query = LogsQuery {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let page = handler.list_page(query, cursor.as_deref()).await.unwrap();
+        seen.extend(page.entries.into_iter().map(|e| e.event_type));
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(seen, vec!["a", "b", "c", "d"]);
+}
+
+#[tokio::test]
+async fn test_list_logs_page_rejects_descending_order() {
+    let mock_server = MockServer::start().await;
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password123")
+        .build()
+        .expect("Failed to create test client");
+
+    let handler = LogsHandler::new(client);
+    let result = handler
+        .list_page(
+            LogsQuery {
+                order: Some("desc".to_string()),
+                limit: Some(1),
+                ..Default::default()
+            },
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(RestError::ValidationError(_))));
+
+    let result = handler
+        .list_page(LogsQuery::default(), Some("opaque-42"))
+        .await;
+    assert!(matches!(result, Err(RestError::ValidationError(_))));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
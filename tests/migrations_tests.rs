@@ -4,6 +4,7 @@ use redis_enterprise::{
     CreateMigrationRequest, EnterpriseClient, MigrationEndpoint, MigrationsHandler,
 };
 use serde_json::json;
+use std::time::Duration;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -627,3 +628,152 @@ async fn test_migrations_resume_not_paused() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_migration_watch_yields_progress_until_finished() {
+    use futures::StreamExt;
+
+    let mock_server = MockServer::start().await;
+
+    for (status, progress, times) in [("syncing", 0.1, 2), ("syncing", 0.5, 1)] {
+        let mut migration = test_migration();
+        migration["status"] = json!(status);
+        migration["progress"] = json!(progress);
+        Mock::given(method("GET"))
+            .and(path("/v1/migrations/migration-123"))
+            .respond_with(success_response(migration))
+            .up_to_n_times(times)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut completed = test_migration();
+    completed["status"] = json!("completed");
+    completed["progress"] = json!(1.0);
+    Mock::given(method("GET"))
+        .and(path("/v1/migrations/migration-123"))
+        .respond_with(success_response(completed))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = MigrationsHandler::new(client);
+
+    let updates: Vec<_> = handler
+        .watch("migration-123", Duration::from_millis(10))
+        .map(|m| m.unwrap())
+        .collect()
+        .await;
+
+    let progress: Vec<_> = updates.iter().map(|m| m.progress).collect();
+    assert_eq!(progress, vec![Some(0.1), Some(0.5), Some(1.0)]);
+    assert!(updates.last().unwrap().is_finished());
+}
+
+/// Start a fake Redis server that reports the bytes received on its first connection
+async fn fake_redis() -> (u16, tokio::sync::oneshot::Receiver<Vec<u8>>) {
+    use tokio::io::AsyncReadExt;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut received = Vec::new();
+        let _ = socket.read_to_end(&mut received).await;
+        let _ = tx.send(received);
+    });
+    (port, rx)
+}
+
+fn external_endpoint(port: u16, password: Option<&str>) -> MigrationEndpoint {
+    MigrationEndpoint {
+        endpoint_type: "redis".to_string(),
+        host: Some("127.0.0.1".to_string()),
+        port: Some(port),
+        bdb_uid: None,
+        password: password.map(str::to_string),
+        ssl: Some(false),
+    }
+}
+
+fn migrations_handler(mock_server: &MockServer) -> MigrationsHandler {
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    MigrationsHandler::new(client)
+}
+
+#[tokio::test]
+async fn test_migration_test_endpoint_reachable() {
+    let mock_server = MockServer::start().await;
+    let (port, received) = fake_redis().await;
+
+    let result = migrations_handler(&mock_server)
+        .test_endpoint(&external_endpoint(port, Some("secret")))
+        .await
+        .unwrap();
+
+    assert!(result.reachable);
+    assert_eq!(result.auth_ok, None);
+    assert!(result.latency.is_some());
+    assert!(result.is_ok());
+    // The password is never sent to the endpoint
+    assert!(received.await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_migration_test_endpoint_unreachable() {
+    let mock_server = MockServer::start().await;
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let result = migrations_handler(&mock_server)
+        .test_endpoint(&external_endpoint(port, None))
+        .await
+        .unwrap();
+
+    assert!(!result.reachable);
+    assert!(!result.is_ok());
+    assert!(result.error.is_some());
+}
+
+#[tokio::test]
+async fn test_migration_test_endpoint_cluster_database() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(success_response(
+            json!({"uid": 1, "name": "target", "status": "active"}),
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let endpoint = MigrationEndpoint {
+        endpoint_type: "bdb".to_string(),
+        host: None,
+        port: None,
+        bdb_uid: Some(1),
+        password: None,
+        ssl: None,
+    };
+    let result = migrations_handler(&mock_server)
+        .test_endpoint(&endpoint)
+        .await
+        .unwrap();
+
+    assert!(result.reachable);
+    assert_eq!(result.auth_ok, None);
+}
@@ -0,0 +1,85 @@
+//! Tests for fanning operations out across several clusters
+
+use redis_enterprise::{EnterpriseClient, MultiClusterClient};
+use serde_json::json;
+use std::sync::Mutex;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client_for(server: &MockServer) -> EnterpriseClient {
+    EnterpriseClient::builder()
+        .base_url(server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap()
+}
+
+async fn cluster_with_databases(count: usize) -> MockServer {
+    let server = MockServer::start().await;
+    let databases: Vec<_> = (1..=count)
+        .map(|uid| json!({"uid": uid, "name": format!("db-{}", uid)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(databases))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_map_concurrent_collects_per_cluster_results() {
+    let east = cluster_with_databases(2).await;
+    let west = cluster_with_databases(3).await;
+    let broken = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .mount(&broken)
+        .await;
+
+    let fleet = MultiClusterClient::new()
+        .with_cluster("us-east", client_for(&east))
+        .with_cluster("us-west", client_for(&west))
+        .with_cluster("eu-central", client_for(&broken))
+        .concurrency(2);
+
+    let counts = fleet
+        .map_concurrent(|_name, client| async move { Ok(client.databases().list().await?.len()) })
+        .await;
+
+    assert_eq!(
+        counts.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["eu-central", "us-east", "us-west"]
+    );
+    assert_eq!(*counts["us-east"].as_ref().unwrap(), 2);
+    assert_eq!(*counts["us-west"].as_ref().unwrap(), 3);
+    assert!(counts["eu-central"].is_err());
+}
+
+#[tokio::test]
+async fn test_for_each_visits_every_cluster() {
+    let a = cluster_with_databases(1).await;
+    let b = cluster_with_databases(1).await;
+    let fleet: MultiClusterClient = [("a", client_for(&a)), ("b", client_for(&b))]
+        .into_iter()
+        .collect();
+    assert_eq!(fleet.len(), 2);
+
+    let visited = Mutex::new(Vec::new());
+    fleet
+        .for_each(|name, client| {
+            let visited = &visited;
+            async move {
+                if client.databases().list().await.is_ok() {
+                    visited.lock().unwrap().push(name);
+                }
+            }
+        })
+        .await;
+
+    let mut visited = visited.into_inner().unwrap();
+    visited.sort();
+    assert_eq!(visited, vec!["a", "b"]);
+}
@@ -1,6 +1,6 @@
 //! Node endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, NodeHandler};
+use redis_enterprise::{EnterpriseClient, Node, NodeHandler, NodeStats};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -92,14 +92,24 @@ async fn test_node_snapshots_and_action_paths() {
     Mock::given(method("GET"))
         .and(path("/v1/nodes/1/snapshots"))
         .and(basic_auth("admin", "password"))
-        .respond_with(success_response(json!(["s1"])))
+        .respond_with(success_response(json!([{
+            "name": "s1",
+            "node_uid": 1,
+            "time": "2024-01-01T00:00:00Z",
+            "size": "1048576"
+        }])))
         .mount(&mock_server)
         .await;
 
     Mock::given(method("POST"))
         .and(path("/v1/nodes/1/snapshots/s1"))
         .and(basic_auth("admin", "password"))
-        .respond_with(success_response(json!({"created": true})))
+        .respond_with(success_response(json!({
+            "name": "s1",
+            "node_uid": 1,
+            "time": "2024-01-02T00:00:00Z",
+            "size": 2048
+        })))
         .mount(&mock_server)
         .await;
 
@@ -134,9 +144,15 @@ async fn test_node_snapshots_and_action_paths() {
     let handler = NodeHandler::new(client);
 
     let snaps = handler.snapshots(1).await.unwrap();
-    assert!(snaps.is_array());
-
-    handler.snapshot_create(1, "s1").await.unwrap();
+    assert_eq!(snaps.len(), 1);
+    assert_eq!(snaps[0].name, "s1");
+    assert_eq!(snaps[0].node_uid, Some(1));
+    assert_eq!(snaps[0].timestamp.as_deref(), Some("2024-01-01T00:00:00Z"));
+    assert_eq!(snaps[0].size, Some(1_048_576));
+
+    let created = handler.snapshot_create(1, "s1").await.unwrap();
+    assert_eq!(created.name, "s1");
+    assert_eq!(created.size, Some(2048));
     handler.snapshot_delete(1, "s1").await.unwrap();
 
     let r = handler
@@ -451,6 +467,39 @@ async fn test_node_stats() {
     assert_eq!(stats.free_memory.unwrap(), 4294967296u64);
     assert_eq!(stats.network_bytes_in.unwrap(), 1024000);
     assert_eq!(stats.network_bytes_out.unwrap(), 2048000);
+
+    // cpu_idle is a fraction of CPU time, so 64.3 is out of range
+    assert_eq!(stats.cpu_utilization(), None);
+    let memory = stats.memory_utilization(17179869184).unwrap();
+    assert!((memory - 75.0).abs() < 1e-9);
+    assert_eq!(stats.memory_utilization(0), None);
+}
+
+#[test]
+fn test_node_stats_utilization_from_fractions() {
+    let stats: NodeStats = serde_json::from_value(json!({
+        "uid": 1,
+        "cpu_user": 0.004,
+        "cpu_system": 0.002,
+        "cpu_idle": 0.99
+    }))
+    .unwrap();
+
+    assert!((stats.cpu_utilization().unwrap() - 1.0).abs() < 1e-9);
+    assert_eq!(stats.memory_utilization(1024), None);
+}
+
+#[test]
+fn test_node_stats_cpu_utilization_bounds() {
+    let with_idle = |idle: f64| -> NodeStats {
+        serde_json::from_value(json!({"uid": 1, "cpu_idle": idle})).unwrap()
+    };
+
+    assert_eq!(with_idle(1.0).cpu_utilization(), Some(0.0));
+    assert_eq!(with_idle(0.0).cpu_utilization(), Some(100.0));
+    assert!((with_idle(0.25).cpu_utilization().unwrap() - 75.0).abs() < 1e-9);
+    assert_eq!(with_idle(1.0001).cpu_utilization(), None);
+    assert_eq!(with_idle(-0.1).cpu_utilization(), None);
 }
 
 #[tokio::test]
@@ -654,3 +703,87 @@ async fn test_node_execute_action_nonexistent_node() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_node_memory_fields_accept_strings() {
+    let mut node = test_node();
+    node["total_memory"] = json!("8589934592");
+    let node: Node = serde_json::from_value(node).unwrap();
+    assert_eq!(node.total_memory, Some(8_589_934_592));
+
+    let stats: NodeStats = serde_json::from_value(json!({
+        "uid": 1,
+        "free_memory": "4294967296",
+        "network_bytes_in": 1.5e6,
+        "network_bytes_out": 2000
+    }))
+    .unwrap();
+    assert_eq!(stats.free_memory, Some(4_294_967_296));
+    assert_eq!(stats.network_bytes_in, Some(1_500_000));
+    assert_eq!(stats.network_bytes_out, Some(2000));
+}
+
+#[tokio::test]
+async fn test_node_maintenance_status_and_wait_until_drained() {
+    let mock_server = MockServer::start().await;
+
+    let mut in_maintenance = test_node();
+    in_maintenance["accept_servers"] = json!(false);
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes/1"))
+        .respond_with(success_response(in_maintenance))
+        .mount(&mock_server)
+        .await;
+
+    let shard = |uid: &str, node_uid: &str| {
+        json!({
+            "uid": uid,
+            "bdb_uid": 1,
+            "node_uid": node_uid,
+            "role": "master",
+            "status": "active"
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(success_response(json!([
+            shard("1", "1"),
+            shard("2", "1"),
+            shard("3", "2")
+        ])))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(success_response(json!([
+            shard("1", "2"),
+            shard("2", "3"),
+            shard("3", "2")
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = NodeHandler::new(client);
+
+    let status = handler.maintenance_status(1).await.unwrap();
+    assert!(status.in_maintenance);
+    assert_eq!(status.shards_remaining, 2);
+    assert!(!status.is_drained());
+
+    let drained = handler
+        .wait_until_drained(
+            1,
+            std::time::Duration::from_millis(10),
+            std::time::Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert!(drained.is_drained());
+}
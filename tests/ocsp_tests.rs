@@ -1,7 +1,8 @@
 //! OCSP endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, OcspConfig, OcspHandler};
+use redis_enterprise::{EnterpriseClient, OcspConfig, OcspHandler, RestError};
 use serde_json::json;
+use std::time::Duration;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -518,3 +519,92 @@ async fn test_ocsp_clear_cache_error() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_ocsp_wait_for_good() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ocsp/status"))
+        .respond_with(success_response(json!({"status": "unknown"})))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ocsp/status"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_ocsp_status()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = OcspHandler::new(client);
+    let status = handler
+        .wait_for_good(Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert!(status.is_good());
+    assert_eq!(status.certificate_status.as_deref(), Some("valid"));
+}
+
+#[tokio::test]
+async fn test_ocsp_wait_for_good_revoked() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ocsp/status"))
+        .respond_with(success_response(test_ocsp_status_revoked()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = OcspHandler::new(client);
+    let error = handler
+        .wait_for_good(Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, RestError::TlsError(_)));
+    assert!(error.to_string().contains("keyCompromise"));
+}
+
+#[tokio::test]
+async fn test_ocsp_wait_for_good_timeout() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ocsp/status"))
+        .respond_with(success_response(json!({"status": "unknown"})))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = OcspHandler::new(client);
+    let result = handler
+        .wait_for_good(Duration::from_millis(10), Duration::from_millis(50))
+        .await;
+    assert!(result.unwrap_err().is_timeout());
+}
@@ -1,8 +1,8 @@
 //! Proxy endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, ProxyHandler};
+use redis_enterprise::{EnterpriseClient, ProxyConfigRequest, ProxyHandler};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
+use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // Test helper functions
@@ -571,3 +571,72 @@ async fn test_proxy_reload_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_proxy_update_partial() {
+    let mock_server = MockServer::start().await;
+
+    // Only the fields that were set are sent
+    Mock::given(method("PUT"))
+        .and(path("/v1/proxies/1"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({"threads": 8, "max_connections": 20000})))
+        .respond_with(success_response(json!({
+            "uid": 1,
+            "bdb_uid": 1,
+            "node_uid": 1,
+            "status": "active",
+            "threads": 8,
+            "max_connections": 20000
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ProxyHandler::new(client);
+    let request = ProxyConfigRequest::builder()
+        .threads(8)
+        .max_connections(20000)
+        .build();
+    let proxy = handler.update(1, request).await.unwrap();
+
+    assert_eq!(proxy.threads, Some(8));
+    assert_eq!(proxy.max_connections, Some(20000));
+}
+
+#[tokio::test]
+async fn test_proxy_restart() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/proxies/1/actions/restart"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "action_uid": "proxy-restart-1",
+            "name": "proxy_restart",
+            "status": "queued"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ProxyHandler::new(client);
+    let action = handler.restart(1).await.unwrap();
+
+    assert_eq!(action.action_uid, "proxy-restart-1");
+    assert_eq!(action.status, "queued");
+}
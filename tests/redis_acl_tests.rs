@@ -1,6 +1,6 @@
 //! Redis ACL endpoint tests for Redis Enterprise
 
-use redis_enterprise::{CreateRedisAclRequest, EnterpriseClient, RedisAclHandler};
+use redis_enterprise::{CreateRedisAclRequest, EnterpriseClient, RedisAclHandler, RestError};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -542,3 +542,96 @@ async fn test_redis_acl_delete_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_redis_acl_validate_accepts_valid_rules() {
+    for acl in [
+        "+@read -@write",
+        "+@all -flushall -config",
+        "+ping +info",
+        "+config|get ~cache:* &notifications",
+        "+@search +@json %R~reports:[a-z]* %RW~tmp:*",
+        "allkeys allchannels +@read (~session:* +@write)",
+        "on >secret nopass resetkeys",
+        "+@read sanitize-payload",
+        "+@all skip-sanitize-payload",
+        "+@mymodule -@reads",
+        "on >pass(word <old)pass #abc) !def(",
+        "~report(2024)",
+        "",
+    ] {
+        let request = CreateRedisAclRequest::builder()
+            .name("valid")
+            .acl(acl)
+            .build();
+        assert!(request.validate().is_ok(), "rejected valid ACL: {acl}");
+    }
+}
+
+#[test]
+fn test_redis_acl_validate_rejects_malformed_rules() {
+    for acl in [
+        "+@",
+        "+@read (~key:* +get",
+        "+@read ~key:* +get)",
+        "((~a +get))",
+        "~key:[abc",
+        "+ -@write",
+        "~",
+        "%X~key:*",
+        "+@read readonly",
+        "+config|",
+        "é~key:*",
+        "+@read ✓",
+    ] {
+        let request = CreateRedisAclRequest::builder()
+            .name("invalid")
+            .acl(acl)
+            .build();
+        let error = request.validate().unwrap_err();
+        assert!(
+            matches!(error, RestError::ValidationError(_)),
+            "unexpected error for {acl}: {error:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_redis_acl_create_validates_before_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/redis_acls"))
+        .respond_with(created_response(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = RedisAclHandler::new(client);
+    let request = CreateRedisAclRequest::builder()
+        .name("typo")
+        .acl("+@read ~key:[abc")
+        .build();
+    let error = handler.create(request).await.unwrap_err();
+    assert!(error.to_string().contains("unbalanced '['"));
+}
+
+#[test]
+fn test_redis_acl_validate_redacts_passwords() {
+    let request = CreateRedisAclRequest::builder()
+        .name("leaky")
+        .acl("on >hunter2 #4a5b6c (~key:* +get")
+        .build();
+    let error = request.validate().unwrap_err().to_string();
+    assert!(error.contains("unbalanced '('"));
+    assert!(error.contains(">***"));
+    assert!(!error.contains("hunter2"));
+    assert!(!error.contains("4a5b6c"));
+}
@@ -0,0 +1,132 @@
+//! Tests for the backoff and retry helpers
+
+use redis_enterprise::RestError;
+use redis_enterprise::retry::{Backoff, retry_async, retry_async_when};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+#[test]
+fn test_backoff_grows_and_caps() {
+    let delays: Vec<Duration> = Backoff::new(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(500))
+        .max_retries(5)
+        .collect();
+
+    assert_eq!(
+        delays,
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(400),
+            Duration::from_millis(500),
+            Duration::from_millis(500),
+        ]
+    );
+}
+
+#[test]
+fn test_backoff_custom_multiplier_and_fixed() {
+    let delays: Vec<Duration> = Backoff::new(Duration::from_millis(10))
+        .multiplier(3.0)
+        .max_retries(3)
+        .collect();
+    assert_eq!(
+        delays,
+        vec![
+            Duration::from_millis(10),
+            Duration::from_millis(30),
+            Duration::from_millis(90),
+        ]
+    );
+
+    let fixed: Vec<Duration> = Backoff::fixed(Duration::from_secs(2)).take(4).collect();
+    assert!(fixed.iter().all(|d| *d == Duration::from_secs(2)));
+}
+
+#[test]
+fn test_backoff_jitter_stays_within_bounds() {
+    let delays: Vec<Duration> = Backoff::new(Duration::from_millis(1000))
+        .multiplier(1.0)
+        .jitter(0.5)
+        .max_retries(50)
+        .collect();
+
+    assert_eq!(delays.len(), 50);
+    assert!(
+        delays
+            .iter()
+            .all(|d| *d >= Duration::from_millis(500) && *d <= Duration::from_millis(1000))
+    );
+}
+
+#[test]
+fn test_backoff_timeout_stops_iteration() {
+    let mut backoff = Backoff::fixed(Duration::from_secs(1)).timeout(Duration::from_millis(2500));
+
+    assert_eq!(backoff.next(), Some(Duration::from_secs(1)));
+    assert!(
+        Backoff::fixed(Duration::from_secs(3))
+            .timeout(Duration::from_secs(2))
+            .next()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_retry_async_succeeds_after_failures() {
+    let attempts = AtomicU32::new(0);
+
+    let result: Result<u32, String> = retry_async(
+        Backoff::fixed(Duration::from_millis(1)).max_retries(5),
+        || async {
+            let n = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if n < 3 {
+                Err(format!("attempt {}", n))
+            } else {
+                Ok(n)
+            }
+        },
+    )
+    .await;
+
+    assert_eq!(result, Ok(3));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_retry_async_returns_last_error_when_exhausted() {
+    let attempts = AtomicU32::new(0);
+
+    let result: Result<(), String> = retry_async(
+        Backoff::fixed(Duration::from_millis(1)).max_retries(2),
+        || async {
+            let n = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            Err(format!("attempt {}", n))
+        },
+    )
+    .await;
+
+    assert_eq!(result, Err("attempt 3".to_string()));
+}
+
+#[tokio::test]
+async fn test_retry_async_when_stops_on_non_retryable_error() {
+    let attempts = AtomicU32::new(0);
+
+    let result: Result<(), RestError> = retry_async_when(
+        Backoff::fixed(Duration::from_millis(1)).max_retries(5),
+        RestError::is_retryable,
+        || async {
+            let n = attempts.fetch_add(1, Ordering::SeqCst);
+            if n == 0 {
+                Err(RestError::ClusterBusy { request_id: None })
+            } else {
+                Err(RestError::NotFound { request_id: None })
+            }
+        },
+    )
+    .await;
+
+    assert!(result.unwrap_err().is_not_found());
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
@@ -1,6 +1,8 @@
 //! Roles endpoint tests for Redis Enterprise
 
-use redis_enterprise::{BdbRole, CreateRoleRequest, EnterpriseClient, RolesHandler};
+use redis_enterprise::{
+    BdbRole, CreateRoleRequest, EnterpriseClient, Permission, RoleInfo, RolesHandler,
+};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -547,3 +549,53 @@ async fn test_role_users_nonexistent() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_role_effective_permissions() {
+    let role: RoleInfo = serde_json::from_value(json!({
+        "uid": 3,
+        "name": "app-team",
+        "management": "db_member",
+        "data_access": "none",
+        "cluster_roles": ["cluster_viewer", "cluster_viewer", ""],
+        "bdb_roles": [
+            {"bdb_uid": 1, "role": "db_member", "redis_acl_uid": 4},
+            {"bdb_uid": 2, "role": "db_viewer"},
+            {"bdb_uid": 1, "role": "db_member", "redis_acl_uid": 4}
+        ]
+    }))
+    .unwrap();
+
+    let permissions = role.effective_permissions();
+    assert_eq!(
+        permissions,
+        vec![
+            Permission::Management("db_member".to_string()),
+            Permission::Cluster("cluster_viewer".to_string()),
+            Permission::Database {
+                bdb_uid: 1,
+                role: "db_member".to_string(),
+                redis_acl_uid: Some(4),
+            },
+            Permission::Database {
+                bdb_uid: 2,
+                role: "db_viewer".to_string(),
+                redis_acl_uid: None,
+            },
+        ]
+    );
+    assert_eq!(permissions[0].bdb_uid(), None);
+    assert_eq!(permissions[3].bdb_uid(), Some(2));
+}
+
+#[test]
+fn test_role_effective_permissions_empty() {
+    let role: RoleInfo = serde_json::from_value(json!({
+        "uid": 4,
+        "name": "nothing",
+        "management": "none"
+    }))
+    .unwrap();
+
+    assert!(role.effective_permissions().is_empty());
+}
@@ -57,3 +57,38 @@ fn test_correct_types_work() {
     assert_eq!(db.name, "test-db".to_string());
     assert_eq!(db.master_persistence, Some(false));
 }
+
+#[tokio::test]
+async fn test_client_reports_structured_parse_error() {
+    use redis_enterprise::{EnterpriseClient, RestError};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"uid": 1, "name": "test-db", "master_persistence": "should-be-bool"}"#,
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let err = client.databases().info(1).await.unwrap_err();
+
+    assert!(err.is_parse_error());
+    assert_eq!(err.parse_path(), Some("master_persistence"));
+    assert!(err.body_snippet().unwrap().contains("should-be-bool"));
+    assert!(
+        matches!(err, RestError::ParseErrorAt { ref message, .. } if message.contains("boolean"))
+    );
+    assert!(
+        err.to_string()
+            .starts_with("Parse error: Failed to deserialize field 'master_persistence': ")
+    );
+}
@@ -2,7 +2,7 @@
 
 use redis_enterprise::{EnterpriseClient, ShardHandler};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
+use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // Test helper functions
@@ -566,3 +566,95 @@ async fn test_shard_stats_metric_invalid() {
     let result = handler.stats_metric("shard:1:1", "invalid_metric").await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_shard_failover() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/shards/shard:1:1/actions/failover"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(json!({
+            "action_uid": "a1b2c3",
+            "status": "queued"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ShardHandler::new(client);
+    let action = handler.failover("shard:1:1").await.unwrap();
+
+    assert_eq!(action.action_uid, "a1b2c3");
+    assert_eq!(action.status.as_deref(), Some("queued"));
+}
+
+#[tokio::test]
+async fn test_shard_migrate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards/shard:1:1"))
+        .respond_with(success_response(master_shard()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/shards/shard:1:1/actions/migrate"))
+        .and(basic_auth("admin", "password"))
+        .and(body_json(json!({"target_node_uid": 3})))
+        .respond_with(success_response(json!({"action_uid": "d4e5f6"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ShardHandler::new(client);
+    let action = handler.migrate("shard:1:1", 3).await.unwrap();
+
+    assert_eq!(action.action_uid, "d4e5f6");
+}
+
+#[tokio::test]
+async fn test_shard_migrate_to_current_node_rejected() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards/shard:1:1"))
+        .respond_with(success_response(master_shard()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/shards/shard:1:1/actions/migrate"))
+        .respond_with(success_response(json!({"action_uid": "unused"})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = ShardHandler::new(client);
+    let error = handler.migrate("shard:1:1", 1).await.unwrap_err();
+
+    assert!(error.is_bad_request());
+    assert!(error.to_string().contains("already on node 1"));
+}
@@ -1,6 +1,6 @@
 //! Statistics endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, StatsHandler, StatsQuery};
+use redis_enterprise::{ClusterMetricsSnapshot, EnterpriseClient, StatsHandler, StatsQuery};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -225,6 +225,56 @@ async fn test_stats_cluster_last() {
     assert_eq!(stats.metrics["memory_usage"], 77.5);
 }
 
+#[tokio::test]
+async fn test_stats_cluster_last_typed() {
+    let mock_server = MockServer::start().await;
+
+    let mut body = test_cluster_last_stats();
+    body["conns"] = json!(42);
+    body["total_req"] = json!("158000");
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/stats/last"))
+        .respond_with(success_response(body))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let snapshot = StatsHandler::new(client)
+        .cluster_last_typed()
+        .await
+        .unwrap();
+    assert_eq!(snapshot.interval.as_deref(), Some("2m"));
+    assert_eq!(snapshot.cpu_usage, Some(28.3));
+    assert_eq!(snapshot.memory_usage, Some(77.5));
+    assert_eq!(snapshot.network_in, Some(1150000.0));
+    assert_eq!(snapshot.network_out, Some(2300000.0));
+    assert_eq!(snapshot.total_req, Some(158000.0));
+    assert_eq!(snapshot.extra.len(), 1);
+    assert_eq!(snapshot.extra["conns"], 42);
+}
+
+#[test]
+fn test_cluster_metrics_snapshot_alternate_names() {
+    let response: redis_enterprise::stats::LastStatsResponse = serde_json::from_value(json!({
+        "stime": "2023-01-01T12:00:00Z",
+        "ingress_bytes": 100.5,
+        "egress_bytes": 200
+    }))
+    .unwrap();
+
+    let snapshot = ClusterMetricsSnapshot::from(response);
+    assert_eq!(snapshot.network_in, Some(100.5));
+    assert_eq!(snapshot.network_out, Some(200.0));
+    assert_eq!(snapshot.cpu_usage, None);
+    assert!(snapshot.extra.is_empty());
+}
+
 #[tokio::test]
 async fn test_stats_node() {
     let mock_server = MockServer::start().await;
@@ -610,3 +660,76 @@ async fn test_stats_shards() {
     assert_eq!(stats.stats[0].uid, 1);
     assert_eq!(stats.stats[1].uid, 2);
 }
+
+#[tokio::test]
+async fn test_stats_database_with_shards() {
+    let mock_server = MockServer::start().await;
+
+    let shard = |uid: &str, role: &str| {
+        json!({
+            "uid": uid,
+            "bdb_uid": 1,
+            "node_uid": "1",
+            "role": role,
+            "status": "active"
+        })
+    };
+    let stats = |total_req: serde_json::Value| {
+        json!({
+            "intervals": [
+                {"time": "2023-01-01T12:00:00Z", "metrics": {"total_req": 10}},
+                {"time": "2023-01-01T12:05:00Z", "metrics": {"total_req": total_req}}
+            ]
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/shards"))
+        .respond_with(success_response(json!([
+            shard("1", "master"),
+            shard("2", "master"),
+            shard("3", "slave")
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/stats"))
+        .and(query_param("interval", "5min"))
+        .respond_with(success_response(stats(json!(1500))))
+        .mount(&mock_server)
+        .await;
+    for (uid, total_req) in [("1", json!(300)), ("2", json!("1200")), ("3", json!(0))] {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/shards/{}/stats", uid)))
+            .and(query_param("interval", "5min"))
+            .respond_with(success_response(stats(total_req)))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let query = StatsQuery {
+        interval: Some("5min".to_string()),
+        stime: None,
+        etime: None,
+        metrics: None,
+    };
+    let breakdown = client
+        .stats()
+        .database_with_shards(1, Some(query))
+        .await
+        .unwrap();
+
+    assert_eq!(breakdown.bdb_uid, 1);
+    assert_eq!(breakdown.database.latest("total_req"), Some(1500.0));
+    assert_eq!(breakdown.shards.len(), 3);
+    assert_eq!(breakdown.shards[2].shard.role, "slave");
+    let hottest = breakdown.hottest_shard("total_req").unwrap();
+    assert_eq!(hottest.shard.uid, "2");
+    assert!(breakdown.hottest_shard("no_such_metric").is_none());
+}
@@ -560,3 +560,150 @@ async fn test_suffixes_cluster_suffixes_error() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_suffix_verify_ready() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/suffix/prod"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_suffix()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/suffixes"))
+        .respond_with(success_response(json!([
+            test_suffix(),
+            test_suffix_minimal()
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "addr": "10.0.0.1", "status": "active"},
+            {"uid": 2, "addr": "10.0.0.2", "status": "active"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = SuffixesHandler::new(client);
+    let status = handler.verify("prod").await.unwrap();
+
+    assert!(status.is_ready());
+    assert!(status.cluster_registered);
+    assert_eq!(status.dns_suffix.as_deref(), Some("prod.redis.example.com"));
+    assert_eq!(status.nodes.len(), 2);
+    assert_eq!(status.nodes[1].address.as_deref(), Some("10.0.0.2"));
+}
+
+#[tokio::test]
+async fn test_suffix_verify_pending_nodes() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/suffix/external"))
+        .respond_with(success_response(test_suffix_external()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/suffixes"))
+        .respond_with(success_response(json!([test_suffix()])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "addr": "10.0.0.1", "external_addr": ["203.0.113.1"], "status": "active"},
+            {"uid": 2, "addr": "10.0.0.2", "status": "active"},
+            {"uid": 3, "addr": "10.0.0.3", "external_addr": ["203.0.113.3"], "status": "down"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = SuffixesHandler::new(client);
+    let status = handler.verify("external").await.unwrap();
+
+    assert!(!status.is_ready());
+    assert!(!status.cluster_registered);
+    assert_eq!(status.nodes[0].address.as_deref(), Some("203.0.113.1"));
+    assert!(status.nodes[0].ready);
+
+    let pending: Vec<u32> = status.pending_nodes().iter().map(|n| n.node_uid).collect();
+    assert_eq!(pending, vec![2, 3]);
+    assert_eq!(
+        status.nodes[1].reason.as_deref(),
+        Some("node has no external address")
+    );
+    assert_eq!(
+        status.nodes[2].reason.as_deref(),
+        Some("node status is down")
+    );
+}
+
+#[tokio::test]
+async fn test_suffix_list_with_usage() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/suffixes"))
+        .respond_with(success_response(json!([
+            test_suffix(),
+            test_suffix_minimal(),
+            test_suffix_external()
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(success_response(json!([
+            {"uid": 1, "name": "cache", "dns_suffixes": ["prod"]},
+            {"uid": 2, "name": "sessions", "dns_suffixes": ["prod", "external.redis.example.com"]},
+            {"uid": 3, "name": "scratch"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let usage = SuffixesHandler::new(client)
+        .list_with_usage()
+        .await
+        .unwrap();
+    let usage: Vec<_> = usage
+        .iter()
+        .map(|(suffix, uids)| (suffix.name.as_str(), uids.clone()))
+        .collect();
+    assert_eq!(
+        usage,
+        vec![
+            ("prod", vec![1, 2]),
+            ("test", vec![]),
+            ("external", vec![2])
+        ]
+    );
+}
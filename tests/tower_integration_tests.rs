@@ -5,9 +5,10 @@
 
 #![cfg(feature = "tower-integration")]
 
-use redis_enterprise::EnterpriseClient;
 use redis_enterprise::tower_support::{ApiRequest, Method};
+use redis_enterprise::{EnterpriseClient, RestError};
 use serde_json::json;
+use std::time::Duration;
 use tower::{Service, ServiceExt};
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -99,6 +100,56 @@ async fn test_tower_service_post_request() {
     assert_eq!(response.body["name"], "test-database");
 }
 
+#[tokio::test]
+async fn test_tower_service_returns_actual_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "uid": 2,
+            "name": "created-db"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/2/actions/backup"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .expect("Failed to create client");
+
+    let mut service = client.into_service();
+
+    let response = service
+        .ready()
+        .await
+        .expect("Service not ready")
+        .call(ApiRequest::post("/v1/bdbs", json!({"name": "created-db"})))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status, 201);
+    assert_eq!(response.body["uid"], 2);
+
+    // Accepted with no body
+    let response = service
+        .ready()
+        .await
+        .expect("Service not ready")
+        .call(ApiRequest::post("/v1/bdbs/2/actions/backup", json!({})))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status, 202);
+    assert!(response.body.is_null());
+}
+
 #[tokio::test]
 async fn test_tower_service_put_request() {
     let mock_server = MockServer::start().await;
@@ -219,6 +270,77 @@ async fn test_tower_service_delete_request() {
         .expect("Request failed");
 
     assert_eq!(response.status, 200);
+    assert_eq!(response.body, json!({}));
+}
+
+#[tokio::test]
+async fn test_tower_service_delete_no_content() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .expect("Failed to create client");
+
+    let response = client
+        .into_service()
+        .oneshot(ApiRequest::delete("/v1/bdbs/1"))
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status, 204);
+    assert_eq!(response.body, json!({"status": "deleted"}));
+}
+
+#[tokio::test]
+async fn test_tower_service_delete_error_mapping() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/bdbs/2"))
+        .respond_with(ResponseTemplate::new(409).set_body_string("database is busy"))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .expect("Failed to create client");
+
+    let err = client
+        .clone()
+        .into_service()
+        .oneshot(ApiRequest::delete("/v1/bdbs/1"))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RestError::RateLimited { retry_after: Some(d), .. } if d == Duration::from_secs(7)
+    ));
+
+    let err = client
+        .into_service()
+        .oneshot(ApiRequest::delete("/v1/bdbs/2"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, RestError::Conflict { ref message, .. } if message == "database is busy")
+    );
 }
 
 #[tokio::test]
@@ -6,7 +6,7 @@
 #![cfg(feature = "tower-integration")]
 
 use redis_enterprise::EnterpriseClient;
-use redis_enterprise::tower_support::ApiRequest;
+use redis_enterprise::tower_support::{ApiRequest, RateLimitRetryLayer, TokenRefreshLayer};
 use serde_json::json;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -15,7 +15,7 @@ use tower::buffer::BufferLayer;
 use tower::limit::RateLimitLayer;
 use tower::timeout::TimeoutLayer;
 use tower::{Service, ServiceBuilder, ServiceExt};
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -457,3 +457,157 @@ async fn test_tower_database_operations_with_middleware() {
     assert_eq!(response.body["uid"], 1);
     assert_eq!(response.body["name"], "test-db");
 }
+
+#[tokio::test]
+async fn test_token_refresh_layer_refreshes_on_unauthorized() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .and(header("authorization", "Bearer fresh-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "test-cluster"})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(401))
+        .with_priority(10)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .unwrap();
+
+    // First token is rejected, every later one is accepted
+    let refreshes = Arc::new(AtomicU32::new(0));
+    let counter = refreshes.clone();
+    let mut service = ServiceBuilder::new()
+        .layer(TokenRefreshLayer::new(move || {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(if n == 0 {
+                    "stale-token".to_string()
+                } else {
+                    "fresh-token".to_string()
+                })
+            }
+        }))
+        .service(client.into_headered_service());
+
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::get("/v1/cluster"))
+        .await
+        .unwrap();
+    assert_eq!(response.body["name"], "test-cluster");
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+
+    // The refreshed token is cached for subsequent requests
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(ApiRequest::get("/v1/cluster"))
+        .await
+        .unwrap();
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_token_refresh_layer_retries_only_once() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .unwrap();
+
+    let service = ServiceBuilder::new()
+        .layer(TokenRefreshLayer::new(|| async {
+            Ok("rejected-token".to_string())
+        }))
+        .service(client.into_headered_service());
+
+    let result = service.oneshot(ApiRequest::get("/v1/cluster")).await;
+    assert!(result.unwrap_err().is_unauthorized());
+}
+
+#[cfg(feature = "test-support")]
+#[tokio::test]
+async fn test_rate_limit_retry_layer_with_flaky_server() {
+    use redis_enterprise::testing::{ClusterFixture, MockEnterpriseServer};
+
+    let server = MockEnterpriseServer::start().await;
+    server
+        .mock_path_flaky(
+            "GET",
+            "/v1/cluster",
+            2,
+            429,
+            ClusterFixture::new("retry-cluster").build(),
+        )
+        .await;
+
+    let service = ServiceBuilder::new()
+        .layer(RateLimitRetryLayer::new(3).default_delay(Duration::from_millis(10)))
+        .service(server.client().into_service());
+
+    let response = service
+        .oneshot(ApiRequest::get("/v1/cluster"))
+        .await
+        .unwrap();
+    assert_eq!(response.body["name"], "retry-cluster");
+}
+
+#[tokio::test]
+async fn test_rate_limit_retry_layer_honors_retry_after_and_cap() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("test-user")
+        .password("test-pass")
+        .build()
+        .unwrap();
+
+    // The server asks for 120s; max_delay keeps the test fast
+    let service = ServiceBuilder::new()
+        .layer(RateLimitRetryLayer::new(2).max_delay(Duration::from_millis(10)))
+        .service(client.into_service());
+
+    let started = std::time::Instant::now();
+    let error = service
+        .oneshot(ApiRequest::get("/v1/cluster"))
+        .await
+        .unwrap_err();
+    assert!(started.elapsed() < Duration::from_secs(5));
+    match error {
+        redis_enterprise::RestError::RateLimited { retry_after, .. } => {
+            assert_eq!(retry_after, Some(Duration::from_secs(120)));
+        }
+        other => panic!("expected RateLimited, got {other:?}"),
+    }
+}
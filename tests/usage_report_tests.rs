@@ -1,6 +1,8 @@
 //! Usage report endpoint tests for Redis Enterprise
 
-use redis_enterprise::{EnterpriseClient, UsageReportConfig, UsageReportHandler};
+use redis_enterprise::{
+    EnterpriseClient, UsageCsvRow, UsageReport, UsageReportConfig, UsageReportHandler,
+};
 use serde_json::json;
 use wiremock::matchers::{basic_auth, body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -567,6 +569,47 @@ async fn test_usage_report_download_csv() {
     assert!(csv_content.contains("redis-db-2"));
 }
 
+#[tokio::test]
+async fn test_usage_report_download_csv_to_writer() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/usage_report/report-2023-01-01/csv"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(test_csv_content())
+                .append_header("content-type", "text/csv"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/usage_report/nonexistent/csv"))
+        .respond_with(error_response(404, "Usage report not found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+    let handler = UsageReportHandler::new(client);
+
+    let mut buffer = Vec::new();
+    let written = handler
+        .download_csv_to("report-2023-01-01", &mut buffer)
+        .await
+        .unwrap();
+    assert_eq!(written, test_csv_content().len() as u64);
+    assert_eq!(String::from_utf8(buffer).unwrap(), test_csv_content());
+
+    let mut untouched = Vec::new();
+    let result = handler.download_csv_to("nonexistent", &mut untouched).await;
+    assert!(result.unwrap_err().is_not_found());
+    assert!(untouched.is_empty());
+}
+
 #[tokio::test]
 async fn test_usage_report_download_csv_nonexistent() {
     let mock_server = MockServer::start().await;
@@ -614,3 +657,116 @@ async fn test_usage_report_download_csv_generation_error() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_usage_report_to_csv_rows() {
+    let report: UsageReport = serde_json::from_value(test_usage_report()).unwrap();
+    let rows = report.to_csv_rows();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].report_id.as_deref(), Some("report-2023-01-01"));
+    assert_eq!(rows[0].bdb_uid, Some(1));
+    assert_eq!(rows[0].database_name, "redis-db-1");
+    assert_eq!(rows[0].memory_used_peak, Some(2147483648));
+    assert_eq!(rows[0].shard_count, Some(2));
+    assert_eq!(rows[1].shard_count, None);
+}
+
+#[test]
+fn test_usage_report_cost_estimate() {
+    let report: UsageReport = serde_json::from_value(test_usage_report()).unwrap();
+    let summary = report.summary.unwrap();
+
+    assert_eq!(summary.cost_estimate(0.5), 18.0);
+    assert_eq!(summary.cost_estimate(0.0), 0.0);
+}
+
+#[test]
+fn test_usage_csv_parse_quoted_fields() {
+    let csv = "database_name,bdb_uid,ops_per_sec_avg,extra\r\n\
+               \"cache, primary\",1,12.5,x\r\n\
+               \"say \"\"hi\"\"\",2,,y\r\n\
+               \r\n";
+    let rows = UsageCsvRow::parse_csv(csv).unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].database_name, "cache, primary");
+    assert_eq!(rows[0].bdb_uid, Some(1));
+    assert_eq!(rows[0].ops_per_sec_avg, Some(12.5));
+    assert_eq!(rows[1].database_name, "say \"hi\"");
+    assert_eq!(rows[1].ops_per_sec_avg, None);
+    assert_eq!(rows[1].report_id, None);
+}
+
+#[test]
+fn test_usage_csv_parse_errors() {
+    assert!(UsageCsvRow::parse_csv("report_id,timestamp\nr1,t1\n").is_err());
+    assert!(UsageCsvRow::parse_csv("name,bdb_uid\ndb,abc\n").is_err());
+    assert!(UsageCsvRow::parse_csv("name\n\"unterminated\n").is_err());
+    assert!(UsageCsvRow::parse_csv("").unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_usage_report_latest_parsed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/usage_report/latest"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(success_response(test_usage_report()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/usage_report/report-2023-01-01/csv"))
+        .and(basic_auth("admin", "password"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(test_csv_content())
+                .append_header("content-type", "text/csv"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap();
+
+    let handler = UsageReportHandler::new(client);
+    let (report, rows) = handler.latest_parsed().await.unwrap();
+
+    assert_eq!(report.report_id, "report-2023-01-01");
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].database_name, "redis-db-1");
+    assert_eq!(rows[0].report_id.as_deref(), Some("report-2023-01-01"));
+    assert_eq!(rows[1].database_name, "redis-db-2");
+}
+
+#[test]
+fn test_usage_report_bandwidth_accepts_strings() {
+    let report: UsageReport = serde_json::from_value(json!({
+        "report_id": "r1",
+        "timestamp": "2024-01-01T00:00:00Z",
+        "period_start": "2023-12-01T00:00:00Z",
+        "period_end": "2023-12-31T23:59:59Z",
+        "cluster_name": "c1",
+        "databases": [{
+            "bdb_uid": 1,
+            "name": "db",
+            "memory_used_avg": "1073741824",
+            "memory_used_peak": 2147483648.0,
+            "ops_per_sec_avg": 10.0,
+            "bandwidth_avg": "5368709120"
+        }]
+    }))
+    .unwrap();
+    let db = &report.databases.unwrap()[0];
+    assert_eq!(db.memory_used_avg, 1_073_741_824);
+    assert_eq!(db.memory_used_peak, 2_147_483_648);
+    assert_eq!(db.bandwidth_avg, 5_368_709_120);
+}
@@ -3,9 +3,13 @@
 
 mod common;
 
-use redis_enterprise::{CreateUserRequest, EnterpriseClient, UpdateUserRequest, User, UserHandler};
+use redis_enterprise::{
+    CreateUserRequest, EnterpriseClient, PasswordPolicy, RoleHandler, UpdateUserRequest, User,
+    UserHandler,
+};
 use serde_json::json;
-use wiremock::matchers::{basic_auth, method, path};
+use std::time::Duration;
+use wiremock::matchers::{basic_auth, body_json, body_partial_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // Test helper functions
@@ -192,3 +196,194 @@ async fn test_user_delete() {
 
     assert!(result.is_ok());
 }
+
+fn user_with(uid: u32, email: &str, role_uids: &[u32]) -> serde_json::Value {
+    json!({
+        "uid": uid,
+        "email": email,
+        "role": "db_viewer",
+        "role_uids": role_uids
+    })
+}
+
+fn test_client(mock_server: &MockServer) -> EnterpriseClient {
+    EnterpriseClient::builder()
+        .base_url(mock_server.uri())
+        .username("admin")
+        .password("password")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_user_create_many_preserves_order() {
+    let mock_server = MockServer::start().await;
+
+    // The first user is the slowest to be created
+    Mock::given(method("POST"))
+        .and(path("/v1/users"))
+        .and(body_partial_json(json!({"email": "a@example.com"})))
+        .respond_with(
+            created_response(user_with(10, "a@example.com", &[]))
+                .set_delay(Duration::from_millis(100)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/users"))
+        .and(body_partial_json(json!({"email": "b@example.com"})))
+        .respond_with(ResponseTemplate::new(409).set_body_string("user already exists"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/users"))
+        .and(body_partial_json(json!({"email": "c@example.com"})))
+        .respond_with(created_response(user_with(12, "c@example.com", &[])))
+        .mount(&mock_server)
+        .await;
+
+    let requests = ["a@example.com", "b@example.com", "c@example.com"]
+        .into_iter()
+        .map(|email| {
+            CreateUserRequest::builder()
+                .email(email)
+                .password("secret")
+                .role("db_viewer")
+                .build()
+        })
+        .collect();
+
+    let handler = UserHandler::new(test_client(&mock_server));
+    let results = handler.create_many(requests, 3).await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().uid, 10);
+    assert!(results[1].as_ref().unwrap_err().is_conflict());
+    assert_eq!(results[2].as_ref().unwrap().uid, 12);
+}
+
+#[tokio::test]
+async fn test_user_create_many_empty() {
+    let mock_server = MockServer::start().await;
+    let handler = UserHandler::new(test_client(&mock_server));
+
+    assert!(handler.create_many(Vec::new(), 0).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_role_assign_many() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/users/1"))
+        .respond_with(success_response(user_with(1, "a@example.com", &[2])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/users/1"))
+        .and(body_json(json!({"role_uids": [2, 5]})))
+        .respond_with(success_response(user_with(1, "a@example.com", &[2, 5])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Already has the role, so it is not updated
+    Mock::given(method("GET"))
+        .and(path("/v1/users/2"))
+        .respond_with(success_response(user_with(2, "b@example.com", &[5])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/users/2"))
+        .respond_with(success_response(user_with(2, "b@example.com", &[5])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/users/3"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let handler = RoleHandler::new(test_client(&mock_server));
+    let results = handler.assign_many(5, &[1, 2, 3]).await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().role_uids, Some(vec![2, 5]));
+    assert_eq!(results[1].as_ref().unwrap().role_uids, Some(vec![5]));
+    assert!(results[2].as_ref().unwrap_err().is_not_found());
+}
+
+#[test]
+fn test_password_policy_rules() {
+    let policy = PasswordPolicy {
+        min_length: Some(10),
+        complexity: true,
+    };
+    let rule = |password: &str| {
+        policy
+            .validate(password, "jane.doe@example.com")
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(
+        policy
+            .validate("Str0ng!Passw", "jane.doe@example.com")
+            .is_ok()
+    );
+    assert!(rule("Sh0rt!").contains("at least 10 characters"));
+    assert!(rule("lowercase1!x").contains("uppercase"));
+    assert!(rule("UPPERCASE1!X").contains("lowercase"));
+    assert!(rule("NoDigits!here").contains("digit"));
+    assert!(rule("NoSpecial1here").contains("special character"));
+    assert!(rule("Paaaass1!word").contains("repeat"));
+    assert!(rule("Jane.Doe1!xyz").contains("user name"));
+
+    let lenient = PasswordPolicy {
+        min_length: None,
+        complexity: false,
+    };
+    assert!(lenient.validate("x", "jane.doe@example.com").is_ok());
+}
+
+#[tokio::test]
+async fn test_user_create_checked_rejects_before_request() {
+    let mock_server = MockServer::start().await;
+
+    let mut cluster = common::fixtures::cluster_info_response();
+    cluster["password_complexity"] = json!(true);
+    cluster["password_min_length"] = json!(12);
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(success_response(cluster))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/users"))
+        .respond_with(created_response(test_user()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let handler = UserHandler::new(test_client(&mock_server));
+    let weak = CreateUserRequest::builder()
+        .email("new@example.com")
+        .password("Short1!")
+        .role("db_viewer")
+        .build();
+    let err = handler.create_checked(weak).await.unwrap_err();
+    assert!(matches!(
+        err,
+        redis_enterprise::RestError::ValidationError(_)
+    ));
+    assert!(err.to_string().contains("at least 12 characters"));
+
+    let strong = CreateUserRequest::builder()
+        .email("new@example.com")
+        .password("Corr3ct-Horse-Battery")
+        .role("db_viewer")
+        .build();
+    assert!(handler.create_checked(strong).await.is_ok());
+}